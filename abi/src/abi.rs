@@ -6,44 +6,133 @@ use core::ops::{Deref, DerefMut};
 
 /// Function ABI.
 #[derive(Clone, Debug, Default)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Abi(sol_abi::Abi);
+pub struct Abi {
+    abi: sol_abi::Abi,
+    /// Selector overriding the one computed from the signature.
+    pub selector: Option<[u8; 4]>,
+    /// Symbol of the function in WASM if it differs from the name,
+    /// which is the case of the overloaded functions.
+    pub symbol: Option<String>,
+}
 
 impl Deref for Abi {
     type Target = sol_abi::Abi;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.abi
+    }
+}
+
+impl Abi {
+    /// Get the symbol of the function in WASM.
+    pub fn symbol(&self) -> &str {
+        self.symbol.as_deref().unwrap_or(&self.name)
     }
 }
 
 impl DerefMut for Abi {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.abi
+    }
+}
+
+impl From<sol_abi::Abi> for Abi {
+    fn from(abi: sol_abi::Abi) -> Self {
+        Self {
+            abi,
+            selector: None,
+            symbol: None,
+        }
+    }
+}
+
+/// The JSON ABI has no place for the selector override and the
+/// symbol, only the solidity ABI is serialized.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Abi {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.abi.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Abi {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        sol_abi::Abi::deserialize(deserializer).map(Into::into)
     }
 }
 
 #[cfg(feature = "bytes")]
 impl Abi {
     /// Convert [`Abi`] to bytes.
+    ///
+    /// The selector override and the symbol are optional trailing
+    /// fields following the solidity ABI, which are only encoded if
+    /// they are set, so the bytes of the plain functions are the same
+    /// as the ones of the solidity ABI.
     pub fn to_bytes(&self) -> postcard::Result<Vec<u8>> {
-        postcard::to_stdvec(self).map_err(Into::into)
+        let mut bytes = postcard::to_stdvec(&self.abi)?;
+        if self.selector.is_some() || self.symbol.is_some() {
+            bytes.extend(postcard::to_stdvec(&self.selector)?);
+        }
+        if self.symbol.is_some() {
+            bytes.extend(postcard::to_stdvec(&self.symbol)?);
+        }
+
+        Ok(bytes)
     }
 
     /// Convert bytes to [`Abi`].
+    ///
+    /// The missing trailing fields are decoded as `None`.
     pub fn from_bytes(bytes: impl AsRef<[u8]>) -> postcard::Result<Self> {
-        postcard::from_bytes(bytes.as_ref()).map_err(Into::into)
+        let (abi, rest) = postcard::take_from_bytes(bytes.as_ref())?;
+        let (selector, rest) = match rest {
+            [] => (None, rest),
+            _ => postcard::take_from_bytes(rest)?,
+        };
+        let symbol = match rest {
+            [] => None,
+            _ => postcard::from_bytes(rest)?,
+        };
+
+        Ok(Self {
+            abi,
+            selector,
+            symbol,
+        })
     }
 }
 
 #[cfg(feature = "hex")]
 mod hex_impl {
-    use crate::{result::Result, Abi};
+    use crate::{
+        result::{Error, Result},
+        Abi,
+    };
     use core::fmt;
 
     impl Abi {
+        /// Check if the types of the arguments are supported.
+        ///
+        /// Fixed point types are rejected since they are not
+        /// supported by the compiler.
+        pub fn validate(&self) -> Result<()> {
+            if let Some(arg) = self
+                .inputs
+                .iter()
+                .chain(self.outputs.iter())
+                .find(|arg| arg.ty.is_fixed())
+            {
+                return Err(Error::FixedPointType(arg.ty.to_string()));
+            }
+
+            Ok(())
+        }
+
         /// Convert [`Abi`] to hex string.
         pub fn to_hex(&self) -> Result<String> {
+            self.validate()?;
             Ok("0x".to_string() + &hex::encode(self.to_bytes()?))
         }
 
@@ -67,11 +156,59 @@ mod hex_impl {
             Self::from_hex(hex)
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use crate::Abi;
+        use sol_abi::{Arg, Param};
+
+        fn abi(ty: &str) -> Abi {
+            let mut abi = Abi::default();
+            abi.inputs.push(Arg {
+                name: "value".into(),
+                ty: Param::from(ty),
+            });
+            abi
+        }
+
+        #[test]
+        fn trailing_fields() {
+            // blobs encoded before the trailing fields
+            let plain = abi("u64");
+            let bytes = plain.to_bytes().unwrap();
+            assert_eq!(bytes, postcard::to_stdvec(&*plain).unwrap());
+            assert!(Abi::from_bytes(&bytes).unwrap().selector.is_none());
+
+            let mut selector = abi("u64");
+            selector.selector = Some([0x12, 0x34, 0x56, 0x78]);
+            let decoded = Abi::from_bytes(selector.to_bytes().unwrap()).unwrap();
+            assert_eq!(decoded.selector, selector.selector);
+            assert!(decoded.symbol.is_none());
+
+            let mut symbol = abi("u64");
+            symbol.symbol = Some("value_u64".into());
+            let decoded = Abi::from_bytes(symbol.to_bytes().unwrap()).unwrap();
+            assert!(decoded.selector.is_none());
+            assert_eq!(decoded.symbol(), "value_u64");
+        }
+
+        #[test]
+        fn fixed_point_types() {
+            for ty in ["fixed", "ufixed", "fixed128x18", "ufixed64x10"] {
+                let err = abi(ty).to_hex().unwrap_err().to_string();
+                assert!(err.contains(ty) && err.contains("uint256"), "{err}");
+            }
+
+            for ty in ["u64", "fixedbytes", "fixed128"] {
+                assert!(abi(ty).to_hex().is_ok(), "{ty}");
+            }
+        }
+    }
 }
 
 #[cfg(feature = "syn")]
 impl From<&syn::Signature> for Abi {
     fn from(sig: &syn::Signature) -> Self {
-        Self(sol_abi::Abi::from(sig))
+        sol_abi::Abi::from(sig).into()
     }
 }
@@ -0,0 +1,218 @@
+//! Event ABI and log decoding.
+#![cfg(feature = "selector")]
+
+use crate::{selector::keccak256, Param};
+
+/// Event ABI.
+#[derive(Clone, Debug, Default)]
+pub struct Event {
+    /// Event name.
+    pub name: String,
+    /// Parameters of the event.
+    pub inputs: Vec<EventParam>,
+    /// If the signature is omitted from the topics.
+    pub anonymous: bool,
+}
+
+/// Parameter of an event.
+#[derive(Clone, Debug, Default)]
+pub struct EventParam {
+    /// Name of the parameter.
+    pub name: String,
+    /// Type of the parameter.
+    pub ty: Param,
+    /// If the parameter is placed in the topics.
+    pub indexed: bool,
+}
+
+impl Event {
+    /// Get the event signature.
+    pub fn signature(&self) -> String {
+        self.name.clone()
+            + "("
+            + &self
+                .inputs
+                .iter()
+                .map(|i| i.ty.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+            + ")"
+    }
+
+    /// Get the first topic of the event, the hash of its signature.
+    pub fn topic(&self) -> [u8; 32] {
+        keccak256(self.signature().as_bytes())
+    }
+}
+
+/// Decoded value of a parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// Unsigned integer as a big-endian word.
+    Uint([u8; 32]),
+    /// Signed integer as a big-endian word.
+    Int([u8; 32]),
+    /// A boolean.
+    Bool(bool),
+    /// An EVM address.
+    Address([u8; 20]),
+    /// A fixed-size byte array `bytesN`.
+    FixedBytes(Vec<u8>),
+    /// A dynamic byte array.
+    Bytes(Vec<u8>),
+    /// A string.
+    String(String),
+    /// A dynamic array `T[]`.
+    Array(Vec<Value>),
+    /// Hash of an indexed dynamic value, the value itself is not
+    /// recoverable from the topics.
+    Hash([u8; 32]),
+}
+
+/// Decode the log of the event into the values of its parameters.
+///
+/// Indexed parameters are reconstructed from the topics and the others
+/// from the data. Returns `None` if the log doesn't match the event or
+/// a parameter type is not supported.
+pub fn decode_log(event: &Event, topics: &[[u8; 32]], data: &[u8]) -> Option<Vec<Value>> {
+    let mut topics = topics.iter();
+    if !event.anonymous && topics.next()? != &event.topic() {
+        return None;
+    }
+
+    let mut head = 0;
+    let values = event
+        .inputs
+        .iter()
+        .map(|input| {
+            if !input.indexed {
+                let value = decode(&input.ty, data, head);
+                head += 32;
+                return value;
+            }
+
+            let topic = topics.next()?;
+            if is_dynamic(&input.ty) {
+                Some(Value::Hash(*topic))
+            } else {
+                decode(&input.ty, topic, 0)
+            }
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    topics.next().is_none().then_some(values)
+}
+
+/// If the type is encoded out of the head.
+fn is_dynamic(ty: &Param) -> bool {
+    matches!(ty, Param::Bytes | Param::String | Param::Array(_))
+}
+
+/// Decode the value with its head at `at` in the encoded `data`.
+///
+/// The offsets of the dynamic values are relative to the start of `data`.
+fn decode(ty: &Param, data: &[u8], at: usize) -> Option<Value> {
+    let head = word(data, at)?;
+    if !is_dynamic(ty) {
+        return Some(match ty {
+            Param::Int8 | Param::Int16 | Param::Int32 | Param::Int64 => Value::Int(head),
+            Param::UInt8 | Param::UInt16 | Param::UInt32 | Param::UInt64 | Param::UInt256 => {
+                Value::Uint(head)
+            }
+            Param::Bool => Value::Bool(head[31] != 0),
+            Param::Address => Value::Address(head[12..].try_into().ok()?),
+            Param::FixedBytes(len) => Value::FixedBytes(head[..*len as usize].into()),
+            _ => return None,
+        });
+    }
+
+    let content = data.get(usize_of(&head)?..)?;
+    let len = usize_of(&word(content, 0)?)?;
+    let content = &content[32..];
+    Some(match ty {
+        Param::Bytes => Value::Bytes(content.get(..len)?.into()),
+        Param::String => Value::String(String::from_utf8(content.get(..len)?.into()).ok()?),
+        Param::Array(ty) => Value::Array(
+            (0..len)
+                .map(|i| decode(ty, content, i * 32))
+                .collect::<Option<_>>()?,
+        ),
+        _ => return None,
+    })
+}
+
+/// Get the word at `at` in the data.
+fn word(data: &[u8], at: usize) -> Option<[u8; 32]> {
+    data.get(at..at.checked_add(32)?)?.try_into().ok()
+}
+
+/// Convert the big-endian word to an offset or a length.
+fn usize_of(word: &[u8; 32]) -> Option<usize> {
+    let (high, low) = word.split_at(24);
+    if high.iter().any(|b| *b != 0) {
+        return None;
+    }
+
+    usize::try_from(u64::from_be_bytes(low.try_into().ok()?)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_log, Event, EventParam, Value};
+    use sol_abi::Param;
+
+    fn param(ty: Param, indexed: bool) -> EventParam {
+        EventParam {
+            name: Default::default(),
+            ty,
+            indexed,
+        }
+    }
+
+    fn uint(n: u8) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[31] = n;
+        word
+    }
+
+    #[test]
+    fn dynamic() {
+        let event = Event {
+            name: "Message".into(),
+            inputs: vec![
+                param(Param::String, true),
+                param(Param::String, false),
+                param(Param::Array(Box::new(Param::UInt256)), false),
+            ],
+            anonymous: false,
+        };
+
+        let hash = [1; 32];
+        let mut hi = [0; 32];
+        hi[..2].copy_from_slice(b"hi");
+
+        let data = [
+            uint(0x40),
+            uint(0x80),
+            uint(2),
+            hi,
+            uint(2),
+            uint(7),
+            uint(8),
+        ]
+        .concat();
+
+        let values = decode_log(&event, &[event.topic(), hash], &data).unwrap();
+        assert_eq!(
+            values,
+            vec![
+                Value::Hash(hash),
+                Value::String("hi".into()),
+                Value::Array(vec![Value::Uint(uint(7)), Value::Uint(uint(8))]),
+            ]
+        );
+
+        assert!(decode_log(&event, &[hash, hash], &data).is_none());
+        assert!(decode_log(&event, &[event.topic()], &data).is_none());
+    }
+}
@@ -3,10 +3,17 @@
 //! Currently just a wrapper of solidity ABI.
 
 mod abi;
+pub mod event;
+pub mod packed;
 pub mod result;
 pub mod selector;
 
 pub use abi::Abi;
+pub use sol_abi::{Arg, Param, StateMutability};
 
 #[cfg(feature = "selector")]
-pub use selector::keccak256;
+pub use {
+    event::{decode_log, Event, EventParam, Value},
+    packed::encode_packed,
+    selector::{keccak256, selector_of},
+};
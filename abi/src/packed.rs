@@ -0,0 +1,148 @@
+//! Non-standard packed encoding.
+#![cfg(feature = "selector")]
+
+use crate::{Param, Value};
+
+/// Encode the values tightly with their types as `abi.encodePacked` of
+/// solidity.
+///
+/// The values of the static types take the bytes of their types only,
+/// e.g. 20 bytes for an address and 2 bytes for an `uint16`, the
+/// dynamic values are encoded in place without their lengths, and the
+/// elements of the arrays are padded to words.
+///
+/// The encoding is ambiguous, `("a", "bc")` and `("ab", "c")` share the
+/// same encoding for example, hashing it for signatures or the leaves
+/// of merkle trees should not take more than one dynamic value.
+///
+/// Returns `None` if a value doesn't fit its type or an array has
+/// elements of the dynamic types, which solidity doesn't support.
+pub fn encode_packed(values: &[(Param, Value)]) -> Option<Vec<u8>> {
+    let mut encoded = Vec::new();
+    for (ty, value) in values {
+        match (ty, value) {
+            (Param::Bytes, Value::Bytes(bytes)) => encoded.extend_from_slice(bytes),
+            (Param::String, Value::String(string)) => encoded.extend_from_slice(string.as_bytes()),
+            (Param::Array(ty), Value::Array(values)) => {
+                for value in values {
+                    encoded.extend_from_slice(&word(ty, value)?);
+                }
+            }
+            _ => encoded.extend_from_slice(&packed(ty, value)?),
+        }
+    }
+
+    Some(encoded)
+}
+
+/// Get the bytes of the static value in the packed encoding.
+fn packed(ty: &Param, value: &Value) -> Option<Vec<u8>> {
+    match (ty, value) {
+        (Param::Bool, Value::Bool(value)) => Some(vec![*value as u8]),
+        (Param::Address, Value::Address(address)) => Some(address.to_vec()),
+        (Param::FixedBytes(len), Value::FixedBytes(bytes)) if bytes.len() == *len as usize => {
+            Some(bytes.clone())
+        }
+        (_, Value::Uint(word)) => truncate(word, bytes_of(ty)?, false),
+        (_, Value::Int(word)) => truncate(word, bytes_of(ty)?, true),
+        _ => None,
+    }
+}
+
+/// Truncate the big-endian word to its low bytes, `None` if the high
+/// bytes are not the extension of them.
+fn truncate(word: &[u8; 32], len: usize, signed: bool) -> Option<Vec<u8>> {
+    let (high, low) = word.split_at(32 - len);
+    let fill = if signed && low[0] & 0x80 != 0 {
+        0xff
+    } else {
+        0
+    };
+    high.iter().all(|b| *b == fill).then(|| low.to_vec())
+}
+
+/// Get the static value padded to a word as an element of an array.
+fn word(ty: &Param, value: &Value) -> Option<[u8; 32]> {
+    let bytes = packed(ty, value)?;
+    let mut word = [0; 32];
+    match value {
+        Value::FixedBytes(_) => word[..bytes.len()].copy_from_slice(&bytes),
+        Value::Int(int) => word = *int,
+        _ => word[32 - bytes.len()..].copy_from_slice(&bytes),
+    }
+
+    Some(word)
+}
+
+/// Get the bytes of the integer type.
+fn bytes_of(ty: &Param) -> Option<usize> {
+    Some(match ty {
+        Param::Int8 | Param::UInt8 => 1,
+        Param::Int16 | Param::UInt16 => 2,
+        Param::Int32 | Param::UInt32 => 4,
+        Param::Int64 | Param::UInt64 => 8,
+        Param::UInt256 => 32,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::encode_packed;
+    use crate::{selector::keccak256, Param, Value};
+
+    fn uint(n: u8) -> [u8; 32] {
+        let mut word = [0; 32];
+        word[31] = n;
+        word
+    }
+
+    #[test]
+    fn solidity() {
+        // abi.encodePacked(int16(-1), bytes1(0x42), uint16(0x03), string("Hello, world!"))
+        let encoded = encode_packed(&[
+            (Param::Int16, Value::Int([0xff; 32])),
+            (Param::FixedBytes(1), Value::FixedBytes(vec![0x42])),
+            (Param::UInt16, Value::Uint(uint(3))),
+            (Param::String, Value::String("Hello, world!".into())),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            encoded,
+            [
+                &[0xff, 0xff, 0x42, 0x00, 0x03][..],
+                b"Hello, world!".as_slice()
+            ]
+            .concat()
+        );
+        assert_eq!(keccak256(&encoded)[..4], [0xa6, 0x1e, 0xca, 0xcd]);
+    }
+
+    #[test]
+    fn array() {
+        let encoded = encode_packed(&[
+            (Param::Address, Value::Address([1; 20])),
+            (
+                Param::Array(Box::new(Param::UInt8)),
+                Value::Array(vec![Value::Uint(uint(7)), Value::Uint(uint(8))]),
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(encoded, [&[1; 20][..], &uint(7), &uint(8)].concat());
+    }
+
+    #[test]
+    fn mismatch() {
+        assert!(encode_packed(&[(Param::UInt8, Value::Uint(uint(0xff)))]).is_some());
+        assert!(encode_packed(&[(Param::UInt8, Value::Uint([0xff; 32]))]).is_none());
+        assert!(encode_packed(&[(Param::Int8, Value::Int(uint(0x80)))]).is_none());
+        assert!(encode_packed(&[(Param::Address, Value::Bool(true))]).is_none());
+        assert!(encode_packed(&[(
+            Param::Array(Box::new(Param::String)),
+            Value::Array(vec![Value::String("a".into())])
+        )])
+        .is_none());
+    }
+}
@@ -10,6 +10,9 @@ pub enum Error {
     /// Failed to decode from hex.
     #[error(transparent)]
     Hex(#[from] hex::FromHexError),
+    /// Fixed point types are not supported.
+    #[error("Fixed point type {0} is not supported, use integers with fixed decimals instead, e.g. uint256 scaled by 1e18")]
+    FixedPointType(String),
 }
 
 /// ABI result
@@ -20,6 +20,15 @@ pub fn parse(bytes: &[u8]) -> [u8; 4] {
     selector
 }
 
+/// Get the selector of the function signature.
+///
+/// ```ignore
+/// assert_eq!(zabi::selector_of("transfer(address,uint256)"), [0xa9, 0x05, 0x9c, 0xbb]);
+/// ```
+pub fn selector_of(signature: &str) -> [u8; 4] {
+    parse(signature.as_bytes())
+}
+
 impl Abi {
     /// Get function signature.
     pub fn signature(&self) -> String {
@@ -28,14 +37,100 @@ impl Abi {
             + &self
                 .inputs
                 .iter()
-                .map(|i| i.ty.as_ref())
+                .map(|i| i.ty.to_string())
                 .collect::<Vec<_>>()
                 .join(",")
             + ")"
     }
 
     /// Get function selector.
+    ///
+    /// Returns the selector override if any.
     pub fn selector(&self) -> [u8; 4] {
-        parse(self.signature().as_bytes())
+        self.selector
+            .unwrap_or_else(|| parse(self.signature().as_bytes()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Abi;
+    use sol_abi::{Arg, Param};
+
+    #[test]
+    fn fixed_bytes() {
+        let mut abi = Abi::default();
+        *abi = sol_abi::Abi {
+            name: "transfer".into(),
+            inputs: vec![Arg {
+                name: "id".into(),
+                ty: Param::from("Bytes4"),
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(abi.signature(), "transfer(bytes4)");
+        assert_ne!(abi.selector(), super::parse(b"transfer(uint32)"));
+    }
+
+    #[test]
+    fn dynamic_array() {
+        let mut abi = Abi::default();
+        *abi = sol_abi::Abi {
+            name: "sum".into(),
+            inputs: vec![Arg {
+                name: "values".into(),
+                ty: Param::from("Vec<U256>"),
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(abi.signature(), "sum(uint256[])");
+    }
+
+    #[test]
+    fn tuple() {
+        let mut abi = Abi::default();
+        *abi = sol_abi::Abi {
+            name: "nested".into(),
+            inputs: vec![Arg {
+                name: "value".into(),
+                ty: Param::from("(U256,(Address,U256))"),
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(abi.signature(), "nested((uint256,(address,uint256)))");
+
+        let ty = &abi.inputs[0].ty;
+        assert_eq!(ty.size(), Some(96));
+        assert_eq!(ty.offset_of(&[0]), Some(0));
+        assert_eq!(ty.offset_of(&[1]), Some(32));
+        assert_eq!(ty.offset_of(&[1, 1]), Some(64));
+        assert_eq!(ty.offset_of(&[2]), None);
+        assert_eq!(Param::from("(U256,Bytes)").size(), None);
+    }
+
+    #[test]
+    fn selector_of() {
+        let mut abi = Abi::default();
+        *abi = sol_abi::Abi {
+            name: "transfer".into(),
+            inputs: vec![
+                Arg {
+                    name: "to".into(),
+                    ty: Param::from("Address"),
+                },
+                Arg {
+                    name: "value".into(),
+                    ty: Param::from("U256"),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let selector = super::selector_of("transfer(address,uint256)");
+        assert_eq!(selector, abi.selector());
+        assert_eq!(selector, [0xa9, 0x05, 0x9c, 0xbb]);
     }
 }
@@ -20,6 +20,8 @@ pub struct Assembler {
     pub mp: usize,
     /// Stack pointer, maximum 1024 items.
     pub sp: u8,
+    /// Program counter and opcode of the last emitted opcode.
+    last: Option<(usize, OpCode)>,
 }
 
 impl Assembler {
@@ -33,6 +35,13 @@ impl Assembler {
         &mut self.buffer
     }
 
+    /// Get the last emitted opcode if it is the last instruction of
+    /// the buffer, the data of `PUSH`es are never taken as opcodes.
+    pub fn last_op(&self) -> Option<OpCode> {
+        let (pc, opcode) = self.last?;
+        (pc + 1 == self.buffer.len() && self.buffer[pc] == u8::from(opcode)).then_some(opcode)
+    }
+
     /// Increment the gas counter.
     ///
     /// TODO: use number bigger than `u256` for throwing proper errors. (#21)
@@ -119,6 +128,7 @@ impl Assembler {
     pub fn emit_op(&mut self, opcode: OpCode) -> Result<()> {
         tracing::trace!("emit opcode: {:?}", opcode);
         self.decrement_sp(opcode.stack_in() as u8)?;
+        self.last = Some((self.buffer.len(), opcode));
         self.emit(opcode.into());
         self.increment_gas(opcode.gas().into());
         self.increment_sp(opcode.stack_out() as u8)?;
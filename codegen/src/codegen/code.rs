@@ -1,6 +1,7 @@
 //! Table for the code section.
 
 use indexmap::IndexMap;
+use opcodes::ShangHai as OpCode;
 
 /// Code section for EVM.
 #[derive(Clone, Default, Debug)]
@@ -76,3 +77,65 @@ pub struct ExtFunc {
     /// The bytecode of the external function.
     pub bytecode: Vec<u8>,
 }
+
+impl ExtFunc {
+    /// Count the leading zero bits of the integer of `bytes` bytes,
+    /// all bits are counted if the integer is zero.
+    ///
+    /// The bits are counted by a binary search without branches, the
+    /// integer is shifted left by half of the remaining width if the
+    /// upper half is zero, which is counted as well.
+    ///
+    /// STACK: [ret, x] -> [clz(x)]
+    pub fn clz(bytes: u8) -> Self {
+        let bits = bytes as u32 * 8;
+        let mut code: Vec<u8> = vec![OpCode::JUMPDEST.into(), push(bytes)];
+        code.extend(vec![0xff; bytes as usize]);
+        code.extend([u8::from(OpCode::AND), u8::from(OpCode::PUSH0)]);
+
+        // [ret, x, n] -> [ret, x << d, n + d] where d is `shift` if
+        // x < 1 << (bits - shift) else 0.
+        let mut shift = bits / 2;
+        while shift > 0 {
+            let threshold = (1u128 << (bits - shift)).to_be_bytes();
+            let threshold = &threshold[threshold.iter().position(|b| *b != 0).unwrap_or(15)..];
+            code.push(push(threshold.len() as u8));
+            code.extend(threshold);
+            code.extend([
+                OpCode::DUP3.into(),
+                OpCode::LT.into(),
+                OpCode::PUSH1.into(),
+                shift.trailing_zeros() as u8,
+                OpCode::SHL.into(),
+                OpCode::DUP1.into(),
+                OpCode::SWAP2.into(),
+                OpCode::ADD.into(),
+                OpCode::SWAP2.into(),
+                OpCode::SWAP1.into(),
+                OpCode::SHL.into(),
+                OpCode::SWAP1.into(),
+            ]);
+            shift /= 2;
+        }
+
+        // the last bit is left if x is zero.
+        code.extend([
+            u8::from(OpCode::SWAP1),
+            u8::from(OpCode::ISZERO),
+            u8::from(OpCode::ADD),
+            u8::from(OpCode::SWAP1),
+            u8::from(OpCode::JUMP),
+        ]);
+
+        Self {
+            stack_in: 1,
+            stack_out: 1,
+            bytecode: code,
+        }
+    }
+}
+
+/// Get the `PUSH` opcode of `len` bytes.
+fn push(len: u8) -> u8 {
+    u8::from(OpCode::PUSH1) + len - 1
+}
@@ -1,20 +1,134 @@
 //! Contract constructor.
 
-use crate::{wasm::ToLSBytes, Buffer, MacroAssembler, Result};
+use crate::{
+    wasm::{Global, Globals, ToLSBytes},
+    Buffer, MacroAssembler, Result,
+};
+use opcodes::ShangHai as OpCode;
 use smallvec::SmallVec;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Initial storage of contracts
 pub type InitStorage = HashMap<SmallVec<[u8; 32]>, SmallVec<[u8; 32]>>;
 
+/// Values of the immutables by their slots
+pub type Immutables = BTreeMap<u32, SmallVec<[u8; 32]>>;
+
+/// Prefix of the placeholders of the immutables.
+const IMMUTABLE_PREFIX: &[u8] = b"zink::immutable";
+
+/// Prefix of the placeholders of the jumps out of the constructor
+/// function.
+const CONSTRUCTOR_PREFIX: &[u8] = b"zink::constructor";
+
 /// Contract constructor.
 #[derive(Default, Debug, Clone)]
 pub struct Constructor {
     /// Code generator.
     masm: MacroAssembler,
+    /// Code patching the immutables of the runtime bytecode in memory.
+    immutables: MacroAssembler,
+    /// Bytecode of the `#[zink::constructor]` function, empty if the
+    /// contract has no constructor function.
+    body: Buffer,
 }
 
 impl Constructor {
+    /// Get the placeholder of the immutable in the runtime bytecode,
+    /// which is the data of a `PUSH32` patched by the constructor.
+    pub fn placeholder(slot: u32) -> [u8; 32] {
+        let mut placeholder = [0; 32];
+        placeholder[..IMMUTABLE_PREFIX.len()].copy_from_slice(IMMUTABLE_PREFIX);
+        placeholder[28..].copy_from_slice(&slot.to_be_bytes());
+        placeholder
+    }
+
+    /// Get the placeholder of the jump from the entry of the
+    /// constructor function to the storage presets.
+    pub fn entry() -> [u8; 32] {
+        Self::jump_placeholder(0)
+    }
+
+    /// Get the placeholder of the jump from the end of the constructor
+    /// function to the code returning the runtime bytecode.
+    pub fn exit() -> [u8; 32] {
+        Self::jump_placeholder(1)
+    }
+
+    /// Get the placeholder of the jumps out of the constructor function.
+    fn jump_placeholder(kind: u8) -> [u8; 32] {
+        let mut placeholder = [0; 32];
+        placeholder[..CONSTRUCTOR_PREFIX.len()].copy_from_slice(CONSTRUCTOR_PREFIX);
+        placeholder[31] = kind;
+        placeholder
+    }
+
+    /// Store the init values of the mutable globals to their backing
+    /// storage slots.
+    pub fn globals(masm: &mut MacroAssembler, globals: &Globals) -> Result<()> {
+        for (index, global) in globals.iter().filter(|(_, global)| global.mutable) {
+            masm.push(&global.to_ls_bytes())?;
+            masm.push(&Global::storage_key(*index))?;
+            masm._sstore()?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the bytecode of the constructor function of the contracts
+    /// without one, which only initializes the mutable globals.
+    ///
+    /// Returns empty bytecode if there are no mutable globals.
+    pub fn globals_body(globals: &Globals) -> Result<Buffer> {
+        if !globals.values().any(|global| global.mutable) {
+            return Ok(Buffer::new());
+        }
+
+        let mut masm = MacroAssembler::default();
+        masm.push(&Self::entry())?;
+        masm._jump()?;
+        masm._jumpdest()?;
+        Self::globals(&mut masm, globals)?;
+        masm.push(&Self::exit())?;
+        masm._jump()?;
+        Ok(masm.buffer().into())
+    }
+
+    /// Set the bytecode of the constructor function, which runs before
+    /// the runtime bytecode is returned.
+    ///
+    /// The storage presets run at the entry of it, a revert of it
+    /// leaves no contract deployed.
+    pub fn body(&mut self, bytecode: &[u8]) {
+        self.body = bytecode.into();
+    }
+
+    /// Bake the immutables into the runtime bytecode.
+    ///
+    /// The placeholders are patched after the runtime bytecode is
+    /// copied to memory, the immutables without values are left as
+    /// their placeholders.
+    pub fn immutables(&mut self, runtime_bytecode: &[u8], immutables: Immutables) -> Result<()> {
+        for (slot, value) in immutables {
+            let mut instr = vec![OpCode::PUSH32.into()];
+            instr.extend_from_slice(&Self::placeholder(slot));
+
+            let value = &value[value.iter().take_while(|b| **b == 0).count()..];
+            for (offset, _) in runtime_bytecode
+                .windows(instr.len())
+                .enumerate()
+                .filter(|(_, code)| *code == instr.as_slice())
+            {
+                tracing::debug!("Patching immutable {slot} at 0x{offset:x} ...");
+                self.immutables.push(value)?;
+                self.immutables.push(&(offset + 1).to_ls_bytes())?;
+                self.immutables._mstore()?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// preset storage for the contract
     pub fn storage(&mut self, mapping: InitStorage) -> Result<()> {
         tracing::debug!("Building storage in constructor ...");
@@ -32,9 +146,65 @@ impl Constructor {
     /// Here we override the memory totally with
     /// the runtime bytecode.
     pub fn finish(&self, runtime_bytecode: Buffer) -> Result<Buffer> {
-        let init_code = self.masm.buffer();
-        let init_code_len = init_code.len();
-        let runtime_bytecode_len = runtime_bytecode.len();
+        let mut bytecode = self.init_code(runtime_bytecode.len())?;
+        bytecode.extend_from_slice(&runtime_bytecode);
+
+        Ok(bytecode)
+    }
+
+    /// Returns the init code which copies the runtime bytecode
+    /// with the provided length to memory and returns it.
+    pub fn init_code(&self, runtime_bytecode_len: usize) -> Result<Buffer> {
+        if self.body.is_empty() {
+            return self.return_runtime(self.masm.clone(), runtime_bytecode_len);
+        }
+
+        // [
+        //   body: push32 <entry>, jump, jumpdest, ..., push32 <exit>, jump
+        //   entry: jumpdest, storage presets, push1 <body>, jump
+        //   exit: jumpdest, ...
+        // ]
+        let entry = self.body.len();
+        let exit = entry + self.masm.buffer().len() + 4;
+        let mut body = self.body.clone();
+        Self::patch(&mut body, Self::entry(), entry);
+        Self::patch(&mut body, Self::exit(), exit);
+
+        let mut masm = MacroAssembler::default();
+        masm.buffer_mut().extend_from_slice(&body);
+        masm._jumpdest()?;
+        masm.buffer_mut().extend_from_slice(self.masm.buffer());
+        masm.push(&[Self::entry().len() as u8 + 2])?;
+        masm._jump()?;
+        masm._jumpdest()?;
+        self.return_runtime(masm, runtime_bytecode_len)
+    }
+
+    /// Replace the jump placeholder in the bytecode with the program
+    /// counter.
+    fn patch(bytecode: &mut [u8], placeholder: [u8; 32], pc: usize) {
+        let mut target = [0; 32];
+        target[24..].copy_from_slice(&(pc as u64).to_be_bytes());
+
+        let mut offset = 0;
+        while let Some(found) = bytecode[offset..]
+            .windows(placeholder.len())
+            .position(|code| code == placeholder)
+        {
+            offset += found;
+            bytecode[offset..offset + target.len()].copy_from_slice(&target);
+            offset += target.len();
+        }
+    }
+
+    /// Append the code copying the runtime bytecode to memory and
+    /// returning it to the init code.
+    fn return_runtime(
+        &self,
+        mut masm: MacroAssembler,
+        runtime_bytecode_len: usize,
+    ) -> Result<Buffer> {
+        let init_code_len = masm.buffer().len() + self.immutables.buffer().len();
         let runtime_bytecode_size = runtime_bytecode_len.to_ls_bytes();
         let runtime_bytecode_offset =
             Self::runtime_bytcode_offset(init_code_len, runtime_bytecode_size.len());
@@ -44,19 +214,19 @@ impl Constructor {
             "length of bytecode in hex: {:?}",
             hex::encode(&runtime_bytecode_size)
         );
-        let mut masm = self.masm.clone();
 
         // 1. copy runtime bytecode to memory
         masm.push(&runtime_bytecode_size)?; // code size
         masm.push(&runtime_bytecode_offset.to_ls_bytes())?; // code offset
         masm._push0()?; // dest offset in memory
         masm._codecopy()?;
+        masm.buffer_mut()
+            .extend_from_slice(self.immutables.buffer());
 
         // 2. return runtime bytecode
         masm.push(&runtime_bytecode_size)?; // code size
         masm._push0()?; // memory offset
         masm.asm._return()?;
-        masm.buffer_mut().extend_from_slice(&runtime_bytecode);
 
         Ok(masm.buffer().into())
     }
@@ -66,12 +236,16 @@ impl Constructor {
     /// [
     ///   init_code,
     ///   pushn, runtime_bytecode_size, pushn + <offset>, push0, code_copy
+    ///   immutables,
     ///   pushn, runtime_bytecode_size, push0, return,
     ///   <OFFSET>
     /// ]
+    ///
+    /// The offset is pushed with two bytes once the init code grows
+    /// over `0xff` bytes, e.g. with the constructor function.
     fn runtime_bytcode_offset(init_code_len: usize, runtime_bytecode_size_len: usize) -> usize {
         let mut offset = init_code_len + runtime_bytecode_size_len * 2 + 8;
-        if (offset <= 0xff) && (offset + offset.to_ls_bytes().len() > 0xff) {
+        if offset + offset.to_ls_bytes().len() > 0xff {
             offset += 1;
         }
 
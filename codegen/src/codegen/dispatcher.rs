@@ -2,7 +2,7 @@
 
 use crate::{
     wasm::{self, Env, Functions},
-    JumpTable, MacroAssembler, Result,
+    Error, JumpTable, MacroAssembler, Result,
 };
 use std::collections::BTreeMap;
 use wasmparser::FuncType;
@@ -30,9 +30,12 @@ impl Dispatcher {
             .map(|func| Ok((func.index(), func.sig()?)))
             .collect::<Result<_>>()?;
 
+        let mut asm = MacroAssembler::default();
+        asm.use_pool(env.pool.clone());
+
         Ok(Self {
             abi: Default::default(),
-            asm: Default::default(),
+            asm,
             env,
             funcs,
             table: Default::default(),
@@ -45,6 +48,7 @@ impl Dispatcher {
             return Ok(Default::default());
         }
 
+        self.asm.store_pool()?;
         self.asm._push0()?;
         self.asm._calldataload()?;
         self.asm.push(&[0xe0])?;
@@ -62,16 +66,24 @@ impl Dispatcher {
     /// Emit selector to buffer.
     fn emit_selector(&mut self, selector: &wasm::Function<'_>, last: bool) -> Result<()> {
         let abi = self.env.load_abi(selector)?;
+        let selector_bytes = abi.selector();
+        if let Some(other) = self.abi.iter().find(|a| a.selector() == selector_bytes) {
+            return Err(Error::SelectorCollision(
+                hex::encode(selector_bytes),
+                other.signature(),
+                abi.signature(),
+            ));
+        }
+
         self.abi.push(abi.clone());
 
-        let selector_bytes = abi.selector();
         tracing::debug!(
             "Emitting selector {:?} for function: {}",
             selector_bytes,
             abi.signature(),
         );
 
-        let func = self.env.query_func(&abi.name)?;
+        let func = self.env.query_func(abi.symbol())?;
         self.asm.increment_sp(1)?;
 
         // Prepare the `PC` of the callee function.
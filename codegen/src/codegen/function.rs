@@ -2,16 +2,18 @@
 use crate::{
     backtrace::Backtrace,
     control::ControlStack,
+    induction::Inductions,
     jump::JumpTable,
     local::{LocalSlot, LocalSlotType, Locals},
     masm::MacroAssembler,
     validator::ValidateThenVisit,
     wasm::Env,
-    Buffer, Error, Result,
+    width::Widths,
+    Buffer, Constructor, Error, Result,
 };
-use opcodes::ShangHai as OpCode;
+use std::collections::BTreeSet;
 use wasmparser::{FuncType, FuncValidator, LocalsReader, OperatorsReader, ValidatorResources};
-use zabi::Abi;
+use zabi::{Abi, StateMutability};
 
 /// The code generation abstraction.
 pub struct Function {
@@ -33,6 +35,15 @@ pub struct Function {
     pub ty: FuncType,
     /// If this function is the main function.
     pub is_main: bool,
+    /// If this function is the constructor function.
+    pub is_constructor: bool,
+    /// Parameters cached from calldata in memory.
+    pub cached: BTreeSet<usize>,
+    /// Induction variables of the loops kept on the stack.
+    pub inductions: Inductions,
+    /// Widths of the wide and the sign-extended values on the operand
+    /// stack.
+    pub widths: Widths,
 }
 
 impl Function {
@@ -49,9 +60,15 @@ impl Function {
             masm: Default::default(),
             table: Default::default(),
             is_main,
+            is_constructor: false,
+            cached: Default::default(),
+            inductions: Default::default(),
+            widths: Default::default(),
         };
 
+        codegen.masm.use_pool(codegen.env.pool.clone());
         if is_main {
+            codegen.masm.store_pool()?;
             return Ok(codegen);
         }
 
@@ -60,6 +77,7 @@ impl Function {
             // codegen.masm.increment_sp(1)?;
             tracing::debug!("<External function>");
             codegen.masm._jumpdest()?;
+            codegen.reject_value()?;
         } else {
             // Mock the stack frame for the callee function
             //
@@ -72,6 +90,52 @@ impl Function {
         Ok(codegen)
     }
 
+    /// Create a code generator for the constructor function.
+    ///
+    /// The constructor function runs as the main function of the init
+    /// code, it jumps to the storage presets at its entry, initializes
+    /// the mutable globals and jumps to the code returning the runtime
+    /// bytecode at its end, see [`Constructor`].
+    pub fn constructor(env: Env, ty: FuncType) -> Result<Self> {
+        if !ty.results().is_empty() {
+            return Err(Error::InvalidFunctionSignature);
+        }
+
+        let mut codegen = Self::new(env, ty, None, true)?;
+        codegen.is_constructor = true;
+        codegen.masm.push(&Constructor::entry())?;
+        codegen.masm._jump()?;
+        codegen.masm._jumpdest()?;
+        Constructor::globals(&mut codegen.masm, &codegen.env.globals)?;
+        Ok(codegen)
+    }
+
+    /// Revert if value is sent to the function which is not payable.
+    ///
+    /// STACK: [] -> []
+    fn reject_value(&mut self) -> Result<()> {
+        if self
+            .abi
+            .as_ref()
+            .is_some_and(|abi| abi.state_mutability == StateMutability::Payable)
+        {
+            return Ok(());
+        }
+
+        self.masm._callvalue()?;
+        self.masm._iszero()?;
+        let pc = self.masm.pc_offset();
+        self.masm.asm.increment_sp(1)?;
+        self.masm._jumpi()?;
+
+        self.masm._push0()?;
+        self.masm._push0()?;
+        self.masm._revert()?;
+
+        self.table.label(pc, self.masm.pc_offset());
+        self.masm._jumpdest()
+    }
+
     /// Emit function locals
     ///
     /// 1. the function parameters.
@@ -109,6 +173,17 @@ impl Function {
             validator.define_locals(validation_offset, count, val)?;
         }
 
+        // The memory slots of the local variables could be dirty
+        // from the previous calls of internal functions, reset
+        // them to zero as WASM requires.
+        if !self.is_main && self.abi.is_none() {
+            for index in self.ty.params().len()..self.locals.len() {
+                self.masm._push0()?;
+                self.masm.push(&self.env.alloc(index as u32))?;
+                self.masm._mstore()?;
+            }
+        }
+
         tracing::trace!("{:?}", self.locals);
         Ok(())
     }
@@ -119,15 +194,42 @@ impl Function {
         ops: &mut OperatorsReader<'_>,
         validator: &mut FuncValidator<ValidatorResources>,
     ) -> Result<()> {
+        if self.is_constructor {
+            self.load_args()?;
+        } else if self.is_main || self.abi.is_some() {
+            self.cache_calldata(ops.clone())?;
+        }
+
+        if !self.env.no_loop_induction {
+            self.inductions = Inductions::scan(ops.clone())?;
+        }
+
         while !ops.eof() {
             let offset = ops.original_position();
-            let mut validate_then_visit = ValidateThenVisit(validator.visitor(offset), self);
-            ops.visit_operator(&mut validate_then_visit)???;
+            let height = validator.operand_stack_height();
+            let op = ops.clone().read()?;
+            if self.env.strict_width {
+                self.widths.check(&op, height)?;
+            }
+
+            self.widths.enter(height);
+            self.table.source(self.masm.pc_offset(), offset);
+            for (depth, bytes) in self.widths.take_unwrapped(&op) {
+                self.masm.wrap_at(depth, bytes)?;
+            }
+
+            {
+                let mut validate_then_visit = ValidateThenVisit(validator.visitor(offset), self);
+                ops.visit_operator(&mut validate_then_visit)???;
+            }
+            self.widths.visit(&op, height, validator, &self.env)?;
         }
 
-        if (self.abi.is_some() || self.is_main)
-            && self.masm.buffer().last() != Some(&OpCode::RETURN.into())
-        {
+        // Make sure the function halts at its boundary instead of
+        // running into the code of the following functions, the
+        // constructor function ends with its jump to the init code.
+        let exited = self.is_constructor && self.masm.is_jumped();
+        if (self.abi.is_some() || self.is_main) && !self.masm.is_terminated() && !exited {
             self._end()?;
         }
 
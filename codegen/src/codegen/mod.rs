@@ -12,7 +12,7 @@ mod function;
 
 pub use self::{
     code::{Code, ExtFunc},
-    constructor::{Constructor, InitStorage},
+    constructor::{Constructor, Immutables, InitStorage},
     dispatcher::Dispatcher,
     function::Function,
 };
@@ -38,7 +38,14 @@ pub struct ControlStackFrame {
     result: BlockType,
 
     /// Original stack pointer.
+    ///
+    /// The stack pointer at the beginning of the block, for
+    /// `if` and `else`, the condition has been consumed.
     pub original_sp: u8,
+
+    /// Program counters of the branches to the end of the block,
+    /// labeled while popping the block.
+    pub branches: SmallVec<[u16; 4]>,
 }
 
 impl ControlStackFrame {
@@ -54,6 +61,7 @@ impl ControlStackFrame {
             original_pc_offset,
             original_sp,
             result,
+            branches: Default::default(),
         }
     }
 
@@ -66,6 +74,32 @@ impl ControlStackFrame {
     pub fn result(&self) -> BlockType {
         self.result
     }
+
+    /// Get the number of the results of the control stack frame.
+    ///
+    /// Returns `None` for the blocks typed with function types
+    /// since we don't have the type section here.
+    pub fn result_len(&self) -> Option<u8> {
+        match self.result {
+            BlockType::Empty => Some(0),
+            BlockType::Type(_) => Some(1),
+            BlockType::FuncType(_) => None,
+        }
+    }
+
+    /// Get the number of the values carried by the branches to
+    /// the control stack frame.
+    ///
+    /// Branches to a loop restart it with its parameters, which
+    /// are only available in function types, branches to the
+    /// others end them with their results.
+    pub fn branch_arity(&self) -> Option<u8> {
+        match (self.ty, self.result) {
+            (ControlStackFrameType::Loop, BlockType::FuncType(_)) => None,
+            (ControlStackFrameType::Loop, _) => Some(0),
+            _ => self.result_len(),
+        }
+    }
 }
 
 /// The control stack.
@@ -83,6 +117,13 @@ impl ControlStack {
         self.stack.len()
     }
 
+    /// If the branch arities of all the frames are known.
+    pub fn has_branch_arities(&self) -> bool {
+        self.stack
+            .iter()
+            .all(|frame| frame.branch_arity().is_some())
+    }
+
     /// Mark the else block of an if.
     pub fn mark_else(&mut self) -> Result<ControlStackFrame> {
         let last = self
@@ -108,21 +149,38 @@ impl ControlStack {
         self.stack.pop().ok_or_else(|| Error::ControlStackUnderflow)
     }
 
-    /// Get the label of the control stack frame at given depth.
-    pub fn label_from_depth(&self, mut depth: u32) -> Result<u16> {
-        for frame in self.stack.iter().rev() {
-            if frame.ty == ControlStackFrameType::Else {
-                continue;
-            }
-
-            if depth == 0 {
-                return Ok(frame.pc_offset());
-            }
+    /// Get the control stack frame targeted by the branches of the
+    /// given depth.
+    pub fn target(&self, depth: u32) -> Result<&ControlStackFrame> {
+        self.stack
+            .iter()
+            .rev()
+            .filter(|frame| frame.ty != ControlStackFrameType::Else)
+            .nth(depth as usize)
+            .ok_or(Error::InvalidBranchTarget(depth))
+    }
 
-            depth -= 1;
+    /// Register a branch at `pc` to the control stack frame at given
+    /// depth, returns the label if it is known already.
+    ///
+    /// Branches to a loop jump back to its beginning, while branches
+    /// to a block or an if jump to its end, which is unknown until the
+    /// frame is popped.
+    pub fn branch(&mut self, depth: u32, pc: u16) -> Result<Option<u16>> {
+        let frame = self
+            .stack
+            .iter_mut()
+            .rev()
+            .filter(|frame| frame.ty != ControlStackFrameType::Else)
+            .nth(depth as usize)
+            .ok_or(Error::InvalidBranchTarget(depth))?;
+
+        if frame.ty == ControlStackFrameType::Loop {
+            return Ok(Some(frame.pc_offset()));
         }
 
-        Err(Error::InvalidDepth(depth as usize))
+        frame.branches.push(pc);
+        Ok(None)
     }
 
     /// Get the return type of the control stack frame at given depth.
@@ -149,3 +207,27 @@ impl ControlStack {
             .ok_or_else(|| Error::InvalidDepth(depth))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{ControlStack, ControlStackFrame, ControlStackFrameType};
+    use crate::Error;
+    use wasmparser::BlockType;
+
+    #[test]
+    fn invalid_branch_target() {
+        let mut stack = ControlStack::default();
+        stack.push(ControlStackFrame::new(
+            ControlStackFrameType::Block,
+            0,
+            0,
+            BlockType::Empty,
+        ));
+
+        assert!(stack.branch(0, 0).is_ok());
+        assert!(matches!(
+            stack.branch(1, 0),
+            Err(Error::InvalidBranchTarget(1))
+        ));
+    }
+}
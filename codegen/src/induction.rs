@@ -0,0 +1,258 @@
+//! Induction variables of loops.
+//!
+//! The local read and written the most in an innermost loop is kept on
+//! the stack across the iterations instead of its memory slot, it is
+//! loaded before the loop, accessed with `DUP` and `SWAP` in the loop
+//! and stored back while leaving the loop.
+//!
+//! Only the loops of the simple instructions are scanned, the stack
+//! height of them has to keep the variable in the reach of `DUP16`.
+use crate::Result;
+use std::collections::{BTreeMap, VecDeque};
+use wasmparser::{BlockType, Operator, OperatorsReader};
+
+/// The maximum stack height above the induction variable.
+const MAX_HEIGHT: i32 = 16;
+
+/// Induction variables of the loops of a function.
+#[derive(Debug, Default)]
+pub struct Inductions {
+    /// Induction variables of the loops in the order of the loops.
+    loops: VecDeque<Option<u32>>,
+    /// Induction variables on the stack with their stack pointers.
+    stack: Vec<(u32, u8)>,
+}
+
+impl Inductions {
+    /// Scan the induction variables of the loops in the operators.
+    pub fn scan(mut ops: OperatorsReader<'_>) -> Result<Self> {
+        let mut loops = Vec::new();
+        let mut scans: Vec<Option<Scan>> = Vec::new();
+        while !ops.eof() {
+            let op = ops.read()?;
+            match op {
+                Operator::Loop { blockty } => {
+                    scans
+                        .iter_mut()
+                        .flatten()
+                        .for_each(|scan| scan.simple = false);
+                    scans.push(Some(Scan::new(loops.len(), blockty)));
+                    loops.push(None);
+                    continue;
+                }
+                Operator::Block { .. } | Operator::If { .. } => {
+                    if let Some(scan) = scans.iter_mut().flatten().last() {
+                        scan.visit(&op);
+                    }
+
+                    scans.push(None);
+                    continue;
+                }
+                Operator::End => {
+                    if let Some(Some(scan)) = scans.pop() {
+                        let index = scan.index;
+                        loops[index] = scan.induction();
+                        continue;
+                    }
+                }
+                _ => {}
+            }
+
+            if let Some(scan) = scans.iter_mut().flatten().last() {
+                scan.visit(&op);
+            }
+        }
+
+        Ok(Self {
+            loops: loops.into(),
+            stack: Vec::new(),
+        })
+    }
+
+    /// Get the induction variable of the next loop.
+    pub fn next_loop(&mut self) -> Option<u32> {
+        self.loops.pop_front().flatten()
+    }
+
+    /// Keep the local on the stack at the stack pointer.
+    pub fn push(&mut self, local: u32, sp: u8) {
+        self.stack.push((local, sp));
+    }
+
+    /// Stop keeping the local at the stack pointer on the stack,
+    /// returns the local if there is one.
+    pub fn pop(&mut self, sp: u8) -> Option<u32> {
+        match self.stack.last() {
+            Some((local, at)) if *at == sp => {
+                let local = *local;
+                self.stack.pop();
+                Some(local)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the stack pointer of the local if it is on the stack.
+    pub fn sp_of(&self, local: u32) -> Option<u8> {
+        self.stack
+            .iter()
+            .rev()
+            .find(|(index, _)| *index == local)
+            .map(|(_, sp)| *sp)
+    }
+
+    /// Get the local kept at the stack pointer.
+    pub fn local_at(&self, sp: u8) -> Option<u32> {
+        self.stack
+            .iter()
+            .find(|(_, at)| *at == sp)
+            .map(|(local, _)| *local)
+    }
+}
+
+/// Scan of a loop.
+struct Scan {
+    /// Index of the loop in the function.
+    index: usize,
+    /// If the loop only has the simple instructions.
+    simple: bool,
+    /// Stack height relative to the beginning of the loop.
+    height: i32,
+    /// The maximum stack height.
+    max: i32,
+    /// Stack heights and results of the blocks in the loop.
+    blocks: Vec<(i32, i32)>,
+    /// Reads and writes of the locals.
+    usages: BTreeMap<u32, (usize, usize)>,
+}
+
+impl Scan {
+    /// Start the scan of a loop.
+    fn new(index: usize, blockty: BlockType) -> Self {
+        Self {
+            index,
+            simple: results(blockty).is_some(),
+            height: 0,
+            max: 0,
+            blocks: Vec::new(),
+            usages: BTreeMap::new(),
+        }
+    }
+
+    /// Get the induction variable of the loop, which is the local both
+    /// read and written the most.
+    fn induction(self) -> Option<u32> {
+        if !self.simple || self.max > MAX_HEIGHT {
+            return None;
+        }
+
+        self.usages
+            .into_iter()
+            .filter(|(_, (reads, writes))| *reads > 0 && *writes > 0)
+            .max_by_key(|(_, (reads, writes))| reads + writes)
+            .map(|(local, _)| local)
+    }
+
+    /// Visit an instruction of the loop.
+    fn visit(&mut self, op: &Operator<'_>) {
+        let effect = match op {
+            Operator::LocalGet { local_index } => {
+                self.usages.entry(*local_index).or_default().0 += 1;
+                1
+            }
+            Operator::LocalSet { local_index } => {
+                self.usages.entry(*local_index).or_default().1 += 1;
+                -1
+            }
+            Operator::LocalTee { local_index } => {
+                self.usages.entry(*local_index).or_default().1 += 1;
+                self.max = self.max.max(self.height + 1);
+                0
+            }
+            Operator::Block { blockty } | Operator::If { blockty } => {
+                if matches!(op, Operator::If { .. }) {
+                    self.height -= 1;
+                }
+
+                match results(*blockty) {
+                    Some(results) => self.blocks.push((self.height, results)),
+                    None => self.simple = false,
+                }
+                0
+            }
+            Operator::Else => {
+                if let Some((height, _)) = self.blocks.last() {
+                    self.height = *height;
+                }
+                0
+            }
+            Operator::End => {
+                if let Some((height, results)) = self.blocks.pop() {
+                    self.height = height + results;
+                }
+                0
+            }
+            _ => match stack_effect(op) {
+                Some(effect) => effect,
+                None => {
+                    self.simple = false;
+                    0
+                }
+            },
+        };
+
+        self.height += effect;
+        self.max = self.max.max(self.height);
+    }
+}
+
+/// Get the number of the results of the block type, `None` for the
+/// function types.
+fn results(blockty: BlockType) -> Option<i32> {
+    match blockty {
+        BlockType::Empty => Some(0),
+        BlockType::Type(_) => Some(1),
+        BlockType::FuncType(_) => None,
+    }
+}
+
+/// Get the stack effect of the simple instructions.
+fn stack_effect(op: &Operator<'_>) -> Option<i32> {
+    use Operator::*;
+
+    Some(match op {
+        Nop | Unreachable | Br { .. } | Return => 0,
+        I32Const { .. } | I64Const { .. } | GlobalGet { .. } => 1,
+        Drop | GlobalSet { .. } | BrIf { .. } | BrTable { .. } => -1,
+        Select | TypedSelect { .. } => -2,
+        I32Eqz | I64Eqz | I32Clz | I32Ctz | I32Popcnt | I64Clz | I64Ctz | I64Popcnt
+        | I32WrapI64 | I64ExtendI32S | I64ExtendI32U | I32Extend8S | I32Extend16S | I64Extend8S
+        | I64Extend16S | I64Extend32S => 0,
+        I32Eq | I32Ne | I32LtS | I32LtU | I32GtS | I32GtU | I32LeS | I32LeU | I32GeS | I32GeU
+        | I64Eq | I64Ne | I64LtS | I64LtU | I64GtS | I64GtU | I64LeS | I64LeU | I64GeS | I64GeU
+        | I32Add | I32Sub | I32Mul | I32DivS | I32DivU | I32RemS | I32RemU | I32And | I32Or
+        | I32Xor | I32Shl | I32ShrS | I32ShrU | I32Rotl | I32Rotr | I64Add | I64Sub | I64Mul
+        | I64DivS | I64DivU | I64RemS | I64RemU | I64And | I64Or | I64Xor | I64Shl | I64ShrS
+        | I64ShrU | I64Rotl | I64Rotr => -1,
+        I32Load { .. }
+        | I64Load { .. }
+        | I32Load8S { .. }
+        | I32Load8U { .. }
+        | I32Load16S { .. }
+        | I32Load16U { .. }
+        | I64Load8S { .. }
+        | I64Load8U { .. }
+        | I64Load16S { .. }
+        | I64Load16U { .. }
+        | I64Load32S { .. }
+        | I64Load32U { .. } => 0,
+        I32Store { .. }
+        | I64Store { .. }
+        | I32Store8 { .. }
+        | I32Store16 { .. }
+        | I64Store8 { .. }
+        | I64Store16 { .. }
+        | I64Store32 { .. } => -2,
+        _ => return None,
+    })
+}
@@ -20,6 +20,9 @@ pub enum Jump {
     Offset(u16),
     /// Jump to a specific label, which corresponds to the original program counter.
     Label(u16),
+    /// Jump to a label from an entry of a jump table, the target is
+    /// always pushed with `PUSH2` so the entries have the same size.
+    Entry(u16),
     /// Jump to a function identified by its index.
     Func(u32),
     /// Jump to an external function.
@@ -31,6 +34,7 @@ impl Display for Jump {
         match self {
             Jump::Offset(offset) => write!(f, "Offset(0x{offset:x})"),
             Jump::Label(offset) => write!(f, "Label(0x{offset:x})"),
+            Jump::Entry(offset) => write!(f, "Entry(0x{offset:x})"),
             Jump::Func(index) => write!(f, "Func({index})"),
             Jump::ExtFunc(_) => write!(f, "ExtFunc"),
         }
@@ -40,7 +44,12 @@ impl Display for Jump {
 impl Jump {
     /// Checks if the target is a label.
     pub fn is_label(&self) -> bool {
-        matches!(self, Jump::Label { .. })
+        matches!(self, Jump::Label { .. } | Jump::Entry(_))
+    }
+
+    /// Checks if the target is an entry of a jump table.
+    pub fn is_entry(&self) -> bool {
+        matches!(self, Jump::Entry(_))
     }
 
     /// Checks if the target is a fixed offset of the program counter.
@@ -43,4 +43,14 @@ impl JumpTable {
         self.jump = new_jump.into_iter().collect();
         Ok(())
     }
+
+    /// Shifts the program counters of the source map.
+    ///
+    /// The instruction at the start keeps its program counter, the
+    /// bytes inserted at it belong to the instruction.
+    pub fn shift_source_pc(&mut self, start: u16, offset: u16) {
+        for (pc, _) in self.source.iter_mut().filter(|(pc, _)| *pc > start) {
+            *pc += offset;
+        }
+    }
 }
@@ -51,8 +51,9 @@ impl JumpTable {
             );
 
             // Update the buffer with the new target program counter.
-            let offset = relocate::pc(buffer, pc, target)?;
+            let offset = relocate::pc(buffer, pc, target, jump.is_entry())?;
             self.shift_label_pc(pc, offset as u16)?;
+            self.shift_source_pc(pc, offset as u16);
         }
 
         // Extend the buffer with the finished code section.
@@ -92,14 +93,19 @@ impl JumpTable {
 /// counter, and updates the provided buffer with the necessary opcode
 /// instructions. It ensures that the buffer does not exceed the defined
 /// size limit and handles the conversion of the target program counter
-/// to the appropriate byte representation.
-fn pc(buffer: &mut Buffer, original_pc: u16, target_pc: u16) -> Result<usize> {
+/// to the appropriate byte representation, the target of a wide jump
+/// is always pushed with two bytes.
+fn pc(buffer: &mut Buffer, original_pc: u16, target_pc: u16, wide: bool) -> Result<usize> {
     let original_pc = original_pc as usize;
     let mut new_buffer: Buffer = buffer[..original_pc].into();
     let rest_buffer: Buffer = buffer[original_pc..].into();
 
     // Convert the target program counter to its byte representation.
-    let target = target_pc.to_ls_bytes();
+    let mut target = target_pc.to_ls_bytes();
+    if wide && target.len() == 1 {
+        target.insert(0, 0);
+    }
+
     if target.len() == 1 {
         new_buffer.push(OpCode::PUSH1.into());
     } else {
@@ -116,7 +122,7 @@ fn pc(buffer: &mut Buffer, original_pc: u16, target_pc: u16) -> Result<usize> {
 
     // Check if the new buffer size exceeds the defined limit.
     if new_buffer.len() > BUFFER_LIMIT {
-        return Err(Error::BufferOverflow(new_buffer.len()));
+        return Err(Error::BytecodeTooLarge(new_buffer.len()));
     }
 
     // Update the original buffer with the new contents.
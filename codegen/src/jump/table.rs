@@ -16,6 +16,9 @@ pub struct JumpTable {
     pub(crate) func: BTreeMap<u32, u16>,
     /// Code section associated with the jump table.
     pub(crate) code: Code,
+    /// Source map of the program counters to the offsets of their
+    /// originating WASM instructions.
+    pub(crate) source: Vec<(u16, usize)>,
 }
 
 impl JumpTable {
@@ -53,6 +56,54 @@ impl JumpTable {
         self.jump.insert(pc, Jump::Label(label));
     }
 
+    /// Registers an entry of a jump table jumping to the label.
+    pub fn entry(&mut self, pc: u16, label: u16) {
+        self.jump.insert(pc, Jump::Entry(label));
+    }
+
+    /// Registers the offset of the WASM instruction emitted at the
+    /// program counter.
+    ///
+    /// The instructions may fold the code of the previous ones, their
+    /// program counters are lowered to the program counter as well.
+    pub fn source(&mut self, pc: u16, offset: usize) {
+        for (at, _) in self.source.iter_mut().rev() {
+            if *at <= pc {
+                break;
+            }
+
+            *at = pc;
+        }
+
+        self.source.push((pc, offset));
+    }
+
+    /// Swaps the source entries of the adjacent instructions at `lhs`
+    /// and `rhs` after their code has been reordered, `size` is the
+    /// size of the code of the instruction at `rhs`.
+    pub fn swap_source(&mut self, lhs: u16, rhs: u16, size: u16) {
+        let find = |pc| self.source.iter().rposition(|(at, _)| *at == pc);
+        let (Some(l), Some(r)) = (find(lhs), find(rhs)) else {
+            return;
+        };
+
+        let (_, offset) = self.source.remove(r);
+        for (at, _) in &mut self.source[l..r] {
+            *at = lhs + size;
+        }
+
+        self.source.insert(l, (lhs, offset));
+    }
+
+    /// Get the source map of the program counters to the offsets of
+    /// their originating WASM instructions, ordered by program counter.
+    pub fn source_map(&self) -> Vec<(usize, usize)> {
+        self.source
+            .iter()
+            .map(|(pc, offset)| (*pc as usize, *offset))
+            .collect()
+    }
+
     /// Registers a label at a specific program counter offset.
     pub fn offset(&mut self, pc: u16, offset: u16) {
         self.jump.insert(pc, Jump::Offset(offset));
@@ -83,6 +134,13 @@ impl JumpTable {
             self.code.try_add_func(func);
         }
 
+        self.source.extend(
+            table
+                .source
+                .into_iter()
+                .map(|(source, offset)| (source + pc, offset)),
+        );
+
         Ok(())
     }
 
@@ -94,8 +152,8 @@ impl JumpTable {
     /// Get the max target from the current jump table
     pub fn max_target(&self) -> u16 {
         self.jump
-            .iter()
-            .filter_map(|(_, jump)| self.target(jump).ok())
+            .values()
+            .filter_map(|jump| self.target(jump).ok())
             .max()
             .unwrap_or(0)
     }
@@ -16,7 +16,7 @@ impl JumpTable {
     pub fn target(&self, jump: &Jump) -> Result<u16> {
         match jump {
             Jump::Offset(offset) => Ok(*offset),
-            Jump::Label(label) => Ok(*label),
+            Jump::Label(label) | Jump::Entry(label) => Ok(*label),
             Jump::Func(func) => Ok(*self.func.get(func).ok_or(Error::FuncNotFound(*func))?),
             Jump::ExtFunc(ext) => Ok(self.code.offset_of(ext).ok_or(Error::ExtFuncNotFound)?),
         }
@@ -39,7 +39,7 @@ impl JumpTable {
                 target += original_pc;
             } */
 
-            let offset = if target > 0xff {
+            let offset = if target > 0xff || jump.is_entry() {
                 3 // Requires 3 bytes for processing the JUMP target offset
             } else {
                 2 // Requires 2 bytes
@@ -92,7 +92,7 @@ impl JumpTable {
         }
 
         self.jump.iter_mut().try_for_each(|(pc, jump)| {
-            if let Jump::Label(target) = jump {
+            if let Jump::Label(target) | Jump::Entry(target) = jump {
                 let next_target = *target + offset;
 
                 if *target > ptr {
@@ -0,0 +1,60 @@
+//! Storage layout
+//!
+//! The slots of the storages declared with `#[zink::storage]` are
+//! emitted to the compiler by the exported `<storage>_storage_layout` functions.
+//!
+//! Zink doesn't pack storages, every storage occupies a slot of its own,
+//! so the byte offset is always `0` and the width is the size of the
+//! value in the slot.
+
+use crate::{Error, Result};
+use core::{fmt, str::FromStr};
+
+/// Slot assignment of a storage.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SlotInfo {
+    /// Name of the storage.
+    pub name: String,
+    /// Storage slot.
+    pub slot: u32,
+    /// Byte offset of the value in the slot.
+    pub offset: u8,
+    /// Width of the value in bytes.
+    pub width: u8,
+}
+
+impl FromStr for SlotInfo {
+    type Err = Error;
+
+    /// Parse the slot info emitted as `name:slot:offset:width`.
+    fn from_str(info: &str) -> Result<Self> {
+        let invalid = || Error::InvalidSlotInfo(info.into());
+        let mut fields = info.split(':');
+        let mut next = || fields.next().ok_or_else(invalid);
+
+        let name = next()?.into();
+        let slot = next()?.parse().map_err(|_| invalid())?;
+        let offset = next()?.parse().map_err(|_| invalid())?;
+        let width = next()?.parse().map_err(|_| invalid())?;
+        if fields.next().is_some() {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            name,
+            slot,
+            offset,
+            width,
+        })
+    }
+}
+
+impl fmt::Display for SlotInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}:{}",
+            self.name, self.slot, self.offset, self.width
+        )
+    }
+}
@@ -4,12 +4,14 @@
 
 pub use crate::{
     asm::Assembler,
-    codegen::{Code, Constructor, Dispatcher, Function, InitStorage},
+    codegen::{Code, Constructor, Dispatcher, ExtFunc, Function, Immutables, InitStorage},
     control::{ControlStack, ControlStackFrame, ControlStackFrameType},
     jump::JumpTable,
     local::{LocalSlot, Locals},
     masm::MacroAssembler,
+    pool::Pool,
     result::{Error, Result},
+    verify::verify,
 };
 use smallvec::SmallVec;
 
@@ -17,13 +19,18 @@ mod asm;
 mod backtrace;
 mod codegen;
 mod control;
+mod induction;
 mod jump;
+pub mod layout;
 mod local;
 mod masm;
+mod pool;
 mod result;
 mod validator;
+mod verify;
 mod visitor;
 pub mod wasm;
+mod width;
 
 /// Maximum size of a evm bytecode in bytes.
 pub const BUFFER_LIMIT: usize = 0x6000;
@@ -20,6 +20,11 @@ pub enum LocalSlotType {
 ///
 /// Represents the type, location and addressing mode of a local
 /// in the stack's local and argument area.
+///
+/// Locals take a word each whatever their types are, the floats are
+/// kept as their IEEE 754 bit patterns in the low bits of the words,
+/// so they are stored and loaded bit-exact, including the payloads of
+/// NaNs and the sign of zeros.
 #[derive(Debug)]
 pub struct LocalSlot {
     /// The type contained by this local slot.
@@ -6,50 +6,42 @@ use opcodes::ShangHai as OpCode;
 impl MacroAssembler {
     /// Greater than or equal comparison.
     ///
-    /// a b ge -> a b-1 gt(lt)
+    /// a b ge -> a b lt iszero
     ///
-    /// Using lt due to order of stack.
+    /// Using gt due to order of stack.
     pub fn _ge(&mut self) -> Result<()> {
-        self.push(&[1])?;
-        // NOTE: this is the overridden sub but not `self.asm.sub`
-        self._sub()?;
-        self.asm._lt()
+        self.asm._gt()?;
+        self.negate()
     }
 
-    /// Greater than or equal comparison.
+    /// Signed greater than or equal comparison.
     ///
-    /// a b sge -> a b-1 sgt(slt)
+    /// a b sge -> a b slt iszero
     ///
-    /// Using lt due to order of stack.
+    /// Using sgt due to order of stack.
     pub fn _sge(&mut self) -> Result<()> {
-        self.push(&[1])?;
-        // NOTE: this is the overridden sub but not `self.asm.sub`
-        self._sub()?;
-        self.asm._slt()
+        self.asm._sgt()?;
+        self.negate()
     }
 
-    /// Greater than or equal comparison.
+    /// Signed less than or equal comparison.
     ///
-    /// a b sge -> a b-1 sgt(slt)
+    /// a b sle -> a b sgt iszero
     ///
-    /// Using lt due to order of stack.
+    /// Using slt due to order of stack.
     pub fn _sle(&mut self) -> Result<()> {
-        self.push(&[1])?;
-        // NOTE: this is the overridden sub but not `self.asm.sub`
-        self._sub()?;
-        self.asm._slt()
+        self.asm._slt()?;
+        self.negate()
     }
 
-    /// Greater than or equal comparison.
+    /// Less than or equal comparison.
     ///
-    /// a b le -> a b-1 lt(gt)
+    /// a b le -> a b gt iszero
     ///
-    /// Using gt due to order of stack.
+    /// Using lt due to order of stack.
     pub fn _le(&mut self) -> Result<()> {
-        self.push(&[1])?;
-        // NOTE: this is the overridden sub but not `self.asm.sub`
-        self._sub()?;
-        self.asm._lt()
+        self.asm._lt()?;
+        self.negate()
     }
 
     /// Greater than and equal comparison.
@@ -83,13 +75,33 @@ impl MacroAssembler {
     /// Sign-agnostic compare unequal.
     pub fn _ne(&mut self) -> Result<()> {
         self.emit_op(OpCode::EQ)?;
-        self.emit_op(OpCode::ISZERO)?;
-        Ok(())
+        self.negate()
     }
 
     /// Simple not operator
     pub fn _eqz(&mut self) -> Result<()> {
+        self.negate()
+    }
+
+    /// Emit the condition of the branching constructs, which jump
+    /// over their branch if the condition is zero.
+    ///
+    /// If the condition has just been negated, the negation is
+    /// dropped instead of stacking another `ISZERO`, `JUMPI` takes
+    /// any non-zero value as true.
+    pub fn _cond_iszero(&mut self) -> Result<()> {
+        if self.negated.take() == Some(self.pc_offset()) {
+            self.asm.buffer_mut().pop();
+            return Ok(());
+        }
+
+        self._iszero()
+    }
+
+    /// Negate the value, records the position for [`Self::_cond_iszero`].
+    fn negate(&mut self) -> Result<()> {
         self.emit_op(OpCode::ISZERO)?;
+        self.negated = Some(self.pc_offset());
         Ok(())
     }
 }
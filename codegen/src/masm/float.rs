@@ -1,76 +1,267 @@
 //! Float Instructions
 
-use crate::{MacroAssembler, Result};
+use crate::{wasm::ToLSBytes, Error, MacroAssembler, Result};
+
+/// IEEE 754 binary formats of floats.
+#[derive(Clone, Copy)]
+enum Float {
+    F32,
+    F64,
+}
+
+impl Float {
+    /// Bits of the exponent.
+    fn exponent(&self) -> u32 {
+        match self {
+            Self::F32 => 8,
+            Self::F64 => 11,
+        }
+    }
+
+    /// Bits of the mantissa.
+    fn mantissa(&self) -> u32 {
+        match self {
+            Self::F32 => 23,
+            Self::F64 => 52,
+        }
+    }
+
+    /// Bias of the exponent.
+    fn bias(&self) -> u32 {
+        (1 << (self.exponent() - 1)) - 1
+    }
+}
+
+/// Define the float to integer truncations.
+macro_rules! trunc {
+    ($($name:ident: ($float:ident, $bits:expr, $signed:expr, $saturating:expr)),+) => {
+        impl MacroAssembler {
+            $(
+                #[doc = concat!("Emit `", stringify!($name), "`, see [`MacroAssembler::float_to_int`].")]
+                pub fn $name(&mut self) -> Result<()> {
+                    self.float_to_int(Float::$float, $bits, $signed, $saturating)
+                }
+            )+
+        }
+    };
+}
+
+trunc! {
+    _i32_trunc_f32_s: (F32, 32, true, false),
+    _i32_trunc_f32_u: (F32, 32, false, false),
+    _i32_trunc_f64_s: (F64, 32, true, false),
+    _i32_trunc_f64_u: (F64, 32, false, false),
+    _i64_trunc_f32_s: (F32, 64, true, false),
+    _i64_trunc_f32_u: (F32, 64, false, false),
+    _i64_trunc_f64_s: (F64, 64, true, false),
+    _i64_trunc_f64_u: (F64, 64, false, false),
+    _i32_trunc_sat_f32_s: (F32, 32, true, true),
+    _i32_trunc_sat_f32_u: (F32, 32, false, true),
+    _i32_trunc_sat_f64_s: (F64, 32, true, true),
+    _i32_trunc_sat_f64_u: (F64, 32, false, true),
+    _i64_trunc_sat_f32_s: (F32, 64, true, true),
+    _i64_trunc_sat_f32_u: (F32, 64, false, true),
+    _i64_trunc_sat_f64_s: (F64, 64, true, true),
+    _i64_trunc_sat_f64_u: (F64, 64, false, true)
+}
 
 impl MacroAssembler {
     /// Maximum of two values
     pub fn _max(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("max".into()))
     }
 
     /// Minimum of two values
     pub fn _min(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("min".into()))
     }
 
     /// Ceiling operator
     pub fn _ceil(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("ceil".into()))
     }
 
     /// Floor operator
     pub fn _floor(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("floor".into()))
     }
 
     /// Round to nearest integer, ties to even.
     pub fn _nearest(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("nearest".into()))
     }
 
     /// Square root
     pub fn _sqrt(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("sqrt".into()))
     }
 
     /// Absolute value
     pub fn _abs(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("abs".into()))
     }
 
     /// Negation
     pub fn _neg(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("neg".into()))
     }
 
     /// If z1 and z2 have the same sign, return z1, otherwise
     /// return z1 with negated sign.
     pub fn _copysign(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("copysign".into()))
     }
 
     /// Convert a signed 32-bit integer to a (32-bit/64-bit) float
     pub fn _convert_i32_s(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("convert_i32_s".into()))
     }
 
     /// Convert an unsigned 32-bit integer to a (32-bit/64-bit) float
     pub fn _convert_i32_u(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("convert_i32_u".into()))
     }
 
     /// Convert a signed 32-bit integer to a (32-bit/64-bit) float
     pub fn _convert_i64_s(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("convert_i64_s".into()))
     }
 
     /// Convert a unsigned 32-bit integer to a (32-bit/64-bit) float
     pub fn _convert_i64_u(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("convert_i64_u".into()))
     }
 
     /// Round to nearest integer towards zero
     pub fn _trunc(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("trunc".into()))
+    }
+
+    /// Truncate the float on the top of the stack to an integer of
+    /// `bits` bits.
+    ///
+    /// Traps on NaN or values out of the range of the integer type,
+    /// the saturating variants return `0` for NaN and clamp the other
+    /// values to the range instead.
+    ///
+    /// The float is decoded from its bit pattern without branches,
+    /// traps are emitted as `JUMPI` to an invalid destination.
+    fn float_to_int(
+        &mut self,
+        float: Float,
+        bits: u32,
+        signed: bool,
+        saturating: bool,
+    ) -> Result<()> {
+        let (exponent, mantissa, bias) = (float.exponent(), float.mantissa(), float.bias());
+        let mask = u128::MAX >> (128 - bits);
+        let half = 1u128 << (bits - 1);
+        let (limit, limit_neg, max, min) = if signed {
+            (half, half + 1, half - 1, half)
+        } else {
+            (mask + 1, 1, mask, 0)
+        };
+
+        // STACK: [x, sign, exp, mant]
+        self.asm._dup1()?;
+        self.push(&(exponent + mantissa).to_ls_bytes())?;
+        self.asm._shr()?;
+        self.asm._dup2()?;
+        self.push(&mantissa.to_ls_bytes())?;
+        self.asm._shr()?;
+        self.push(&((1u32 << exponent) - 1).to_ls_bytes())?;
+        self.asm._and()?;
+        self.asm._dup3()?;
+        self.push(&((1u64 << mantissa) - 1).to_ls_bytes())?;
+        self.asm._and()?;
+
+        // STACK: [x, sign, exp, mant, mag]
+        //
+        // mag = sig << (exp - bias - mantissa) | sig >> (bias + mantissa - exp),
+        // the shift underflowing in one of them results 0.
+        self.asm._dup1()?;
+        self.push(&(1u64 << mantissa).to_ls_bytes())?;
+        self.asm._or()?;
+        self.asm._dup1()?;
+        self.push(&(bias + mantissa).to_ls_bytes())?;
+        self.asm._dup5()?;
+        self.asm._sub()?;
+        self.asm._shl()?;
+        self.asm._swap1()?;
+        self.asm._dup4()?;
+        self.push(&(bias + mantissa).to_ls_bytes())?;
+        self.asm._sub()?;
+        self.asm._shr()?;
+        self.asm._or()?;
+
+        // STACK: [x, sign, exp, mant, mag, ok]
+        //
+        // ok = mag < (sign ? limit_neg : limit) && exp < bias + bits
+        self.push(&(limit ^ limit_neg).to_ls_bytes())?;
+        self.asm._dup5()?;
+        self.asm._mul()?;
+        self.push(&limit.to_ls_bytes())?;
+        self.asm._xor()?;
+        self.asm._dup2()?;
+        self.asm._lt()?;
+        self.push(&(bias + bits).to_ls_bytes())?;
+        self.asm._dup5()?;
+        self.asm._lt()?;
+        self.asm._and()?;
+
+        // STACK: [x, sign, exp, mant, mag, ok, value]
+        //
+        // value = ((mag ^ (sign * mask)) + sign) & mask
+        self.push(&mask.to_ls_bytes())?;
+        self.asm._dup6()?;
+        self.asm._mul()?;
+        self.asm._dup3()?;
+        self.asm._xor()?;
+        self.asm._dup6()?;
+        self.asm._add()?;
+        self.push(&mask.to_ls_bytes())?;
+        self.asm._and()?;
+
+        if saturating {
+            // STACK: [x, sign, exp, mant, mag, ok * value, !ok]
+            self.asm._dup2()?;
+            self.asm._mul()?;
+            self.asm._swap1()?;
+            self.asm._iszero()?;
+
+            // STACK: [x, sign, exp, mant, mag, ok * value, !ok, sign ? min : max]
+            self.push(&(max ^ min).to_ls_bytes())?;
+            self.asm._dup7()?;
+            self.asm._mul()?;
+            self.push(&max.to_ls_bytes())?;
+            self.asm._xor()?;
+
+            // NaN saturates to 0.
+            self.asm._dup5()?;
+            self.asm._iszero()?;
+            self.asm._iszero()?;
+            self.push(&((1u32 << exponent) - 1).to_ls_bytes())?;
+            self.asm._dup8()?;
+            self.asm._eq()?;
+            self.asm._and()?;
+            self.asm._iszero()?;
+            self.asm._mul()?;
+            self.asm._mul()?;
+            self.asm._add()?;
+        } else {
+            self.asm._swap1()?;
+            self.asm._iszero()?;
+            self.asm._push0()?;
+            self.asm._not()?;
+            self.asm._jumpi()?;
+        }
+
+        // STACK: [result]
+        self.asm._swap5()?;
+        for _ in 0..5 {
+            self.asm._pop()?;
+        }
+
+        Ok(())
     }
 }
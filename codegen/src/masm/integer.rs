@@ -1,6 +1,7 @@
 // Integer instructions
 
-use crate::{wasm::ToLSBytes, MacroAssembler, Result};
+use crate::{wasm::ToLSBytes, Error, MacroAssembler, Result};
+use opcodes::ShangHai as OpCode;
 use wasmparser::{Ieee32, Ieee64};
 
 impl MacroAssembler {
@@ -10,99 +11,345 @@ impl MacroAssembler {
         self.asm._sub()
     }
 
+    /// Apply the arithmetic operation on 128-bit integers, the result
+    /// is wrapped to 128 bits.
+    ///
+    /// The operands fit in the words of EVM, so the operation is a
+    /// single opcode instead of the 64-bit limbs of WASM.
+    pub fn u128_op(&mut self, op: OpCode) -> Result<()> {
+        self.emit_op(op)?;
+        self.wrap(16)
+    }
+
+    /// Wrap the value on the top of the stack to `bytes` bytes.
+    pub fn wrap(&mut self, bytes: usize) -> Result<()> {
+        self.push(&[0xff; 32][..bytes])?;
+        self.asm._and()
+    }
+
+    /// Wrap the value at `depth` from the top of the stack to `bytes`
+    /// bytes.
+    pub fn wrap_at(&mut self, depth: u8, bytes: usize) -> Result<()> {
+        self.swap(depth)?;
+        self.wrap(bytes)?;
+        self.swap(depth)
+    }
+
+    /// Clean the bytes of the value on the top of the stack following
+    /// its leading `bytes` bytes, which left-aligns `bytesN` in the
+    /// word.
+    pub fn left_align(&mut self, bytes: usize) -> Result<()> {
+        if bytes >= 32 {
+            return Ok(());
+        }
+
+        let mut mask = [0; 32];
+        mask[..bytes].fill(0xff);
+        self.push(&mask)?;
+        self.asm._and()
+    }
+
     /// Push a 32-bit integer value on the stack.
+    ///
+    /// Negative values are pushed in the two's complement of 32 bits,
+    /// the signed operations sign-extend their operands.
     pub fn _i32_const(&mut self, value: i32) -> Result<()> {
-        if value == 0 {
-            self._push0()
-        } else {
-            self.push(value.to_ls_bytes().as_ref())
-        }
+        self.push_bits(value as u32 as u64)
     }
 
     /// Push a 64-bit integer value on the stack.
+    ///
+    /// Negative values are pushed in the two's complement of 64 bits,
+    /// see [`Self::_i32_const`].
     pub fn _i64_const(&mut self, value: i64) -> Result<()> {
-        if value == 0 {
-            self._push0()
-        } else {
-            self.push(value.to_ls_bytes().as_ref())
-        }
+        self.push_bits(value as u64)
     }
 
     /// Push a 32-bit float value on the stack.
-    pub fn _f32_const(&mut self, _value: Ieee32) -> Result<()> {
-        todo!()
+    ///
+    /// Floats are represented by their IEEE 754 bit patterns, the bits
+    /// of the immediate are pushed as they are so the payloads of NaNs
+    /// are kept without canonicalization as WASM requires.
+    pub fn _f32_const(&mut self, value: Ieee32) -> Result<()> {
+        self.push_bits(value.bits() as u64)
     }
 
     /// Push a 64-bit float value on the stack.
-    pub fn _f64_const(&mut self, _value: Ieee64) -> Result<()> {
-        todo!()
+    ///
+    /// Floats are represented by their IEEE 754 bit patterns, see
+    /// [`Self::_f32_const`].
+    pub fn _f64_const(&mut self, value: Ieee64) -> Result<()> {
+        self.push_bits(value.bits())
+    }
+
+    /// Push the bits as an unsigned value on the stack.
+    fn push_bits(&mut self, bits: u64) -> Result<()> {
+        if bits == 0 {
+            self._push0()
+        } else {
+            self.push(bits.to_ls_bytes().as_ref())
+        }
     }
 
     /// wrap a 64-bit integer to a 32-bit integer.
     pub fn _i32_wrap_i64(&mut self) -> Result<()> {
-        todo!()
+        self.wrap(4)
     }
 
     /// Extend a signed 32-bit integer to a 64-bit integer.
+    ///
+    /// The value may carry bits above 32 bits, it is sign-extended
+    /// from bit 31 and masked to 64 bits.
     pub fn _i64_extend_i32_s(&mut self) -> Result<()> {
-        todo!()
+        self.push(&[3])?;
+        self.asm._signextend()?;
+        self.wrap(8)
     }
 
-    /// Extend an unsigned 32-bit integer to a 64-bit integer.
+    /// Extend an unsigned 32-bit integer to a 64-bit integer, the bits
+    /// above 32 bits are dropped.
     pub fn _i64_extend_i32_u(&mut self) -> Result<()> {
-        todo!()
+        self.wrap(4)
+    }
+
+    /// Sign-extend the low 8 bits of a 32-bit integer.
+    pub fn _i32_extend8_s(&mut self) -> Result<()> {
+        self.sign_extend(1)
+    }
+
+    /// Sign-extend the low 16 bits of a 32-bit integer.
+    pub fn _i32_extend16_s(&mut self) -> Result<()> {
+        self.sign_extend(2)
+    }
+
+    /// Sign-extend the low 8 bits of a 64-bit integer.
+    pub fn _i64_extend8_s(&mut self) -> Result<()> {
+        self.sign_extend(1)
+    }
+
+    /// Sign-extend the low 16 bits of a 64-bit integer.
+    pub fn _i64_extend16_s(&mut self) -> Result<()> {
+        self.sign_extend(2)
+    }
+
+    /// Sign-extend the low 32 bits of a 64-bit integer.
+    pub fn _i64_extend32_s(&mut self) -> Result<()> {
+        self.sign_extend(4)
+    }
+
+    /// Sign-extend the low `bytes` bytes of the value on the top of the
+    /// stack to 256 bits for the signed operations.
+    pub fn sign_extend(&mut self, bytes: u8) -> Result<()> {
+        self.push(&[bytes - 1])?;
+        self.asm._signextend()
     }
 
     /// Truncate a 64-bit float to a signed 32-bit integer.
     pub fn _f32_demote_f64(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("f32.demote_f64".into()))
+    }
+
+    /// Signed division of 32-bit integers.
+    pub fn _i32_div_s(&mut self) -> Result<()> {
+        self.div_s(4)
+    }
+
+    /// Unsigned division of 32-bit integers.
+    pub fn _i32_div_u(&mut self) -> Result<()> {
+        self.div_u(4)
+    }
+
+    /// Signed division of 64-bit integers.
+    pub fn _i64_div_s(&mut self) -> Result<()> {
+        self.div_s(8)
+    }
+
+    /// Unsigned division of 64-bit integers.
+    pub fn _i64_div_u(&mut self) -> Result<()> {
+        self.div_u(8)
+    }
+
+    /// Signed division of integers of `bytes` bytes.
+    ///
+    /// The operands may carry bits above their width from the previous
+    /// operations, both are sign-extended to 256 bits for `SDIV` and the
+    /// quotient is wrapped back.
+    fn div_s(&mut self, bytes: u8) -> Result<()> {
+        // STACK: [dividend, divisor]
+        self.push(&[bytes - 1])?;
+        self.asm._signextend()?;
+        self.asm._swap1()?;
+        self.push(&[bytes - 1])?;
+        self.asm._signextend()?;
+        self.asm._sdiv()?;
+        self.push(&[0xff; 8][..bytes as usize])?;
+        self.asm._and()
+    }
+
+    /// Unsigned division of integers of `bytes` bytes.
+    ///
+    /// The operands may carry bits above their width from the previous
+    /// operations, both are masked to the width for `DIV`.
+    fn div_u(&mut self, bytes: u8) -> Result<()> {
+        // STACK: [dividend, divisor]
+        self.push(&[0xff; 8][..bytes as usize])?;
+        self.asm._and()?;
+        self.asm._swap1()?;
+        self.push(&[0xff; 8][..bytes as usize])?;
+        self.asm._and()?;
+        self.asm._div()
+    }
+
+    /// Signed remainder of 32-bit integers.
+    pub fn _i32_rem_s(&mut self) -> Result<()> {
+        self.rem_s(4)
+    }
+
+    /// Unsigned remainder of 32-bit integers.
+    pub fn _i32_rem_u(&mut self) -> Result<()> {
+        self.rem_u(4)
+    }
+
+    /// Signed remainder of 64-bit integers.
+    pub fn _i64_rem_s(&mut self) -> Result<()> {
+        self.rem_s(8)
+    }
+
+    /// Unsigned remainder of 64-bit integers.
+    pub fn _i64_rem_u(&mut self) -> Result<()> {
+        self.rem_u(8)
+    }
+
+    /// Signed remainder of integers of `bytes` bytes.
+    ///
+    /// Both operands are sign-extended to 256 bits for `SMOD`, the
+    /// remainder takes the sign of the dividend as WASM requires, e.g.
+    /// `-7 % 3 == -1`, and is wrapped back to the width.
+    fn rem_s(&mut self, bytes: u8) -> Result<()> {
+        // STACK: [dividend, divisor]
+        self.push(&[bytes - 1])?;
+        self.asm._signextend()?;
+        self.asm._swap1()?;
+        self.push(&[bytes - 1])?;
+        self.asm._signextend()?;
+        self.asm._smod()?;
+        self.push(&[0xff; 8][..bytes as usize])?;
+        self.asm._and()
+    }
+
+    /// Unsigned remainder of integers of `bytes` bytes.
+    ///
+    /// Both operands are masked to the width for `MOD`.
+    fn rem_u(&mut self, bytes: u8) -> Result<()> {
+        // STACK: [dividend, divisor]
+        self.push(&[0xff; 8][..bytes as usize])?;
+        self.asm._and()?;
+        self.asm._swap1()?;
+        self.push(&[0xff; 8][..bytes as usize])?;
+        self.asm._and()?;
+        self.asm._mod()
+    }
+
+    /// Arithmetic right shift of a 32-bit integer.
+    pub fn _i32_shr_s(&mut self) -> Result<()> {
+        self.shr_s(4)
+    }
+
+    /// Logical right shift of a 32-bit integer.
+    pub fn _i32_shr_u(&mut self) -> Result<()> {
+        self.shr_u(4)
+    }
+
+    /// Arithmetic right shift of a 64-bit integer.
+    pub fn _i64_shr_s(&mut self) -> Result<()> {
+        self.shr_s(8)
+    }
+
+    /// Logical right shift of a 64-bit integer.
+    pub fn _i64_shr_u(&mut self) -> Result<()> {
+        self.shr_u(8)
+    }
+
+    /// Arithmetic right shift of an integer of `bytes` bytes.
+    ///
+    /// The value is sign-extended to 256 bits for `SAR` and wrapped
+    /// back afterwards, the shift count is taken modulo the bit width
+    /// as WASM requires.
+    fn shr_s(&mut self, bytes: u8) -> Result<()> {
+        // STACK: [value, shift]
+        self.push(&[bytes * 8 - 1])?;
+        self.asm._and()?;
+        self.asm._swap1()?;
+        self.push(&[bytes - 1])?;
+        self.asm._signextend()?;
+        self.asm._swap1()?;
+        self.asm._sar()?;
+        self.push(&[0xff; 8][..bytes as usize])?;
+        self.asm._and()
+    }
+
+    /// Logical right shift of an integer of `bytes` bytes.
+    ///
+    /// The value is masked to the width for `SHR`, the bits over the
+    /// width, e.g. the carry of a wrapped addition, are not shifted
+    /// in. The shift count is taken modulo the bit width as WASM
+    /// requires.
+    fn shr_u(&mut self, bytes: u8) -> Result<()> {
+        // STACK: [value, shift]
+        self.push(&[bytes * 8 - 1])?;
+        self.asm._and()?;
+        self.asm._swap1()?;
+        self.push(&[0xff; 8][..bytes as usize])?;
+        self.asm._and()?;
+        self.asm._swap1()?;
+        self.asm._shr()
     }
 
     /// Truncate a 64-bit float to an unsigned 32-bit integer.
     pub fn _f64_promote_f32(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("f64.promote_f32".into()))
     }
 
-    /// Convert a signed 32-bit integer to a 32-bit float.
+    /// Reinterpret the bits of a 32-bit float as a 32-bit integer.
+    ///
+    /// No-op since floats are represented by their bit patterns.
     pub fn _i32_reinterpret_f32(&mut self) -> Result<()> {
-        todo!()
+        Ok(())
     }
 
-    /// Convert a signed 64-bit integer to a 64-bit float.
+    /// Reinterpret the bits of a 64-bit float as a 64-bit integer.
+    ///
+    /// No-op since floats are represented by their bit patterns.
     pub fn _i64_reinterpret_f64(&mut self) -> Result<()> {
-        todo!()
+        Ok(())
     }
 
-    /// Convert a 32-bit float to a signed 32-bit integer.
+    /// Reinterpret the bits of a 32-bit integer as a 32-bit float.
+    ///
+    /// No-op since floats are represented by their bit patterns.
     pub fn _f32_reinterpret_i32(&mut self) -> Result<()> {
-        todo!()
+        Ok(())
     }
 
-    /// Convert a 64-bit float to a signed 64-bit integer.
+    /// Reinterpret the bits of a 64-bit integer as a 64-bit float.
+    ///
+    /// No-op since floats are represented by their bit patterns.
     pub fn _f64_reinterpret_i64(&mut self) -> Result<()> {
-        todo!()
+        Ok(())
     }
 
     /// sign-agnostic rotate left
     ///
     /// Return the result of rotating i1 left by k bits.
     pub fn _rotl(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("rotl".into()))
     }
 
     /// sign-agnostic rotate right
     ///
     /// Return the result of rotating i1 right by k bits.
     pub fn _rotr(&mut self) -> Result<()> {
-        todo!()
-    }
-
-    /// sign-agnostic count leading zero bits
-    ///
-    /// Return the number of leading zero bits in i, all zero bits
-    /// are considered leading if the value is zero.
-    pub fn _clz(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("rotr".into()))
     }
 
     /// sign-agnostic count leading zero bits
@@ -110,23 +357,75 @@ impl MacroAssembler {
     /// Return the number of leading zero bits in i, all zero bits
     /// are considered trailing if the value is zero.
     pub fn _ctz(&mut self) -> Result<()> {
-        todo!()
+        Err(Error::UnsupportedOperator("ctz".into()))
     }
 
-    /// sign-agnostic count number of one bits
+    /// sign-agnostic count number of one bits of a 32-bit integer
     ///
     /// Return the count of no zero bits in i.
-    pub fn _popcnt(&mut self) -> Result<()> {
-        todo!()
+    pub fn _i32_popcnt(&mut self) -> Result<()> {
+        self.popcnt(4)
     }
 
-    /// Truncate a 32-bit float to an integer
-    pub fn _trunc_f32(&mut self) -> Result<()> {
-        todo!()
+    /// sign-agnostic count number of one bits of a 64-bit integer
+    ///
+    /// Return the count of no zero bits in i.
+    pub fn _i64_popcnt(&mut self) -> Result<()> {
+        self.popcnt(8)
     }
 
-    /// Truncate a 64-bit float to an integer
-    pub fn _trunc_f64(&mut self) -> Result<()> {
-        todo!()
+    /// Count the one bits in the lowest `width` bytes of the top
+    /// stack item with SWAR (SIMD within a register).
+    ///
+    /// The sequence is branch-free, so the gas cost doesn't depend
+    /// on the input value.
+    ///
+    /// ```text
+    /// x = x - ((x >> 1) & 0x55..)
+    /// x = (x & 0x33..) + ((x >> 2) & 0x33..)
+    /// x = (x + (x >> 4)) & 0x0f..
+    /// x = ((x * 0x01..) >> (width - 1) * 8) & 0xff
+    /// ```
+    fn popcnt(&mut self, width: usize) -> Result<()> {
+        let mask = |byte: u8| vec![byte; width];
+
+        // Drop the bits out of the width of the integer.
+        self.push(&mask(0xff))?;
+        self.asm._and()?;
+
+        // x - ((x >> 1) & 0x55..)
+        self._dup1()?;
+        self.push(&[1])?;
+        self.asm._shr()?;
+        self.push(&mask(0x55))?;
+        self.asm._and()?;
+        self._sub()?;
+
+        // (x & 0x33..) + ((x >> 2) & 0x33..)
+        self._dup1()?;
+        self.push(&mask(0x33))?;
+        self.asm._and()?;
+        self._swap1()?;
+        self.push(&[2])?;
+        self.asm._shr()?;
+        self.push(&mask(0x33))?;
+        self.asm._and()?;
+        self.asm._add()?;
+
+        // (x + (x >> 4)) & 0x0f..
+        self._dup1()?;
+        self.push(&[4])?;
+        self.asm._shr()?;
+        self.asm._add()?;
+        self.push(&mask(0x0f))?;
+        self.asm._and()?;
+
+        // ((x * 0x01..) >> (width - 1) * 8) & 0xff
+        self.push(&mask(0x01))?;
+        self.asm._mul()?;
+        self.push(&[((width - 1) * 8) as u8])?;
+        self.asm._shr()?;
+        self.push(&[0xff])?;
+        self.asm._and()
     }
 }
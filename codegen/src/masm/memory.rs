@@ -1,70 +1,197 @@
 //! Memory Instructions
 
-use crate::{MacroAssembler, Result};
+use crate::{wasm::ToLSBytes, MacroAssembler, Result};
 
 impl MacroAssembler {
-    /// Load n bytes to extend self as another number type.
+    /// Load `bytes` bytes in little-endian from memory at the address
+    /// on the top of the stack plus the static `offset`.
     ///
-    /// Just for adapting the WASM instructions, this method makes
-    /// no sense for EVM since all of the numbers as U256.
-    pub(crate) fn _load(&mut self) -> Result<()> {
-        Ok(())
+    /// STACK: [address] -> [value]
+    pub fn load(&mut self, offset: u64, bytes: u8) -> Result<()> {
+        self.memory_address(offset)?;
+        self.asm._mload()?;
+        self.push(&((32 - bytes) * 8).to_ls_bytes())?;
+        self.asm._shr()?;
+        self.reverse_bytes(bytes)
     }
 
-    /// Load 1 byte to extend self as another number type.
+    /// Sign-extend the loaded value of `bytes` bytes to an integer of
+    /// `width` bytes.
     ///
-    /// Just for adapting the WASM instructions, this method makes
-    /// no sense for EVM since all of the numbers as U256.
-    pub(crate) fn _load8(&mut self) -> Result<()> {
-        Ok(())
+    /// STACK: [value] -> [value]
+    pub fn load_extend_s(&mut self, bytes: u8, width: u8) -> Result<()> {
+        self.push(&[bytes - 1])?;
+        self.asm._signextend()?;
+        self.push(&[0xff; 8][..width as usize])?;
+        self.asm._and()
     }
 
-    /// Load 2 bytes to extend self as another number type.
+    /// Store the low `bytes` bytes of the value on the top of the
+    /// stack in little-endian to memory at the address under it plus
+    /// the static `offset`, the bytes following them in memory are
+    /// kept.
     ///
-    /// Just for adapting the WASM instructions, this method makes
-    /// no sense for EVM since all of the numbers as U256.
-    pub(crate) fn _load16(&mut self) -> Result<()> {
-        Ok(())
+    /// STACK: [address, value] -> []
+    pub fn store(&mut self, offset: u64, bytes: u8) -> Result<()> {
+        self.reverse_bytes(bytes)?;
+        self.asm._swap1()?;
+        self.memory_address(offset)?;
+        if bytes == 1 {
+            return self.asm._mstore8();
+        }
+
+        // [value, address, word & mask]
+        self.asm._dup1()?;
+        self.asm._mload()?;
+        self.push(&vec![0xff; 32 - bytes as usize])?;
+        self.asm._and()?;
+
+        // [value, address, word & mask | value << shift]
+        let shift = (32 - bytes) * 8;
+        self.asm._dup3()?;
+        self.push(&shift.to_ls_bytes())?;
+        self.asm._shl()?;
+        self.asm._or()?;
+
+        self.asm._swap1()?;
+        self.asm._mstore()?;
+        self.asm._pop()
     }
 
-    /// Load 4 bytes to extend self as another number type.
+    /// Reverse the order of the low `bytes` bytes of the value on the
+    /// top of the stack, the bytes above them are cleared.
     ///
-    /// Just for adapting the WASM instructions, this method makes
-    /// no sense for EVM since all of the numbers as U256.
-    pub(crate) fn _load32(&mut self) -> Result<()> {
-        Ok(())
+    /// The memory of WASM is little-endian while `MLOAD` and `MSTORE`
+    /// access big-endian words, values are reversed around them.
+    ///
+    /// STACK: [value] -> [value]
+    fn reverse_bytes(&mut self, bytes: u8) -> Result<()> {
+        if bytes == 1 {
+            return Ok(());
+        }
+
+        self.asm._push0()?;
+        for i in 0..bytes {
+            // [value, reversed, byte << shift]
+            self.asm._dup2()?;
+            self.push(&[31 - i])?;
+            self.asm._byte()?;
+            self.push(&((bytes - 1 - i) * 8).to_ls_bytes())?;
+            self.asm._shl()?;
+            self.asm._or()?;
+        }
+
+        self.asm._swap1()?;
+        self.asm._pop()
     }
 
-    /// Store n bytes in memory.
-    pub fn _store(&mut self) -> Result<()> {
-        todo!()
+    /// Add the static offset of the memory instruction to the address
+    /// on the top of the stack.
+    ///
+    /// The alignment hint is ignored since the memory of EVM has no
+    /// alignment requirement.
+    fn memory_address(&mut self, offset: u64) -> Result<()> {
+        if offset == 0 {
+            return Ok(());
+        }
+
+        self.push(&offset.to_ls_bytes())?;
+        self.asm._add()
     }
 
-    /// Wrap self to i8 and store 1 byte
-    pub fn _store8(&mut self) -> Result<()> {
-        todo!()
+    /// Copy calldata into memory.
+    ///
+    /// STACK: [offset, len] -> [dest]
+    ///
+    /// The destination is the free memory, see [`Self::_free_memory`],
+    /// calldata out of `CALLDATASIZE` is zero-filled by the EVM.
+    pub fn _calldatacopy(&mut self, reserved: &[u8]) -> Result<()> {
+        self._free_memory(reserved)?;
+        self.asm._swap2()?;
+        self.asm._dup3()?;
+        self.asm._calldatacopy()
     }
 
-    /// Wrap self to i16 and store 2 bytes
-    pub fn _store16(&mut self) -> Result<()> {
-        todo!()
+    /// Push the selector of the calldata, left-aligned as `bytes4`.
+    pub fn _selector(&mut self) -> Result<()> {
+        self.asm._push0()?;
+        self.asm._calldataload()?;
+        self.push(&[0xe0])?;
+        self.asm._shr()?;
+        self.push(&[0xe0])?;
+        self.asm._shl()
     }
 
-    /// Wrap self to i32 and store 4 bytes
-    pub fn _store32(&mut self) -> Result<()> {
-        todo!()
+    /// Push the offset of the free memory.
+    ///
+    /// The free memory starts at the end of the used memory but not
+    /// lower than `reserved` which is the end of the memory slots of
+    /// the local variables, `max(msize, reserved)`.
+    pub fn _free_memory(&mut self, reserved: &[u8]) -> Result<()> {
+        self.asm._msize()?;
+        self.push(reserved)?;
+        self.max()
     }
 
-    /// The memory size instruction returns the current
-    /// size of memory.
-    pub fn _memory_size(&mut self, _: u32, _: u8) -> Result<()> {
-        todo!()
+    /// Push the size of memory in pages of 64 KiB, which is the larger
+    /// one of the initial `pages` of the WASM memory and the pages
+    /// covering the memory of EVM.
+    ///
+    /// The memory of EVM is billed in words, its size is rounded up to
+    /// the pages, e.g. 100 bytes of memory accessed are 1 page.
+    ///
+    /// STACK: [] -> [size]
+    pub fn memory_size(&mut self, pages: u64) -> Result<()> {
+        self.asm._msize()?;
+        self.push(&[0xff, 0xff])?;
+        self.asm._add()?;
+        self.push(&[16])?;
+        self.asm._shr()?;
+        self.push(&pages.to_ls_bytes())?;
+        self.max()
     }
 
-    /// The memory grow instruction grows memory by a given
-    /// delta and returns the previous size, or -1 if enough
-    /// memory cannot be allocated.
-    pub fn _memory_grow(&mut self, _: u32, _: u8) -> Result<()> {
-        todo!()
+    /// Grow memory by the delta of pages and push the previous size.
+    ///
+    /// The memory of EVM grows while being accessed, so growing never
+    /// fails with -1 but runs out of gas instead, the last word of the
+    /// grown memory is loaded for expanding the memory of EVM to the
+    /// new size.
+    ///
+    /// STACK: [delta] -> [previous]
+    pub fn memory_grow(&mut self, pages: u64) -> Result<()> {
+        self.wrap(4)?;
+        self.memory_size(pages)?;
+        self.asm._swap1()?;
+        self.asm._dup2()?;
+        self.asm._add()?;
+        self.push(&[16])?;
+        self.asm._shl()?;
+
+        // load the word ending at the new size.
+        self.push(&[0x20])?;
+        self.max()?;
+        self.push(&[0x20])?;
+        self.asm._swap1()?;
+        self.asm._sub()?;
+        self.asm._mload()?;
+        self.asm._pop()
+    }
+
+    /// Keep the larger one of the top two items of the stack.
+    ///
+    /// STACK: [a, b] -> [max(a, b)]
+    fn max(&mut self) -> Result<()> {
+        self.asm._dup2()?;
+        self.asm._dup2()?;
+        self.asm._lt()?;
+        self.asm._swap2()?;
+        self.asm._dup2()?;
+        self.asm._xor()?;
+        self.asm._dup3()?;
+        self.asm._mul()?;
+        self.asm._xor()?;
+        self.asm._swap1()?;
+        self.asm._pop()
     }
 }
@@ -3,8 +3,9 @@
 use crate::{
     asm::Assembler,
     wasm::{ToLSBytes, Type},
-    Error, Result,
+    Error, Pool, Result,
 };
+use opcodes::ShangHai as OpCode;
 use smallvec::SmallVec;
 use std::ops::{Deref, DerefMut};
 
@@ -21,6 +22,10 @@ mod stack;
 pub struct MacroAssembler {
     /// Low level assembler.
     pub(crate) asm: Assembler,
+    /// Program counter right after the last negation of a boolean.
+    negated: Option<u16>,
+    /// Constants loaded from memory instead of being pushed.
+    pool: Pool,
 }
 
 impl Deref for MacroAssembler {
@@ -84,8 +89,44 @@ impl MacroAssembler {
         self.asm.buffer().len() as u16
     }
 
-    /// Place n bytes on stack.
+    /// Place n bytes on stack, the pooled constants are loaded from
+    /// memory.
     pub fn push(&mut self, bytes: &[u8]) -> Result<()> {
+        if let Some(offset) = self.pool.get(bytes) {
+            tracing::trace!("load pooled bytes: 0x{}", hex::encode(bytes));
+            let offset = SmallVec::<[u8; 4]>::from_slice(offset);
+            self.push_bytes(&offset)?;
+            return self.asm._mload();
+        }
+
+        self.push_bytes(bytes)
+    }
+
+    /// Load the pooled constants from memory, the memory written by
+    /// the macro assembler starts after the pool.
+    pub fn use_pool(&mut self, pool: Pool) {
+        if !pool.is_empty() {
+            self.asm.mp = pool.end();
+        }
+
+        self.pool = pool;
+    }
+
+    /// Store the pooled constants in memory.
+    ///
+    /// STACK: [] -> []
+    pub fn store_pool(&mut self) -> Result<()> {
+        for (constant, offset) in self.pool.clone().iter() {
+            self.push_bytes(constant)?;
+            self.push_bytes(offset)?;
+            self.asm._mstore()?;
+        }
+
+        Ok(())
+    }
+
+    /// Place n bytes on stack without loading them from the pool.
+    fn push_bytes(&mut self, bytes: &[u8]) -> Result<()> {
         tracing::trace!("push bytes: 0x{}", hex::encode(bytes));
 
         // TODO: support PUSH0 #247
@@ -145,6 +186,19 @@ impl MacroAssembler {
         Ok(f(self.mp)?.to_ls_bytes())
     }
 
+    /// If the last emitted instruction halts the execution.
+    pub fn is_terminated(&self) -> bool {
+        matches!(
+            self.asm.last_op(),
+            Some(OpCode::INVALID | OpCode::RETURN | OpCode::REVERT | OpCode::STOP)
+        )
+    }
+
+    /// If the last emitted instruction jumps away unconditionally.
+    pub fn is_jumped(&self) -> bool {
+        self.asm.last_op() == Some(OpCode::JUMP)
+    }
+
     /// Get the stack pointer.
     pub fn sp(&self) -> u8 {
         self.asm.sp
@@ -228,16 +282,23 @@ impl MacroAssembler {
 
         Ok(())
     }
+}
 
-    /// Return zero or more values from the function.
-    ///
-    /// The return instruction is a shortcut for an unconditional
-    /// branch to the outermost block, which implicitly is the body
-    /// of the current function.
-    ///
-    /// NOTE: This `return` could be different from the `return` in
-    /// the EVM.
-    pub fn _return(&mut self) -> Result<()> {
-        todo!()
+#[cfg(test)]
+mod tests {
+    use super::MacroAssembler;
+
+    #[test]
+    fn push_data_is_not_an_opcode() {
+        let mut masm = MacroAssembler::default();
+
+        // RETURN and JUMP as the data of PUSH1.
+        masm.push(&[0xf3]).unwrap();
+        assert!(!masm.is_terminated());
+        masm.push(&[0x56]).unwrap();
+        assert!(!masm.is_jumped());
+
+        masm.asm._return().unwrap();
+        assert!(masm.is_terminated());
     }
 }
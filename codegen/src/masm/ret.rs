@@ -5,12 +5,20 @@ use wasmparser::ValType;
 
 impl MacroAssembler {
     /// Return with nothing.
+    ///
+    /// Halts with `STOP` which is cheaper than a zero-length `RETURN`.
     pub(crate) fn handle_empty_return(&mut self) -> Result<()> {
-        self._push0()?;
-        self._push0()?;
-        self.asm._return()?;
+        self.asm._stop()
+    }
 
-        Ok(())
+    /// Return with zero-length data.
+    ///
+    /// The external functions without results return as solidity
+    /// does, the callers could tell the returns from the halts.
+    pub fn empty_return(&mut self) -> Result<()> {
+        self.asm._push0()?;
+        self.asm._push0()?;
+        self.asm._return()
     }
 
     /// Handle the end of the main function.
@@ -29,6 +37,71 @@ impl MacroAssembler {
         Ok(())
     }
 
+    /// Return the dynamic array at the memory pointer on the top
+    /// of the stack.
+    ///
+    /// The array is laid out as its length followed by the elements,
+    /// the word before it is overwritten with the ABI offset `0x20`
+    /// since the memory is not used anymore after returning, the
+    /// pointer has to be at least `0x20`, see [`Self::shift_array`].
+    ///
+    /// The functions return the pointer of the array in WASM, the
+    /// `Vec` returns of `#[zink::external]` are not lowered to it yet.
+    pub fn array_return(&mut self) -> Result<()> {
+        // start = ptr - 0x20
+        self.push(&[0x20])?;
+        self.asm._dup2()?;
+        self.asm._sub()?;
+
+        // mstore(start, 0x20)
+        self.push(&[0x20])?;
+        self.asm._dup2()?;
+        self.asm._mstore()?;
+
+        // size = 0x40 + mload(ptr) * 0x20
+        self.asm._swap1()?;
+        self.asm._mload()?;
+        self.push(&[0x20])?;
+        self.asm._mul()?;
+        self.push(&[0x40])?;
+        self.asm._add()?;
+
+        self.asm._swap1()?;
+        self.asm._return()
+    }
+
+    /// Move the dynamic array at the memory pointer on the top of the
+    /// stack a word up with the identity precompile, which leaves room
+    /// for the ABI offset before the arrays in the first word of the
+    /// memory.
+    ///
+    /// STACK: [ptr] -> [ptr + 0x20]
+    pub fn shift_array(&mut self) -> Result<()> {
+        // size = 0x20 + mload(ptr) * 0x20
+        self.asm._dup1()?;
+        self.asm._mload()?;
+        self.push(&[0x20])?;
+        self.asm._mul()?;
+        self.push(&[0x20])?;
+        self.asm._add()?;
+
+        // staticcall(gas, 0x04, ptr, size, ptr + 0x20, size)
+        self.asm._dup1()?;
+        self.asm._dup3()?;
+        self.push(&[0x20])?;
+        self.asm._add()?;
+        self.asm._dup2()?;
+        self.asm._dup5()?;
+        self.push(&[0x04])?;
+        self.asm._gas()?;
+        self.asm._staticcall()?;
+        self.asm._pop()?;
+        self.asm._pop()?;
+
+        self.push(&[0x20])?;
+        self.asm._add()
+    }
+
     /// Handle the return of a call.
     pub fn call_return(&mut self, results: &[ValType]) -> Result<()> {
         let len = results.len() as u8;
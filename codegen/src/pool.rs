@@ -0,0 +1,138 @@
+//! Constant pool.
+//!
+//! The wide constants pushed many times are stored in the memory slots
+//! following the slots reserved by the functions at the entry of the
+//! runtime bytecode, i.e. the beginning of the dispatcher or the main
+//! function, and loaded with `PUSH` and `MLOAD` at where they are used.
+//!
+//! The constants are collected from the bytecode compiled without the
+//! pool, a constant is pooled if it is pushed at least the times of the
+//! threshold and pooling it shrinks the bytecode.
+//!
+//! The memory written by the macro assembler starts after the pool,
+//! the free memory and the locals never overlap it.
+use crate::wasm::ToLSBytes;
+use opcodes::ShangHai as OpCode;
+use smallvec::SmallVec;
+use std::collections::BTreeMap;
+
+/// The maximum bytes of the `i32` constants, which are never pooled
+/// since some of them are parsed back from the bytecode, e.g. the
+/// offsets of the data.
+const MAX_I32: usize = 4;
+
+/// Bytes of loading a pooled constant, `PUSH2` and `MLOAD`.
+const LOAD: usize = 4;
+
+/// Bytes of storing a pooled constant besides the constant itself,
+/// `PUSH2` and `MSTORE`.
+const STORE: usize = 4;
+
+/// Constants stored in memory with their memory offsets.
+#[derive(Clone, Debug, Default)]
+pub struct Pool {
+    /// Memory offsets of the constants.
+    offsets: BTreeMap<Vec<u8>, SmallVec<[u8; 4]>>,
+    /// End of the memory storing the constants.
+    end: usize,
+}
+
+impl Pool {
+    /// Create the pool with the constants stored from the memory slot.
+    pub fn new(constants: Vec<Vec<u8>>, slot: u32) -> Self {
+        let end = (slot as usize + constants.len()) * 0x20;
+        let offsets = constants
+            .into_iter()
+            .zip(slot..)
+            .map(|(constant, slot)| (constant, (slot * 0x20).to_ls_bytes()))
+            .collect();
+
+        Self { offsets, end }
+    }
+
+    /// Collect the constants pushed at least the times of the threshold
+    /// in the bytecode, which shrink the bytecode if they are pooled.
+    pub fn collect(bytecode: &[u8], threshold: usize) -> Vec<Vec<u8>> {
+        let push0 = u8::from(OpCode::PUSH0);
+        let push32 = u8::from(OpCode::PUSH32);
+
+        let mut counts = BTreeMap::<&[u8], usize>::new();
+        let mut pc = 0;
+        while pc < bytecode.len() {
+            let byte = bytecode[pc];
+            let size = if (push0..=push32).contains(&byte) {
+                (byte - push0) as usize
+            } else {
+                0
+            };
+
+            let data = bytecode.get(pc + 1..pc + 1 + size).unwrap_or_default();
+            if !data.is_empty() {
+                *counts.entry(data).or_default() += 1;
+            }
+            pc += 1 + size;
+        }
+
+        counts
+            .into_iter()
+            .filter(|(constant, count)| {
+                let push = constant.len() + 1;
+                constant.len() > MAX_I32
+                    && *count >= threshold
+                    && count * (push - LOAD) > push + STORE
+            })
+            .map(|(constant, _)| constant.to_vec())
+            .collect()
+    }
+
+    /// Get the memory offset of the pooled constant.
+    pub fn get(&self, constant: &[u8]) -> Option<&[u8]> {
+        self.offsets.get(constant).map(|offset| offset.as_slice())
+    }
+
+    /// Get the number of the pooled constants.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// If there are no pooled constants.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Get the end of the memory storing the constants.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Iterate the pooled constants with their memory offsets.
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], &[u8])> {
+        self.offsets
+            .iter()
+            .map(|(constant, offset)| (constant.as_slice(), offset.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Pool;
+
+    #[test]
+    fn collect() {
+        let wide = [0xff; 32];
+        let push = |data: &[u8]| [&[0x5f + data.len() as u8][..], data].concat();
+        let code = [
+            push(&wide),
+            push(&[0x01]),
+            push(&wide),
+            push(&[0x01]),
+            push(&[0x01]),
+            push(&[0x02; 8]),
+            push(&[0x02; 8]),
+        ]
+        .concat();
+
+        assert_eq!(Pool::collect(&code, 2), vec![wide.to_vec()]);
+        assert!(Pool::collect(&code, 3).is_empty());
+    }
+}
@@ -12,9 +12,9 @@ pub enum Error {
     /// Failed to parse WASM with binary reader.
     #[error(transparent)]
     BinaryReader(#[from] wasmparser::BinaryReaderError),
-    /// Failed to push more data to the buffer.
-    #[error("Buffer overflow: {0}, the limit of the binary buffer is 0x6000.")]
-    BufferOverflow(usize),
+    /// Failed to emit bytecode larger than the limit of contract size.
+    #[error("Bytecode too large: {0} bytes, the limit of the contract size is 0x6000 bytes")]
+    BytecodeTooLarge(usize),
     /// Failed to pop control stack frame.
     #[error("Control stack underflow")]
     ControlStackUnderflow,
@@ -36,18 +36,35 @@ pub enum Error {
     /// Failed to find function index in jump table.
     #[error("Function {0} not imported")]
     FuncNotImported(String),
+    /// Failed to find global with the given index.
+    #[error("Global {0} not found")]
+    GlobalNotFound(u32),
     /// Failed to find host function in compiler.
     #[error("Host function {0}::{1} not found in compiler")]
     HostFuncNotFound(String, String),
     /// Failed to find imported function by index in jump table.
     #[error("Imported Function {0} not found in jump table")]
     ImportedFuncNotFound(u32),
+    /// Failed to set the immutable global.
+    #[error("Global {0} is immutable")]
+    ImmutableGlobal(u32),
+    /// Failed to access the state or the environment in pure functions.
+    #[error(
+        "Pure function {0} could not use {1:?} which reads or writes the state or the environment"
+    )]
+    ImpureFunction(String, opcodes::ShangHai),
     /// Failed to mark else block for if block.
     #[error("Invalid else block for if block at {0}")]
     InvalidElseBlock(u16),
     /// Failed parse function signature.
     #[error("Invalid function signature")]
     InvalidFunctionSignature,
+    /// Failed to get the constant slot of the immutable.
+    #[error("Invalid immutable slot, the slot should be a constant")]
+    InvalidImmutableSlot,
+    /// Failed to verify the target of the jump at the program counter.
+    #[error("Invalid jump target {1:#x} of the jump at {0:#x}, the target is not a JUMPDEST")]
+    InvalidJumpTarget(usize, usize),
     /// Failed to get local with given index.
     #[error("Invalid local index {0}")]
     InvalidLocalIndex(usize),
@@ -63,12 +80,18 @@ pub enum Error {
     /// Failed to get data from the provided offset.
     #[error("Invalid data size {0}")]
     InvalidDataSize(usize),
+    /// Failed to find the target of the branch with the given depth.
+    #[error("Invalid branch target, no enclosing construct at depth {0}")]
+    InvalidBranchTarget(u32),
     /// Failed to get frame info of the given depth.
     #[error("Invalid contract stack frame depth {0}")]
     InvalidDepth(usize),
     /// Failed to parse function selector.
     #[error("Invalid function selector")]
     InvalidSelector,
+    /// Failed to parse the slot info of a storage.
+    #[error("Invalid storage slot info {0}")]
+    InvalidSlotInfo(String),
     /// Failed to patch jump destination.
     #[error("Invalid frame label")]
     LabelMismatch,
@@ -81,6 +104,17 @@ pub enum Error {
     /// Failed to index data on memory.
     #[error("Memory index is out of range")]
     MemoryOutOfBounds,
+    /// Failed to emit the opcode introduced after the target fork.
+    #[error("Opcode {opcode} is not available in the target fork {fork}")]
+    OpcodeNotAvailable {
+        /// The name of the opcode.
+        opcode: String,
+        /// The target fork.
+        fork: &'static str,
+    },
+    /// Failed to dispatch functions with the same selector.
+    #[error("Selector 0x{0} of {2} collides with {1}")]
+    SelectorCollision(String, String, String),
     /// Failed to find function selectors.0
     #[error("Function selector is not found.")]
     SelectorNotFound,
@@ -96,9 +130,24 @@ pub enum Error {
     /// Failed to pop stack.
     #[error("Stack not balanced, current stack items {0}")]
     StackNotBalanced(u8),
+    /// Failed to verify the data of the push at the program counter.
+    #[error("Truncated data of the push at {0:#x}, expect {1} bytes")]
+    TruncatedPush(usize, usize),
+    /// Failed to verify the byte at the program counter.
+    #[error("Unknown opcode {1:#04x} at {0:#x} in the target fork Shanghai")]
+    UnknownOpcode(usize, u8),
+    /// Failed to evaluate the init expression of global.
+    #[error("Unsupported init expression of global {0}, only numeric constant expressions are supported")]
+    UnsupportedGlobalInit(u32),
     /// Failed to queue host functions.
     #[error("Unsupported host function {0:?}")]
     UnsupportedHostFunc(crate::wasm::HostFunc),
+    /// Failed to translate the WASM operator.
+    #[error("Unsupported operator {0}")]
+    UnsupportedOperator(String),
+    /// Failed to consume a value wider than the operand type.
+    #[error("Operator {0} consumes a {1}-bit value without wrapping it")]
+    WidthMismatch(String, u16),
 }
 
 /// Codegen result
@@ -0,0 +1,140 @@
+//! Verification of the emitted bytecode.
+//!
+//! The bytecode is walked after the code generation to catch the bugs
+//! of it before deploying the contract, every instruction has to be an
+//! opcode of the target fork, the data of `PUSH`es can not be truncated
+//! and the jumps to the constant targets, i.e. `PUSH`es followed by
+//! `JUMP` or `JUMPI`, have to land on `JUMPDEST`s.
+//!
+//! The targets computed at runtime, e.g. the return addresses of the
+//! internal functions, are not verified.
+
+use crate::{Error, Result};
+use opcodes::ShangHai as OpCode;
+use std::collections::BTreeSet;
+
+/// Verify the bytecode, returns the first malformed instruction.
+pub fn verify(bytecode: &[u8]) -> Result<()> {
+    let mut dests = BTreeSet::new();
+    let mut jumps = Vec::new();
+    // the constant pushed by the previous instruction.
+    let mut pushed = None;
+    let mut pc = 0;
+    while pc < bytecode.len() {
+        let byte = bytecode[pc];
+        let opcode = OpCode::from_byte(byte).ok_or(Error::UnknownOpcode(pc, byte))?;
+        match opcode {
+            OpCode::JUMPDEST => {
+                dests.insert(pc);
+            }
+            OpCode::JUMP | OpCode::JUMPI => {
+                if let Some(target) = pushed {
+                    jumps.push((pc, target));
+                }
+            }
+            _ => {}
+        }
+
+        let size = push_size(opcode);
+        let data = bytecode
+            .get(pc + 1..pc + 1 + size)
+            .ok_or(Error::TruncatedPush(pc, size))?;
+        pushed = is_push(opcode).then(|| {
+            data.iter()
+                .try_fold(0usize, |target, byte| {
+                    target.checked_mul(0x100)?.checked_add(*byte as usize)
+                })
+                .unwrap_or(usize::MAX)
+        });
+        pc += 1 + size;
+    }
+
+    match jumps
+        .into_iter()
+        .find(|(_, target)| !dests.contains(target))
+    {
+        Some((pc, target)) => Err(Error::InvalidJumpTarget(pc, target)),
+        None => Ok(()),
+    }
+}
+
+/// If the opcode pushes a constant.
+fn is_push(opcode: OpCode) -> bool {
+    (u8::from(OpCode::PUSH0)..=u8::from(OpCode::PUSH32)).contains(&u8::from(opcode))
+}
+
+/// Get the size of the data following the opcode.
+fn push_size(opcode: OpCode) -> usize {
+    if is_push(opcode) {
+        (u8::from(opcode) - u8::from(OpCode::PUSH0)) as usize
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify;
+    use crate::Error;
+    use opcodes::ShangHai as OpCode;
+
+    /// Assemble the opcodes and the data bytes.
+    fn bytecode(code: &[(OpCode, &[u8])]) -> Vec<u8> {
+        code.iter()
+            .flat_map(|(opcode, data)| [&[u8::from(*opcode)][..], *data].concat())
+            .collect()
+    }
+
+    #[test]
+    fn valid_jumps() {
+        let code = bytecode(&[
+            (OpCode::PUSH1, &[0x04]),
+            (OpCode::JUMP, &[]),
+            (OpCode::INVALID, &[]),
+            (OpCode::JUMPDEST, &[]),
+            (OpCode::PUSH0, &[]),
+            (OpCode::PUSH2, &[0x00, 0x04]),
+            (OpCode::JUMPI, &[]),
+            (OpCode::STOP, &[]),
+        ]);
+
+        assert!(verify(&code).is_ok());
+    }
+
+    #[test]
+    fn invalid_jump_target() {
+        let code = bytecode(&[
+            (OpCode::PUSH1, &[0x04]),
+            (OpCode::JUMP, &[]),
+            (OpCode::JUMPDEST, &[]),
+            (OpCode::STOP, &[]),
+        ]);
+
+        assert!(matches!(verify(&code), Err(Error::InvalidJumpTarget(2, 4))));
+    }
+
+    #[test]
+    fn jump_into_push_data() {
+        // the JUMPDEST byte is the data of PUSH1.
+        let code = bytecode(&[
+            (OpCode::PUSH1, &[0x04]),
+            (OpCode::JUMP, &[]),
+            (OpCode::PUSH1, &[u8::from(OpCode::JUMPDEST)]),
+            (OpCode::STOP, &[]),
+        ]);
+
+        assert!(matches!(verify(&code), Err(Error::InvalidJumpTarget(2, 4))));
+    }
+
+    #[test]
+    fn truncated_push() {
+        let code = bytecode(&[(OpCode::PUSH0, &[]), (OpCode::PUSH2, &[0x01])]);
+        assert!(matches!(verify(&code), Err(Error::TruncatedPush(1, 2))));
+    }
+
+    #[test]
+    fn unknown_opcode() {
+        let code = [u8::from(OpCode::PUSH0), 0x0c];
+        assert!(matches!(verify(&code), Err(Error::UnknownOpcode(1, 0x0c))));
+    }
+}
@@ -0,0 +1,105 @@
+//! Strength reduction
+//!
+//! Multiplications and unsigned divisions by constant powers of two
+//! are emitted as shifts, the constant operand is rewritten to its
+//! exponent in place.
+
+use crate::{wasm::ToLSBytes, Function, Result};
+use opcodes::ShangHai as OpCode;
+
+impl Function {
+    /// Multiply two 32-bit integers.
+    pub fn _i32_mul(&mut self) -> Result<()> {
+        self.mul()
+    }
+
+    /// Multiply two 64-bit integers.
+    pub fn _i64_mul(&mut self) -> Result<()> {
+        self.mul()
+    }
+
+    /// Unsigned division of 32-bit integers.
+    pub fn _i32_div_u(&mut self) -> Result<()> {
+        match self.log2_operand() {
+            Some(exponent) => self.shr_u(exponent, 4),
+            None => self.masm._i32_div_u(),
+        }
+    }
+
+    /// Unsigned division of 64-bit integers.
+    pub fn _i64_div_u(&mut self) -> Result<()> {
+        match self.log2_operand() {
+            Some(exponent) => self.shr_u(exponent, 8),
+            None => self.masm._i64_div_u(),
+        }
+    }
+
+    /// Multiply the top two items of the stack, shift the second
+    /// item left if the top is a power of two.
+    fn mul(&mut self) -> Result<()> {
+        if self.log2_operand().is_some() {
+            self.masm.asm._shl()
+        } else {
+            self.masm._mul()
+        }
+    }
+
+    /// Shift the dividend right by the exponent on the top of the
+    /// stack, the dividend may carry bits above its width so the
+    /// quotient is masked with the mask of the width shifted as well.
+    ///
+    /// STACK: [dividend, exponent] -> [quotient]
+    fn shr_u(&mut self, exponent: u8, bytes: u8) -> Result<()> {
+        let mask = (u64::MAX >> (64 - bytes as u32 * 8))
+            .checked_shr(exponent as u32)
+            .unwrap_or_default();
+
+        self.masm.asm._shr()?;
+        self.masm.push(mask.to_ls_bytes().as_ref())?;
+        self.masm.asm._and()
+    }
+
+    /// Rewrite the constant power of two on the top of the stack to
+    /// its exponent, returns the exponent if it has been rewritten.
+    ///
+    /// Only the constant pushed by the last instruction is rewritten,
+    /// the width of the `PUSH` is kept.
+    fn log2_operand(&mut self) -> Option<u8> {
+        let instr = self.backtrace.last()?;
+        let exponent = log2(&instr)?;
+
+        let buffer = self.masm.asm.buffer_mut();
+        if !buffer.ends_with(&instr) {
+            return None;
+        }
+
+        let mut rewritten = vec![0; instr.len()];
+        rewritten[0] = instr[0];
+        rewritten[instr.len() - 1] = exponent;
+
+        buffer.truncate(buffer.len() - instr.len());
+        buffer.extend_from_slice(&rewritten);
+        self.backtrace.pop();
+        self.backtrace.push(rewritten);
+        Some(exponent)
+    }
+}
+
+/// Get the exponent of the value pushed by the instruction if it is
+/// a power of two.
+fn log2(instr: &[u8]) -> Option<u8> {
+    let (&op, value) = instr.split_first()?;
+
+    let push1 = u8::from(OpCode::PUSH1);
+    let push32 = u8::from(OpCode::PUSH32);
+    if !(push1..=push32).contains(&op) || value.len() != (op - push1 + 1) as usize {
+        return None;
+    }
+
+    if value.iter().map(|byte| byte.count_ones()).sum::<u32>() != 1 {
+        return None;
+    }
+
+    let (index, byte) = value.iter().enumerate().find(|(_, byte)| **byte != 0)?;
+    Some(((value.len() - index - 1) * 8) as u8 + byte.trailing_zeros() as u8)
+}
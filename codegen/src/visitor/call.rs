@@ -4,24 +4,110 @@
 //! within the execution environment. It handles the setup of the call stack, manages
 //! parameters, and ensures that the program counter is correctly adjusted for function
 //! calls.
+//!
+//! # Calling convention
+//!
+//! 1. The caller pushes the return address and shifts it below the parameters.
+//! 2. The caller stores the parameters to the memory slots of the callee and jumps
+//!    to the `JUMPDEST` of the callee.
+//! 3. The callee reads the parameters and the locals from its memory slots, the
+//!    local variables are reset to zero at the beginning of the callee.
+//! 4. The callee leaves the results on the stack, shifts the return address to the
+//!    top and jumps back to the `JUMPDEST` of the caller.
+//!
+//! The parameters deeper than 16 items below the top of the stack could not be
+//! reached by `SWAP`, the caller spills them to the memory slots of the callee
+//! before the steps above.
+//!
+//! Since the memory slots are allocated per function, the calls which may re-enter
+//! the caller, directly or through the other functions, e.g. `a -> b -> a`, save
+//! the frame of the caller on the stack before the call and restore it afterwards,
+//! so each call has its own frame.
 
 use crate::{
     wasm::{HostFunc, ToLSBytes},
     Error, Function, Result,
 };
-use anyhow::anyhow;
 use opcodes::ShangHai as OpCode;
 
 impl Function {
-    /// The call indirect instruction calls a function indirectly                                                                                                                                          
-    /// through an operand indexing into a table.                                                                                                                                                          
+    /// The call indirect instruction calls a function indirectly
+    /// through an operand indexing into a table.
+    ///
+    /// The table is fixed at compile time, the index is compared with
+    /// the indexes of the functions of the declared type in the table,
+    /// the call is made to the matched one with the calling convention
+    /// of the internal functions. The indexes out of the table and the
+    /// functions of the other types trap with `INVALID`.
+    ///
+    /// STACK: [params, index] -> [results]
     pub fn _call_indirect(
         &mut self,
-        _type_index: u32,
-        _table_index: u32,
+        type_index: u32,
+        table_index: u32,
         _table_byte: u8,
     ) -> Result<()> {
-        todo!()
+        if table_index != 0 {
+            return Err(Error::UnsupportedOperator(format!(
+                "call_indirect of table {table_index}"
+            )));
+        }
+
+        let ty = self
+            .env
+            .types
+            .get(&type_index)
+            .ok_or(Error::InvalidFunctionSignature)?;
+        let (params, results) = (ty.params().len() as u8, ty.results().len() as u8);
+        let funcs: Vec<(u32, u32)> = self
+            .env
+            .table
+            .iter()
+            .filter(|(_, (_, sig))| sig == ty)
+            .map(|(index, (func, _))| (*index, *func))
+            .collect();
+
+        // Compare the index with the entries of the type.
+        //
+        // [ .., params, index ] -> [ .., params, index ]
+        let mut entries = Vec::with_capacity(funcs.len());
+        for (index, func) in funcs {
+            if self.env.is_external(func) {
+                return Err(Error::UnsupportedOperator(format!(
+                    "call_indirect to the external function {func}"
+                )));
+            }
+
+            self.masm._dup1()?;
+            self.masm.push(&index.to_ls_bytes())?;
+            self.masm._eq()?;
+            entries.push((self.masm.pc_offset(), func));
+            self.masm.increment_sp(1)?;
+            self.masm._jumpi()?;
+        }
+        self.masm._invalid()?;
+
+        // Call the function of the matched entry and jump to the end.
+        let sp = self.masm.sp();
+        let mut exits = Vec::with_capacity(entries.len());
+        for (pc, func) in entries {
+            self.table.label(pc, self.masm.pc_offset());
+            self.masm._jumpdest()?;
+            self.masm.asm.sp = sp;
+            self.masm._drop()?;
+            self._call(func)?;
+
+            exits.push(self.masm.pc_offset());
+            self.masm.increment_sp(1)?;
+            self.masm._jump()?;
+        }
+
+        for pc in exits {
+            self.table.label(pc, self.masm.pc_offset());
+        }
+        self.masm._jumpdest()?;
+        self.masm.asm.sp = sp + results - params - 1;
+        Ok(())
     }
 
     /// Calls a function specified by its index.
@@ -48,24 +134,41 @@ impl Function {
     /// Calls an internal function specified by its index.
     ///
     /// This function handles the mechanics of calling an internal function, including:
-    /// - Checking for recursion and returning an error if detected.
+    /// - Saving the frame of the caller on stack if the call may re-enter it.
     /// - Recording the current program counter (PC) to manage the return address.
     /// - Adjusting the stack to accommodate parameters and the return address.
     /// - Storing parameters in memory and registering the call index in the jump table.
+    /// - Restoring the frame of the caller after the callee returns.
     ///
     /// # Errors
     ///
-    /// Returns an error if recursion is detected or if the function index is invalid.
+    /// Returns an error if the function index is invalid.
     fn call_internal(&mut self, index: u32) -> Result<()> {
-        if self.env.index == Some(index) {
-            return Err(anyhow!(
-                "Recursion is no longer supported in this version. See https://github.com/zink-lang/zink/issues/248"
-            ).into());
+        tracing::debug!("Calling internal function: index={index}");
+        let reserved = *self.env.slots.get(&index).unwrap_or(&0);
+        let (params, results) = *self.env.funcs.get(&index).unwrap_or(&(0, 0));
+
+        // The memory slots of the caller are overwritten if the callee
+        // re-enters it, save the frame of the caller below the
+        // parameters.
+        //
+        // [ .., <FRAME>, params ]
+        let reentrant = self.env.is_reentrant(index);
+        if reentrant {
+            self.save_frame(params as u8)?;
         }
 
-        tracing::debug!("Calling internal function: index={index}");
-        let reserved = self.env.slots.get(&index).unwrap_or(&0);
-        let (params, results) = self.env.funcs.get(&index).unwrap_or(&(0, 0));
+        // Only 16 items below the top of the stack are reachable, the
+        // parameters deeper than that are spilled to the memory slots
+        // of the callee before shifting the PC below the rest.
+        //
+        // [ .., params[..16], params[16..] ] -> [ .., params[..16] ]
+        let stacked = params.min(16);
+        for i in (stacked..params).rev() {
+            tracing::trace!("Spilling local at {} for function {index}", i + reserved);
+            self.masm.push(&((i + reserved) * 0x20).to_ls_bytes())?;
+            self.masm._mstore()?;
+        }
 
         // Prepare the stack structure for the function call.
         // The stack will be structured as follows:
@@ -75,25 +178,36 @@ impl Function {
         //   params[PUSH, SLOT, MSTORE],   // Push parameters to the stack
         //   {(PUSH, PC), JUMP, JUMPDEST}   // Prepare for the jump to the callee
         // ]
-        let base_offset = 5 + ((params + reserved) * 0x20).saturating_sub(0xff) / 0x20;
+        let mut base_offset = 5 + ((stacked + reserved) * 0x20).saturating_sub(0xff) / 0x20;
+
+        // The jump table holds one jump per program counter, without
+        // parameters in between, the return address and the callee
+        // have to be separated by a `JUMPDEST`.
+        if stacked == 0 {
+            base_offset += 1;
+        }
 
         // Move the PC before the parameters in the stack.
         self.table.offset(
             self.masm.pc_offset(),
-            base_offset as u16 + 4 * (*params as u16),
+            base_offset as u16 + 4 * (stacked as u16),
         );
         self.masm.increment_sp(1)?;
 
         // Adjust the stack to place the PC before the parameters.
-        self.masm.shift_stack(*params as u8, true)?;
+        self.masm.shift_stack(stacked as u8, true)?;
 
         // Store parameters in memory and register the call index in the jump table.
-        for i in (0..*params).rev() {
+        for i in (0..stacked).rev() {
             tracing::trace!("Storing local at {} for function {index}", i + reserved);
             self.masm.push(&((i + reserved) * 0x20).to_ls_bytes())?;
             self.masm._mstore()?;
         }
 
+        if stacked == 0 {
+            self.masm._jumpdest()?;
+        }
+
         // Register the call index in the jump table.
         self.table.call(self.masm.pc_offset(), index);
 
@@ -102,7 +216,37 @@ impl Function {
         self.masm._jumpdest()?;
 
         // Adjust the stack pointer for the results.
-        self.masm.increment_sp(*results as u8)?;
+        self.masm.increment_sp(results as u8)?;
+
+        // [ .., <FRAME>, results ] -> [ .., results ]
+        if reentrant {
+            self.restore_frame(results as u8)?;
+        }
+
+        Ok(())
+    }
+
+    /// Load the memory slots of the locals of the current function
+    /// and place them below the parameters of the call.
+    fn save_frame(&mut self, params: u8) -> Result<()> {
+        for index in 0..self.locals.len() as u32 {
+            self.masm.push(&self.env.alloc(index))?;
+            self.masm._mload()?;
+            self.masm.shift_stack(params, true)?;
+        }
+
+        Ok(())
+    }
+
+    /// Store the saved frame back to the memory slots of the locals
+    /// of the current function, keeping the results on the top.
+    fn restore_frame(&mut self, results: u8) -> Result<()> {
+        for index in (0..self.locals.len() as u32).rev() {
+            self.masm.shift_stack(results, false)?;
+            self.masm.push(&self.env.alloc(index))?;
+            self.masm._mstore()?;
+        }
+
         Ok(())
     }
 
@@ -131,9 +275,27 @@ impl Function {
             HostFunc::Evm(OpCode::LOG2) => self.log(2),
             HostFunc::Evm(OpCode::LOG3) => self.log(3),
             HostFunc::Evm(OpCode::LOG4) => self.log(4),
+            HostFunc::Evm(OpCode::SSTORE) if self.env.sstore_if_changed => self.sstore_if_changed(),
             HostFunc::Evm(op) => self.masm.emit_op(op),
             HostFunc::U256MAX => self.masm.push(&[255; 32]),
+            HostFunc::U128(op) => self.masm.u128_op(op),
+            HostFunc::U128MAX => self.masm.push(&[255; 16]),
+            HostFunc::U128FromU64 => self.masm.wrap(8),
+            HostFunc::FixedBytes(size) => self.masm.left_align(size as usize),
+            HostFunc::Calldata => self
+                .masm
+                ._calldatacopy(&(self.env.memory * 0x20).to_ls_bytes()),
+            HostFunc::Selector => self.masm._selector(),
+            HostFunc::FreeMemory => self
+                .masm
+                ._free_memory(&(self.env.memory * 0x20).to_ls_bytes()),
             HostFunc::Revert(count) => self.revert(count),
+            HostFunc::RevertError => self.revert_error(),
+            HostFunc::Emit(topics, words) => self.emit(topics, words),
+            HostFunc::RevertPanic => self.revert_panic(),
+            HostFunc::BubbleRevert => self.bubble_revert(),
+            HostFunc::Immutable => self.immutable(),
+            HostFunc::SStoreIfChanged => self.sstore_if_changed(),
             HostFunc::NoOp | HostFunc::Label(_) => Ok(()),
             _ => {
                 tracing::error!("Unsupported host function {func:?}");
@@ -0,0 +1,64 @@
+//! Signed comparisons
+//!
+//! The operands are sign-extended to 256 bits for `SLT` and `SGT`, the
+//! ones already sign-extended on the operand stack are compared as
+//! they are, see [`crate::width::Widths`].
+
+use crate::{Function, Result};
+
+/// Implement the signed comparisons with the bytes of their operands.
+macro_rules! impl_signed_cmp {
+    ($($ty:ident: $bytes:expr),+) => {
+        paste::paste! {
+            impl Function {
+                $(
+                    #[doc = concat!("Signed less than comparison of ", stringify!($ty), ".")]
+                    pub fn [< _ $ty _lt_s >](&mut self) -> Result<()> {
+                        self.sign_extend_operands($bytes)?;
+                        self.masm._slt()
+                    }
+
+                    #[doc = concat!("Signed greater than comparison of ", stringify!($ty), ".")]
+                    pub fn [< _ $ty _gt_s >](&mut self) -> Result<()> {
+                        self.sign_extend_operands($bytes)?;
+                        self.masm._sgt()
+                    }
+
+                    #[doc = concat!("Signed less than or equal comparison of ", stringify!($ty), ".")]
+                    pub fn [< _ $ty _le_s >](&mut self) -> Result<()> {
+                        self.sign_extend_operands($bytes)?;
+                        self.masm._sle()
+                    }
+
+                    #[doc = concat!("Signed greater than or equal comparison of ", stringify!($ty), ".")]
+                    pub fn [< _ $ty _ge_s >](&mut self) -> Result<()> {
+                        self.sign_extend_operands($bytes)?;
+                        self.masm._sge()
+                    }
+                )+
+            }
+        }
+    };
+}
+
+impl Function {
+    /// Sign-extend the operands of `bytes` bytes of the comparison to
+    /// 256 bits unless they are sign-extended already.
+    ///
+    /// STACK: [lhs, rhs] -> [lhs, rhs]
+    fn sign_extend_operands(&mut self, bytes: u8) -> Result<()> {
+        if !self.widths.is_extended(0) {
+            self.masm.sign_extend(bytes)?;
+        }
+
+        if !self.widths.is_extended(1) {
+            self.masm._swap1()?;
+            self.masm.sign_extend(bytes)?;
+            self.masm._swap1()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl_signed_cmp!(i32: 4, i64: 8);
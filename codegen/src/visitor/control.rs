@@ -2,31 +2,38 @@
 
 use crate::{
     control::{ControlStackFrame, ControlStackFrameType},
-    Function, Result,
+    wasm::ToLSBytes,
+    Constructor, Error, Function, Result,
 };
-use wasmparser::{BlockType, BrTable};
+use std::{collections::BTreeMap, mem};
+use wasmparser::{BlockType, BrTable, HeapType, ValType};
+use zabi::Param;
+
+/// Size of an entry of the jump table of `br_table`.
+const BR_TABLE_ENTRY_SIZE: u8 = 5;
 
 impl Function {
     /// The beginning of an if construct with an implicit block.
     pub fn _if(&mut self, blockty: BlockType) -> Result<()> {
         // Emit iszero to check the condition.
-        self.masm._iszero()?;
+        self.masm._cond_iszero()?;
+
+        // mock the stack output of the counter
+        //
+        // the program counter operators should be patched afterwards.
+        let pc = self.masm.pc_offset();
+        self.masm.asm.increment_sp(1)?;
+        self.masm._jumpi()?;
 
         // push an `If` frame to the control stack
         let frame = ControlStackFrame::new(
             ControlStackFrameType::If(false),
-            self.masm.pc_offset(),
+            pc,
             self.masm.sp(),
             blockty,
         );
         self.control.push(frame);
 
-        // mock the stack output of the counter
-        //
-        // the program counter operators should be patched afterwards.
-        self.masm.asm.increment_sp(1)?;
-        self.masm._jumpi()?;
-
         Ok(())
     }
 
@@ -47,7 +54,23 @@ impl Function {
 
     /// A block with a label which may be used to
     /// form loops.
+    ///
+    /// The induction variable of the loop is loaded on the stack
+    /// before the loop, see [`crate::induction`].
     pub fn _loop(&mut self, blockty: BlockType) -> Result<()> {
+        let induction = self.inductions.next_loop().filter(|local| {
+            !matches!(blockty, BlockType::FuncType(_))
+                && self.control.has_branch_arities()
+                && self.inductions.sp_of(*local).is_none()
+                && !self.reads_calldata(*local as usize)
+        });
+
+        if let Some(local) = induction {
+            self.masm.push(&self.env.alloc(local))?;
+            self.masm._mload()?;
+            self.inductions.push(local, self.masm.sp());
+        }
+
         let frame = ControlStackFrame::new(
             ControlStackFrameType::Loop,
             self.masm.pc_offset(),
@@ -64,12 +87,13 @@ impl Function {
     /// Marks an else block of an if.
     pub fn _else(&mut self) -> Result<()> {
         let last_frame = self.control.mark_else()?;
+        self.check_frame_results(&last_frame)?;
 
         // push an `Else` frame to the control stack.
         let frame = ControlStackFrame::new(
             ControlStackFrameType::Else,
             self.masm.pc_offset(),
-            self.masm.sp(),
+            last_frame.original_sp,
             last_frame.result(),
         );
         self.control.push(frame);
@@ -81,6 +105,9 @@ impl Function {
             .label(last_frame.original_pc_offset, self.masm.pc_offset());
         self.masm._jumpdest()?;
 
+        // the else block starts with the stack of the if block,
+        // the results of the then block are not there at runtime.
+        self.masm.asm.sp = last_frame.original_sp;
         Ok(())
     }
 
@@ -90,7 +117,7 @@ impl Function {
     /// STACK: [cond, val2, val1] -> \[val1\] if cond is non-zero, \[val2\] otherwise.
     pub fn _select(&mut self) -> Result<()> {
         tracing::trace!("select");
-        self.masm._iszero()?;
+        self.masm._cond_iszero()?;
         self.masm.increment_sp(1)?;
         self.table.offset(self.masm.pc_offset(), 4);
         self.masm._jumpi()?;
@@ -100,24 +127,59 @@ impl Function {
         Ok(())
     }
 
-    /// Branch to a given label in an enclosing construct.
+    /// The typed select instruction of the reference types proposal.
     ///
-    /// Performs an unconditional branch.
-    pub fn _br(&mut self, _depth: u32) -> Result<()> {
-        // TODO: do sth here?
-        Ok(())
+    /// The operands have been checked against the declared result type
+    /// by the validator, only the number types and the function
+    /// references are supported.
+    pub fn _typed_select(&mut self, ty: ValType) -> Result<()> {
+        match ty {
+            ValType::I32 | ValType::I64 | ValType::F32 | ValType::F64 => self._select(),
+            ValType::Ref(ty) if ty.heap_type() == HeapType::Func => self._select(),
+            ty => Err(Error::UnsupportedOperator(format!("select (result {ty})"))),
+        }
+    }
+
+    /// Performs an unconditional branch to a label in an enclosing
+    /// construct, carrying the values expected by the label.
+    pub fn _br(&mut self, depth: u32) -> Result<()> {
+        self.branch_results(depth)?;
+        self.jump_to(depth)
     }
 
     /// Performs a conditional branch if i32 is non-zero.
     ///
-    /// Conditional branch to a given label in an enclosing construct.
+    /// Conditional branch to a given label in an enclosing construct,
+    /// the values carried by the branch are moved to the results of
+    /// the target if it is taken, and stay on the stack otherwise.
     pub fn _br_if(&mut self, depth: u32) -> Result<()> {
-        let label = self.control.label_from_depth(depth)?;
-        self.table.label(self.masm.pc_offset(), label);
+        // the condition is consumed by the branch.
+        if self.branch_drops(depth, self.masm.sp().saturating_sub(1))? == 0 {
+            let pc = self.masm.pc_offset();
+            if let Some(label) = self.control.branch(depth, pc)? {
+                self.table.label(pc, label);
+            }
+
+            self.masm.asm.increment_sp(1)?;
+            self.masm._jumpi()?;
+            return Ok(());
+        }
+
+        // The values under the results of the branch have to be
+        // dropped only if the branch is taken, skip the shifting
+        // of the stack otherwise.
+        self.masm._cond_iszero()?;
+        let skip = self.masm.pc_offset();
         self.masm.asm.increment_sp(1)?;
         self.masm._jumpi()?;
 
-        Ok(())
+        let sp = self.masm.sp();
+        self.branch_results(depth)?;
+        self.jump_to(depth)?;
+
+        self.masm.asm.sp = sp;
+        self.table.label(skip, self.masm.pc_offset());
+        self.masm._jumpdest()
     }
 
     /// A jump table which jumps to a label in an enclosing construct.
@@ -125,8 +187,73 @@ impl Function {
     /// Performs an indirect branch through an operand indexing into the
     /// label vector that is an immediate to the instruction, or to the
     /// default target if the operand is out of bounds.
-    pub fn _br_table(&mut self, _table: BrTable<'_>) -> Result<()> {
-        todo!()
+    pub fn _br_table(&mut self, table: BrTable<'_>) -> Result<()> {
+        let mut targets = table
+            .targets()
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        targets.push(table.default());
+        for depth in &targets {
+            self.control.target(*depth)?;
+        }
+
+        // Clamp the index to the default target without branching.
+        //
+        // STACK: [index] -> [index < default ? index : default]
+        let default = (targets.len() as u32 - 1).to_ls_bytes();
+        self.masm.wrap(4)?;
+        self.masm.asm._dup1()?;
+        self.masm.push(&default)?;
+        self.masm.asm._gt()?;
+        self.masm.asm._swap1()?;
+        self.masm.push(&default)?;
+        self.masm.asm._swap1()?;
+        self.masm.asm._sub()?;
+        self.masm.asm._mul()?;
+        self.masm.push(&default)?;
+        self.masm.asm._add()?;
+
+        // Jump to the entry of the index, the entries start right
+        // after the `JUMP`, 6 bytes from the `PC`.
+        //
+        // STACK: [index] -> []
+        self.masm.push(&[BR_TABLE_ENTRY_SIZE])?;
+        self.masm.asm._mul()?;
+        self.masm.asm._pc()?;
+        self.masm.asm._add()?;
+        self.masm.push(&[6])?;
+        self.masm.asm._add()?;
+        self.masm._jump()?;
+
+        // The entries have the same size, `JUMPDEST PUSH2 <branch> JUMP`.
+        let sp = self.masm.sp();
+        let mut entries = Vec::with_capacity(targets.len());
+        for _ in &targets {
+            self.masm._jumpdest()?;
+            entries.push(self.masm.pc_offset());
+            self.masm.asm.increment_sp(1)?;
+            self.masm._jump()?;
+        }
+
+        // The branches to the targets, shared by the entries of the
+        // same target.
+        let mut branches = BTreeMap::new();
+        for (pc, depth) in entries.into_iter().zip(targets) {
+            let branch = match branches.get(&depth) {
+                Some(branch) => *branch,
+                None => {
+                    self.masm.asm.sp = sp;
+                    let branch = self.masm.pc_offset();
+                    self.masm._jumpdest()?;
+                    self._br(depth)?;
+                    branches.insert(depth, branch);
+                    branch
+                }
+            };
+
+            self.table.entry(pc, branch);
+        }
+
+        Ok(())
     }
 
     /// Handle the end of instructions for different situations.
@@ -139,8 +266,59 @@ impl Function {
             return self.handle_frame_popping(frame);
         }
 
-        let results = self.ty.results();
+        self.function_return()
+    }
+
+    /// Return zero or more values from the function.
+    ///
+    /// External functions halt with their results as the end of them,
+    /// internal functions jump back to their callers with the results
+    /// instead of halting, the values under the results are dropped.
+    pub fn _return(&mut self) -> Result<()> {
         if self.is_main || self.abi.is_some() {
+            return self.function_return();
+        }
+
+        // STACK: [pc, dropped, results] -> [pc, results]
+        let sp = self.masm.sp();
+        let results = self.ty.results().len() as u8;
+        let drops = sp
+            .checked_sub(results + 1)
+            .ok_or(Error::StackNotBalanced(sp))?;
+        if results == 1 && drops > 0 {
+            self.masm.swap(drops)?;
+        }
+
+        for _ in 0..drops {
+            // rotate the value under the results to the top.
+            //
+            // [dropped, results] -> [results, dropped]
+            if results > 1 {
+                self.masm.shift_stack(results, false)?;
+            }
+
+            self.masm._drop()?;
+        }
+
+        self.masm.call_return(self.ty.results())
+    }
+
+    /// Return from the function with the results on the stack, the
+    /// constructor function jumps to the code returning the runtime
+    /// bytecode instead.
+    fn function_return(&mut self) -> Result<()> {
+        let results = self.ty.results();
+        if self.is_constructor {
+            tracing::trace!("end of constructor function");
+            self.masm.push(&Constructor::exit())?;
+            self.masm._jump()
+        } else if self.returns_array() {
+            tracing::trace!("end of main function with dynamic array");
+            self.array_return()
+        } else if self.abi.is_some() && results.is_empty() {
+            tracing::trace!("end of external function without results");
+            self.masm.empty_return()
+        } else if self.is_main || self.abi.is_some() {
             tracing::trace!("end of main function");
             self.masm.main_return(results)
         } else {
@@ -149,6 +327,33 @@ impl Function {
         }
     }
 
+    /// If the function returns a dynamic array in its ABI.
+    fn returns_array(&self) -> bool {
+        self.abi.as_ref().is_some_and(|abi| {
+            matches!(abi.outputs.as_slice(), [output] if matches!(output.ty, Param::Array(_)))
+        })
+    }
+
+    /// Return the dynamic array at the memory pointer on the top of
+    /// the stack, the arrays in the first word of the memory are moved
+    /// a word up for the ABI offset before them.
+    fn array_return(&mut self) -> Result<()> {
+        // [ptr] -> [ptr, ptr >= 0x20]
+        self.masm._dup1()?;
+        self.masm.push(&[0x20])?;
+        self.masm.asm._gt()?;
+        self.masm._iszero()?;
+
+        let pc = self.masm.pc_offset();
+        self.masm.increment_sp(1)?;
+        self.masm._jumpi()?;
+        self.masm.shift_array()?;
+
+        self.table.label(pc, self.masm.pc_offset());
+        self.masm._jumpdest()?;
+        self.masm.array_return()
+    }
+
     /// Mark as invalid for now.
     ///
     /// TODO: recheck this implementation, if it is okay,
@@ -165,22 +370,145 @@ impl Function {
 
     /// Handle the popping of a frame.
     ///
-    /// TODO: validate stack IO for all frames (#59)
-    pub(crate) fn handle_frame_popping(&mut self, frame: ControlStackFrame) -> Result<()> {
+    /// TODO: validate stack IO for blocks and loops (#59)
+    pub(crate) fn handle_frame_popping(&mut self, mut frame: ControlStackFrame) -> Result<()> {
         match frame.ty {
-            ControlStackFrameType::If(true) => Ok(()),
-            ControlStackFrameType::Block => self.masm._jumpdest(),
-            ControlStackFrameType::Loop => Ok(()),
-            _ => {
+            ControlStackFrameType::Block => {
+                for pc in mem::take(&mut frame.branches) {
+                    self.table.label(pc, self.masm.pc_offset());
+                }
+
+                // The branches merge here with the results of the
+                // block on the stack.
+                if let Some(results) = frame.result_len() {
+                    self.masm.asm.sp = frame.original_sp + results;
+                }
+
+                self.masm._jumpdest()
+            }
+            ControlStackFrameType::Loop => {
+                let results = frame.result_len();
+                if let Some(results) = results {
+                    self.masm.asm.sp = frame.original_sp + results;
+                }
+
+                // STACK: [induction, results] -> [results]
+                if let Some(local) = self.inductions.pop(frame.original_sp) {
+                    if results == Some(1) {
+                        self.masm.asm._swap1()?;
+                    }
+
+                    self.store_var(local)?;
+                }
+
+                Ok(())
+            }
+            ControlStackFrameType::If(_) | ControlStackFrameType::Else => {
+                self.check_frame_results(&frame)?;
                 self.table
                     .label(frame.original_pc_offset, self.masm.pc_offset());
 
-                // TODO: Check the stack output and make decisions
-                // how to handle the results.
+                // the else frame closes its if frame as well, the
+                // branches to the if are registered on the if frame.
+                let branches = if frame.ty == ControlStackFrameType::Else {
+                    self.control.pop()?.branches
+                } else {
+                    mem::take(&mut frame.branches)
+                };
+
+                for pc in branches {
+                    self.table.label(pc, self.masm.pc_offset());
+                }
+
+                // Both branches merge here with the results of the
+                // block on the stack.
+                if let Some(results) = frame.result_len() {
+                    self.masm.asm.sp = frame.original_sp + results;
+                }
 
                 // Emit JUMPDEST after at the end of the control flow.
                 self.masm._jumpdest()
             }
         }
     }
+
+    /// Get the number of the values to drop under the results of
+    /// the branch to the given depth with the stack pointer `sp`.
+    fn branch_drops(&self, depth: u32, sp: u8) -> Result<u8> {
+        let frame = self.control.target(depth)?;
+        let Some(arity) = frame.branch_arity() else {
+            return Ok(0);
+        };
+
+        sp.checked_sub(frame.original_sp + arity)
+            .ok_or(Error::StackNotBalanced(sp))
+    }
+
+    /// Move the values carried by the branch to the given depth to
+    /// the stack position expected by the target, the dropped
+    /// induction variables are stored back to their locals.
+    ///
+    /// STACK: [results, dropped, target] -> [results, target]
+    fn branch_results(&mut self, depth: u32) -> Result<()> {
+        let sp = self.masm.sp();
+        let drops = self.branch_drops(depth, sp)?;
+        if drops == 0 {
+            return Ok(());
+        }
+
+        let arity = self.control.target(depth)?.branch_arity();
+        match arity {
+            Some(0) => {}
+            Some(1) => self.masm.swap(drops)?,
+            _ => return Err(Error::StackNotBalanced(sp)),
+        }
+
+        for at in (sp - drops + 1..=sp).rev() {
+            // the lowest dropped value is swapped to the top.
+            let at = if arity == Some(1) && at == sp {
+                sp - drops
+            } else {
+                at
+            };
+
+            match self.inductions.local_at(at) {
+                Some(local) => self.store_var(local)?,
+                None => self.masm._drop()?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Jump to the label of the given depth unconditionally.
+    fn jump_to(&mut self, depth: u32) -> Result<()> {
+        let pc = self.masm.pc_offset();
+        if let Some(label) = self.control.branch(depth, pc)? {
+            self.table.label(pc, label);
+        }
+
+        self.masm.asm.increment_sp(1)?;
+        self.masm._jump()
+    }
+
+    /// Check if the branch of the frame leaves exactly its
+    /// results on the stack.
+    fn check_frame_results(&self, frame: &ControlStackFrame) -> Result<()> {
+        let Some(results) = frame.result_len() else {
+            return Ok(());
+        };
+
+        // The stack is polymorphic after the terminating
+        // instructions and the branches, nothing to check.
+        if self.masm.is_terminated() || self.masm.is_jumped() {
+            return Ok(());
+        }
+
+        let sp = self.masm.sp();
+        if sp != frame.original_sp + results {
+            return Err(Error::StackNotBalanced(sp));
+        }
+
+        Ok(())
+    }
 }
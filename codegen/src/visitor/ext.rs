@@ -0,0 +1,35 @@
+//! Helpers in the code section
+//!
+//! The instructions which take long sequences of opcodes are compiled
+//! to jumps to the helpers appended after the functions, the helpers
+//! are keyed by their bytecode so each of them is emitted only once
+//! however many times it is called.
+
+use crate::{codegen::ExtFunc, Function, Result};
+
+impl Function {
+    /// Count the leading zero bits of a 32-bit integer.
+    pub fn _i32_clz(&mut self) -> Result<()> {
+        self.call_ext(ExtFunc::clz(4))
+    }
+
+    /// Count the leading zero bits of a 64-bit integer.
+    pub fn _i64_clz(&mut self) -> Result<()> {
+        self.call_ext(ExtFunc::clz(8))
+    }
+
+    /// Call the helper with its input on the top of the stack, the
+    /// return address is placed under the input.
+    ///
+    /// [ .., input ] -> [ .., <PC>, input ] -> [ .., output ]
+    fn call_ext(&mut self, func: ExtFunc) -> Result<()> {
+        // PUSH <PC>, SWAP1, PUSH <FUNC>, JUMP, JUMPDEST
+        self.table.offset(self.masm.pc_offset(), 6);
+        self.masm.increment_sp(1)?;
+        self.masm._swap1()?;
+
+        self.table.ext(self.masm.pc_offset(), func);
+        self.masm._jump()?;
+        self.masm._jumpdest()
+    }
+}
@@ -0,0 +1,44 @@
+//! Immutables
+//!
+//! Immutables are set by the constructor and baked into the runtime
+//! bytecode, reading them costs a `PUSH32` only.
+
+use crate::{Constructor, Error, Function, Result};
+use opcodes::ShangHai as OpCode;
+
+impl Function {
+    /// Push the immutable of the slot on the top of the stack.
+    ///
+    /// The constant slot pushed by the last instruction is replaced
+    /// with the placeholder of the immutable, which is patched with
+    /// the value by the constructor.
+    pub fn immutable(&mut self) -> Result<()> {
+        let instr = self.backtrace.last().ok_or(Error::InvalidImmutableSlot)?;
+        let slot = slot(&instr).ok_or(Error::InvalidImmutableSlot)?;
+        if !self.masm.buffer().ends_with(&instr) {
+            return Err(Error::InvalidImmutableSlot);
+        }
+
+        let len = self.masm.buffer().len() - instr.len();
+        self.masm.buffer_mut().truncate(len);
+        self.backtrace.pop();
+        self.masm.decrement_sp(1)?;
+
+        tracing::debug!("immutable slot: {slot}");
+        self.masm.push(&Constructor::placeholder(slot))
+    }
+}
+
+/// Get the slot pushed by the instruction.
+fn slot(instr: &[u8]) -> Option<u32> {
+    let (&op, value) = instr.split_first()?;
+
+    let push0 = u8::from(OpCode::PUSH0);
+    if !(push0..=u8::from(OpCode::PUSH4)).contains(&op) || value.len() != (op - push0) as usize {
+        return None;
+    }
+
+    let mut bytes = [0; 4];
+    bytes[4 - value.len()..].copy_from_slice(value);
+    Some(u32::from_be_bytes(bytes))
+}
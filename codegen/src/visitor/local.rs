@@ -1,12 +1,22 @@
 //! Local instructions
 
-use crate::{wasm::ToLSBytes, Error, Function, Result};
+use crate::{
+    wasm::{Global, ToLSBytes},
+    Error, Function, Result,
+};
+use std::collections::BTreeMap;
+use wasmparser::{Operator, OperatorsReader};
+use zabi::{Abi, Param};
 
 impl Function {
     /// This instruction gets the value of a variable.
     pub fn _local_get(&mut self, local_index: u32) -> Result<()> {
+        if let Some(sp) = self.inductions.sp_of(local_index) {
+            return self.masm.dup(self.induction_depth(sp)? + 1);
+        }
+
         let local_index = local_index as usize;
-        if (self.is_main || self.abi.is_some()) && local_index < self.ty.params().len() {
+        if self.reads_calldata(local_index) {
             // Parsing data from selector.
             self._local_get_calldata(local_index)
         } else {
@@ -17,10 +27,13 @@ impl Function {
 
     /// This instruction sets the value of a variable.
     pub fn _local_set(&mut self, local_index: u32) -> Result<()> {
-        self.masm.push(&self.env.alloc(local_index))?;
-        self.masm._mstore()?;
+        let Some(sp) = self.inductions.sp_of(local_index) else {
+            return self.store_var(local_index);
+        };
 
-        Ok(())
+        // STACK: [induction, values, value] -> [value, values]
+        self.masm.swap(self.induction_depth(sp)?)?;
+        self.masm._drop()
     }
 
     /// This _local_tee is like _local_set, but it also returns the value
@@ -31,27 +44,174 @@ impl Function {
         Ok(())
     }
 
-    /// This instruction gets the value of a variable.
-    pub fn _global_get(&mut self, _: u32) -> Result<()> {
-        todo!()
+    /// This instruction gets the value of a global.
+    ///
+    /// Immutable globals are baked into the bytecode, mutable globals
+    /// are loaded from their backing storage slots.
+    pub fn _global_get(&mut self, index: u32) -> Result<()> {
+        let global = self
+            .env
+            .globals
+            .get(&index)
+            .ok_or(Error::GlobalNotFound(index))?;
+
+        if !global.mutable {
+            return self.masm.push(&global.to_ls_bytes());
+        }
+
+        self.masm.push(&Global::storage_key(index))?;
+        self.masm._sload()
     }
 
-    /// This instruction sets the value of a variable.
-    pub fn _global_set(&mut self, _: u32) -> Result<()> {
-        todo!()
+    /// This instruction sets the value of a global.
+    ///
+    /// The immutable globals are inlined as constants, they could not
+    /// be set.
+    pub fn _global_set(&mut self, index: u32) -> Result<()> {
+        let global = self
+            .env
+            .globals
+            .get(&index)
+            .ok_or(Error::GlobalNotFound(index))?;
+
+        if !global.mutable {
+            return Err(Error::ImmutableGlobal(index));
+        }
+
+        self.masm.push(&Global::storage_key(index))?;
+        self.masm._sstore()
+    }
+
+    /// Store the value on the top of the stack to the memory slot of
+    /// the local.
+    pub(crate) fn store_var(&mut self, local_index: u32) -> Result<()> {
+        self.masm.push(&self.env.alloc(local_index))?;
+        self.masm._mstore()
+    }
+
+    /// If the local is a parameter read from calldata.
+    pub(crate) fn reads_calldata(&self, local_index: usize) -> bool {
+        (self.is_main || self.abi.is_some())
+            && local_index < self.ty.params().len()
+            && !self.cached.contains(&local_index)
+    }
+
+    /// Get the depth of the induction variable at `sp` from the top
+    /// of the stack.
+    fn induction_depth(&self, sp: u8) -> Result<u8> {
+        let top = self.masm.sp();
+        top.checked_sub(sp).ok_or(Error::StackNotBalanced(top))
+    }
+
+    /// Load the parameters used more than once into their memory
+    /// slots at the entry of the function, the following reads of
+    /// them are served from memory instead of `CALLDATALOAD`.
+    ///
+    /// The parameters written by `local.set` or `local.tee` are
+    /// always loaded, since calldata is read only.
+    pub(crate) fn cache_calldata(&mut self, mut ops: OperatorsReader<'_>) -> Result<()> {
+        let params = self.ty.params().len();
+        let mut usages = BTreeMap::<usize, usize>::new();
+        while !ops.eof() {
+            let (index, count) = match ops.read()? {
+                Operator::LocalGet { local_index } => (local_index as usize, 1),
+                Operator::LocalSet { local_index } | Operator::LocalTee { local_index } => {
+                    (local_index as usize, 2)
+                }
+                _ => continue,
+            };
+
+            if index < params {
+                *usages.entry(index).or_default() += count;
+            }
+        }
+
+        for (index, _) in usages.into_iter().filter(|(_, count)| *count > 1) {
+            self._local_get_calldata(index)?;
+            self.masm.push(&self.env.alloc(index as u32))?;
+            self.masm._mstore()?;
+            self.cached.insert(index);
+        }
+
+        Ok(())
+    }
+
+    /// Load the arguments of the constructor function into the memory
+    /// slots of its parameters.
+    ///
+    /// The arguments are appended to the init code as words, they are
+    /// copied from the end of the code.
+    pub(crate) fn load_args(&mut self) -> Result<()> {
+        let params = self.ty.params().len();
+        for index in 0..params {
+            self.masm.push(&[32])?; // size
+            self.masm.push(&((params - index) * 32).to_ls_bytes())?;
+            self.masm._codesize()?;
+            self.masm._sub()?; // code offset
+            self.masm.push(&self.env.alloc(index as u32))?; // dest offset
+            self.masm._codecopy()?;
+            self.cached.insert(index);
+        }
+
+        Ok(())
     }
 
     /// Local get from calldata.
     fn _local_get_calldata(&mut self, local_index: usize) -> Result<()> {
-        let mut offset = self.locals.offset_of(local_index)?;
-        if self.abi.is_some() {
-            offset = (4 + local_index * 32).to_ls_bytes().to_vec().into();
+        let Some(abi) = &self.abi else {
+            let offset = self.locals.offset_of(local_index)?;
+            self.masm.push(&offset)?;
+            return self.masm._calldataload();
+        };
+
+        let (head, part) = calldata_param(abi, local_index);
+        if let Part::Fields(fields) = &part {
+            // STACK: [] -> [ptr]
+            self.masm
+                ._free_memory(&(self.env.memory * 0x20).to_ls_bytes())?;
+            for field in fields {
+                self.masm.asm._dup1()?;
+                self.masm.push(&(4 + head + field.head).to_ls_bytes())?;
+                self.masm._calldataload()?;
+                self.masm.store(field.offset as u64, field.bytes)?;
+            }
+
+            return Ok(());
+        }
+
+        self.masm.push(&(4 + head).to_ls_bytes())?;
+        if let Part::Struct(size) = part {
+            // STACK: [offset] -> [ptr]
+            self.masm.push(&size.to_ls_bytes())?;
+            return self
+                .masm
+                ._calldatacopy(&(self.env.memory * 0x20).to_ls_bytes());
         }
 
-        self.masm.push(&offset)?;
         self.masm._calldataload()?;
 
-        Ok(())
+        // STACK: [offset] -> [length_at]
+        if part != Part::Word {
+            self.masm.push(&[4])?;
+            self.masm.asm._add()?;
+        }
+
+        match part {
+            Part::Len => self.masm._calldataload(),
+            Part::Ptr => {
+                // STACK: [length_at] -> [data_at, length]
+                self.masm.asm._dup1()?;
+                self.masm._calldataload()?;
+                self.masm.asm._swap1()?;
+                self.masm.push(&[0x20])?;
+                self.masm.asm._add()?;
+                self.masm.asm._swap1()?;
+
+                self.masm
+                    ._calldatacopy(&(self.env.memory * 0x20).to_ls_bytes())
+            }
+            _ => Ok(()),
+        }
     }
 
     /// Local get for variables.
@@ -67,3 +227,115 @@ impl Function {
         Ok(())
     }
 }
+
+/// The part of an ABI input passed as a WASM parameter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    /// The word of the input.
+    Word,
+    /// Pointer to the data of a `bytes` input copied into memory.
+    Ptr,
+    /// Length of the data of a `bytes` input.
+    Len,
+    /// Pointer to a struct input of the size decoded into memory, the
+    /// fields are words at their offsets of the static encoding.
+    Struct(usize),
+    /// Pointer to a struct input decoded into memory with the fields
+    /// laid out as `#[repr(C)]` in Rust.
+    Fields(Vec<Field>),
+}
+
+/// Integer field of a struct laid out in memory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    /// Offset of the field in the static encoding.
+    head: usize,
+    /// Offset of the field in memory.
+    offset: usize,
+    /// Bytes of the field.
+    bytes: u8,
+}
+
+/// Get the offset of the head of the ABI input of the WASM parameter
+/// in the arguments, and the part of the input it is.
+///
+/// The `bytes` inputs are passed as the pointers and the lengths of
+/// the slices, which are decoded from their tails in calldata, the
+/// lengths followed by the data.
+///
+/// The static tuples, the ABI of the structs, are encoded in place in
+/// the heads, they are passed as the pointers of the structs decoded
+/// into memory. The structs of integers are laid out as `#[repr(C)]`
+/// in Rust with the little-endian fields, which are read by the loads
+/// of WASM, the other structs are copied as the words of the static
+/// encoding, which are read with `evm.mload`.
+fn calldata_param(abi: &Abi, local_index: usize) -> (usize, Part) {
+    let mut param = 0;
+    let mut head = 0;
+    for input in abi.inputs.iter() {
+        let size = match input.ty {
+            Param::Tuple(_) => input.ty.size(),
+            _ => None,
+        };
+
+        match (&input.ty, local_index - param) {
+            (Param::Bytes, 0) => return (head, Part::Ptr),
+            (Param::Bytes, 1) => return (head, Part::Len),
+            (Param::Bytes, _) => param += 2,
+            (Param::Tuple(_), 0) => {
+                let part = match layout(&input.ty) {
+                    Some((fields, _, _)) => Part::Fields(fields),
+                    None => size.map_or(Part::Word, Part::Struct),
+                };
+
+                return (head, part);
+            }
+            (_, 0) => return (head, Part::Word),
+            _ => param += 1,
+        }
+
+        head += size.unwrap_or(32);
+    }
+
+    (head + (local_index - param) * 32, Part::Word)
+}
+
+/// Lay out the struct of integers as `#[repr(C)]` in Rust, returns the
+/// fields with the size and the alignment of the struct, or `None` if
+/// it is not a struct or has fields other than the integers of 64 bits
+/// and the booleans.
+fn layout(ty: &Param) -> Option<(Vec<Field>, usize, usize)> {
+    let bytes = match ty {
+        Param::Int8 | Param::UInt8 | Param::Bool => 1,
+        Param::Int16 | Param::UInt16 => 2,
+        Param::Int32 | Param::UInt32 => 4,
+        Param::Int64 | Param::UInt64 => 8,
+        Param::Tuple(tys) => {
+            let mut fields = Vec::new();
+            let (mut head, mut size, mut align) = (0, 0, 1);
+            for ty in tys {
+                let (inner, inner_size, inner_align) = layout(ty)?;
+                size = usize::next_multiple_of(size, inner_align);
+                fields.extend(inner.into_iter().map(|field| Field {
+                    head: head + field.head,
+                    offset: size + field.offset,
+                    bytes: field.bytes,
+                }));
+
+                head += ty.size()?;
+                size += inner_size;
+                align = align.max(inner_align);
+            }
+
+            return Some((fields, size.next_multiple_of(align), align));
+        }
+        _ => return None,
+    };
+
+    let field = Field {
+        head: 0,
+        offset: 0,
+        bytes,
+    };
+    Some((vec![field], bytes as usize, bytes as usize))
+}
@@ -1,12 +1,16 @@
 //! System instructions
 
-use crate::{masm::MemoryInfo, wasm::ToLSBytes, Error, Function, Result};
+use crate::{
+    masm::MemoryInfo,
+    wasm::{HostFunc, ToLSBytes},
+    Error, Function, Result,
+};
 
 impl Function {
     /// Parse log data from the bytecode.
     ///
     /// WASM example:
-    /// ```
+    /// ```wasm
     /// i32.const 1048576   ;; offset
     /// i32.const 4         ;; 4 bytes
     /// ```
@@ -103,6 +107,61 @@ impl Function {
         Ok(())
     }
 
+    /// Emit the event with the indexed topics and the data words on
+    /// the stack, the hash of the signature is the first topic.
+    ///
+    /// The data words are written after the memory accessed so far,
+    /// which is not used by anything else.
+    ///
+    /// STACK: [topic1, .., topicN, word1, .., wordM] -> []
+    pub fn emit(&mut self, topics: usize, words: usize) -> Result<()> {
+        let (offset, size) = self.data()?;
+        let signature = self.env.data.load(offset, size as usize)?;
+        tracing::debug!("emit event: {}", String::from_utf8_lossy(&signature));
+
+        // 1. write the data words, the memory size grows by the first
+        // write, which is the last word.
+        for index in (0..words).rev() {
+            if index + 1 == words {
+                self.masm.asm._msize()?;
+                if index > 0 {
+                    self.masm.push(&(index * 0x20).to_ls_bytes())?;
+                    self.masm.asm._add()?;
+                }
+            } else {
+                self.masm.push(&((words - index) * 0x20).to_ls_bytes())?;
+                self.masm.asm._msize()?;
+                self.masm.asm._sub()?;
+            }
+            self.masm._mstore()?;
+        }
+
+        // 2. reverse the topics and push the hash of the signature.
+        self.masm.swap(topics.saturating_sub(1) as u8)?;
+        self.masm.push(&zabi::selector::keccak256(&signature))?;
+
+        // 3. prepare the offset and size of the data.
+        let size = words * 0x20;
+        if size == 0 {
+            self.masm._push0()?;
+            self.masm._push0()?;
+        } else {
+            self.masm.push(&size.to_ls_bytes())?;
+            self.masm.push(&size.to_ls_bytes())?;
+            self.masm.asm._msize()?;
+            self.masm.asm._sub()?;
+        }
+
+        // 4. run log for the data
+        match topics {
+            0 => self.masm._log1(),
+            1 => self.masm._log2(),
+            2 => self.masm._log3(),
+            3 => self.masm._log4(),
+            _ => Err(Error::UnsupportedHostFunc(HostFunc::Emit(topics, words))),
+        }
+    }
+
     /// Revert with message.
     pub fn revert(&mut self, count: usize) -> Result<()> {
         let mut message = Vec::<Vec<u8>>::default();
@@ -133,4 +192,80 @@ impl Function {
         self.masm._revert()?;
         Ok(())
     }
+
+    /// Revert with message in the ABI encoding of `Error(string)`.
+    ///
+    /// MEMORY: [selector, 0x20, len, message..]
+    pub fn revert_error(&mut self) -> Result<()> {
+        let (offset, size) = self.data()?;
+        let message = self.env.data.load(offset, size as usize)?;
+        tracing::debug!("revert error: {}", String::from_utf8_lossy(&message));
+
+        // 1. write selector and the offset of the string.
+        self.selector(b"Error(string)")?;
+        self.masm.push(&[0x20])?;
+        self.masm.push(&[0x04])?;
+        self.masm._mstore()?;
+
+        // 2. write the length and the left-aligned words of the string.
+        self.masm.push(&message.len().to_ls_bytes())?;
+        self.masm.push(&[0x24])?;
+        self.masm._mstore()?;
+        for (index, chunk) in message.chunks(32).enumerate() {
+            let mut word = [0; 32];
+            word[..chunk.len()].copy_from_slice(chunk);
+
+            self.masm.push(&word)?;
+            self.masm.push(&(0x44 + index * 32).to_ls_bytes())?;
+            self.masm._mstore()?;
+        }
+
+        // 3. revert with the encoded error.
+        let size = 0x44 + message.len().div_ceil(32) * 32;
+        self.masm.push(&size.to_ls_bytes())?;
+        self.masm._push0()?;
+        self.masm._revert()?;
+        Ok(())
+    }
+
+    /// Revert with the panic code on the stack in the ABI encoding
+    /// of `Panic(uint256)`.
+    ///
+    /// MEMORY: [selector, code]
+    pub fn revert_panic(&mut self) -> Result<()> {
+        self.selector(b"Panic(uint256)")?;
+        self.masm.push(&[0x04])?;
+        self.masm._mstore()?;
+
+        self.masm.push(&[0x24])?;
+        self.masm._push0()?;
+        self.masm._revert()?;
+        Ok(())
+    }
+
+    /// Revert with the return data of the last call, which preserves
+    /// the errors of the reverted sub-calls.
+    ///
+    /// MEMORY: [returndata..]
+    pub fn bubble_revert(&mut self) -> Result<()> {
+        self.masm._returndatasize()?;
+        self.masm._push0()?;
+        self.masm._push0()?;
+        self.masm._returndatacopy()?;
+
+        self.masm._returndatasize()?;
+        self.masm._push0()?;
+        self.masm._revert()?;
+        Ok(())
+    }
+
+    /// Write the selector of the provided signature to the head of
+    /// the memory.
+    fn selector(&mut self, signature: &[u8]) -> Result<()> {
+        self.masm.push(&zabi::selector::parse(signature))?;
+        self.masm.push(&[0xe0])?;
+        self.masm._shl()?;
+        self.masm._push0()?;
+        self.masm._mstore()
+    }
 }
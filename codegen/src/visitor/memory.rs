@@ -0,0 +1,81 @@
+//! Memory instructions
+//!
+//! The linear memory of WASM is mapped to the memory of EVM directly,
+//! values are stored in little-endian as WASM requires at the address
+//! plus the static offset of the instruction, the signed loads are
+//! sign-extended to the width of their result.
+//!
+//! The size of memory is the larger one of the initial pages of the
+//! WASM memory and the pages covering the memory of EVM, growing never
+//! fails but runs out of gas for the expansion of the memory of EVM.
+
+use crate::{Function, Result};
+use wasmparser::MemArg;
+
+/// Implement the memory instructions with the bytes they access.
+macro_rules! impl_memory {
+    (
+        load: [$($load:ident: $lbytes:expr $(=> $width:expr)?),+],
+        store: [$($store:ident: $sbytes:expr),+]
+    ) => {
+        impl Function {
+            $(
+                #[doc = concat!("Load ", stringify!($lbytes), " bytes from memory.")]
+                pub fn $load(&mut self, memarg: MemArg) -> Result<()> {
+                    self.masm.load(memarg.offset, $lbytes)?;
+                    $(self.masm.load_extend_s($lbytes, $width)?;)?
+                    Ok(())
+                }
+            )+
+
+            $(
+                #[doc = concat!("Store ", stringify!($sbytes), " bytes in memory.")]
+                pub fn $store(&mut self, memarg: MemArg) -> Result<()> {
+                    self.masm.store(memarg.offset, $sbytes)
+                }
+            )+
+        }
+    };
+}
+
+impl Function {
+    /// Push the size of memory in pages.
+    pub fn _memory_size(&mut self, _mem: u32, _mem_byte: u8) -> Result<()> {
+        self.masm.memory_size(self.env.pages)
+    }
+
+    /// Grow memory by the delta of pages and push the previous size.
+    pub fn _memory_grow(&mut self, _mem: u32, _mem_byte: u8) -> Result<()> {
+        self.masm.memory_grow(self.env.pages)
+    }
+}
+
+impl_memory! {
+    load: [
+        _i32_load: 4,
+        _i64_load: 8,
+        _f32_load: 4,
+        _f64_load: 8,
+        _i32_load8_s: 1 => 4,
+        _i32_load8_u: 1,
+        _i32_load16_s: 2 => 4,
+        _i32_load16_u: 2,
+        _i64_load8_s: 1 => 8,
+        _i64_load8_u: 1,
+        _i64_load16_s: 2 => 8,
+        _i64_load16_u: 2,
+        _i64_load32_s: 4 => 8,
+        _i64_load32_u: 4
+    ],
+    store: [
+        _i32_store: 4,
+        _i64_store: 8,
+        _f32_store: 4,
+        _f64_store: 8,
+        _i32_store8: 1,
+        _i32_store16: 2,
+        _i64_store8: 1,
+        _i64_store16: 2,
+        _i64_store32: 4
+    ]
+}
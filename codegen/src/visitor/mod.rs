@@ -4,27 +4,67 @@
 //! `Function`; which defines a visitor per op-code, which validates
 //! and dispatches to the corresponding machine code emitter.
 
-use crate::{Function, Result};
+use crate::{Error, Function, Result};
 use paste::paste;
 use tracing::trace;
-use wasmparser::{for_each_operator, BlockType, BrTable, Ieee32, Ieee64, MemArg, VisitOperator};
+use wasmparser::{
+    for_each_operator, BlockType, BrTable, HeapType, Ieee32, Ieee64, MemArg, ValType, VisitOperator,
+};
 
+mod arith;
 mod call;
+mod cmp;
 mod control;
+mod ext;
+mod immutable;
 mod local;
 mod log;
+mod memory;
+mod reference;
+mod stack;
+mod storage;
 
 /// A macro to define unsupported WebAssembly operators.
 ///
 /// This macro calls itself recursively;
 /// 1. It no-ops when matching a supported operator.
 /// 2. Defines the visitor function and panics when
-///     matching an unsupported operator.
+///    matching an unsupported operator.
 macro_rules! impl_visit_operator {
     ( @mvp $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
         impl_visit_operator!($($rest)*);
     };
+    ( @saturating_float_to_int $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @threads $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        // The EVM is single-threaded, dropping the atomic operators
+        // silently would break the assumptions of the program.
+        #[allow(unused_variables)]
+        fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
+            let op = stringify!($visit).trim_start_matches("visit_").replace('_', ".");
+            Err(Error::UnsupportedOperator(op))
+        }
+
+        impl_visit_operator!($($rest)*);
+    };
+    ( @sign_extension $op:ident => $visit:ident $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @reference_types TypedSelect { ty: $argty:ty } => visit_typed_select $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @reference_types RefNull { hty: $argty:ty } => visit_ref_null $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @reference_types RefIsNull => visit_ref_is_null $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
+    ( @reference_types RefFunc { function_index: $argty:ty } => visit_ref_func $($rest:tt)* ) => {
+        impl_visit_operator!($($rest)*);
+    };
     ( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident $($rest:tt)* ) => {
+        #[allow(unused_variables)]
         fn $visit(&mut self $($(, $arg: $argty)*)?) -> Self::Output {
             trace!("{}", stringify!($op));
             Ok(())
@@ -62,12 +102,6 @@ macro_rules! map_wasm_operators {
         map_wasm_operators!(@integer32 $wasm, $evm $($arg: $argty),*);
         map_wasm_operators!(@integer64 $wasm, $evm $($arg: $argty),*);
     };
-    (@xdr $wasm:tt, $evm:tt $($arg:ident: $argty:ty),*) => {
-        paste!{
-            map_wasm_operators!(@integer [< $wasm _s >], $evm $($arg: $argty),*);
-            map_wasm_operators!(@integer [< $wasm _u >], $evm $($arg: $argty),*);
-        }
-    };
     (@float32 $wasm:tt, $evm:tt $($arg:ident: $argty:ty),*) => {
         map_wasm_operators!(@basic f32, $wasm, $evm $($arg: $argty),*);
     };
@@ -108,21 +142,9 @@ macro_rules! map_wasm_operators {
     };
     (
         all: [$($all:tt),+],
-        xdr: [$($xdr:tt),+],
         integer: [$($integer:tt),+],
         integer_and_float: [$($op:tt),+],
         float: [$($float:tt),+],
-        map: {
-            integer: [$($map_int_wasm:tt => $map_int_evm:tt),+],
-        },
-        mem: {
-            all: [$($mem:tt),+],
-            integer: [$($mem_integer:tt),+],
-            integer64: [$($mem_integer64:tt),+],
-            signed: [$($mem_signed:tt),+],
-            signed64: [$($mem_signed64:tt),+],
-            signed_and_float: [$($mem_signed_and_float:tt),+],
-        },
         masm: {
             $( $masm:tt $(: { $($marg:ident: $margty:ty),+ })? ),+
         },
@@ -134,49 +156,13 @@ macro_rules! map_wasm_operators {
             $(map_wasm_operators!(@integer_and_float $op);)+
 
             $(
-                map_wasm_operators!(@integer [< $all _s >], [< s $all >]);
                 map_wasm_operators!(@integer [< $all _u >], $all);
                 map_wasm_operators!(@float $all, $all);
             )+
 
             $(map_wasm_operators!(@integer $integer, $integer);)+
-            $(map_wasm_operators!(@xdr $xdr, $xdr);)+
             $(map_wasm_operators!(@float $float, $float);)+
 
-            $(
-                map_wasm_operators!(@integer [< $map_int_wasm _s >], [< s $map_int_evm >]);
-                map_wasm_operators!(@integer [< $map_int_wasm _u >], $map_int_evm);
-            )+
-
-            $(
-                map_wasm_operators!(@integer $mem, $mem _arg: MemArg);
-                map_wasm_operators!(@float $mem, $mem _arg: MemArg);
-            )+
-
-
-            $(
-                map_wasm_operators!(@xdr $mem_integer, $mem_integer _arg: MemArg);
-            )+
-
-            $(
-                map_wasm_operators!(@integer64 [< $mem_integer64 _s >], $mem_integer64 _arg: MemArg );
-                map_wasm_operators!(@integer64 [< $mem_integer64 _u >], $mem_integer64 _arg: MemArg );
-            )+
-
-
-            $(
-                map_wasm_operators!(@integer $mem_signed, $mem_signed _arg: MemArg);
-            )+
-
-            $(
-                map_wasm_operators!(@integer $mem_signed_and_float, $mem_signed_and_float _arg: MemArg);
-                map_wasm_operators!(@float $mem_signed_and_float, $mem_signed_and_float _arg: MemArg);
-            )+
-
-            $(
-                map_wasm_operators!(@integer64 $mem_signed64, $mem_signed64 _arg: MemArg);
-            )+
-
             $(
                 map_wasm_operators!(@field (masm) ($masm -> $masm) $( $($marg: $margty),+ )?);
             )+
@@ -194,36 +180,16 @@ impl<'a> VisitOperator<'a> for Function {
     for_each_operator!(impl_visit_operator);
 
     map_wasm_operators! {
-        all: [div, lt, gt, ge, le],
-        xdr: [shr, trunc_f32, trunc_f64],
-        integer: [and, clz, ctz, eqz, or, popcnt, rotl, rotr, shl, xor],
-        integer_and_float: [add, sub, mul, eq, ne],
+        all: [lt, gt, ge, le],
+        integer: [and, ctz, eqz, or, rotl, rotr, shl, xor],
+        integer_and_float: [add, eq, ne],
         float: [
-            abs, ceil, copysign, floor, max, min, nearest, neg, sqrt,
+            abs, ceil, copysign, div, floor, max, min, mul, nearest, neg, sqrt, sub,
             convert_i32_s, convert_i32_u, convert_i64_s, convert_i64_u,
             trunc
         ],
-        map: {
-            integer: [rem => mod],
-        },
-        mem: {
-            all: [load],
-            integer: [load8, load16],
-            integer64: [load32],
-            signed: [store8, store16],
-            signed64: [store32],
-            signed_and_float: [store],
-        },
         masm: {
             drop,
-            memory_grow: {
-                mem: u32,
-                mem_byte: u8
-            },
-            memory_size: {
-                mem: u32,
-                mem_byte: u8
-            },
             i32_const: {
                 value: i32
             },
@@ -236,19 +202,64 @@ impl<'a> VisitOperator<'a> for Function {
             f64_const: {
                 value: Ieee64
             },
+            i32_popcnt,
+            i64_popcnt,
+            i32_div_s,
+            i64_div_s,
+            i32_rem_s,
+            i32_rem_u,
+            i64_rem_s,
+            i64_rem_u,
+            i32_shr_s,
+            i32_shr_u,
+            i64_shr_s,
+            i64_shr_u,
             i32_wrap_i64,
             i64_extend_i32_s,
             i64_extend_i32_u,
+            i32_extend8_s,
+            i32_extend16_s,
+            i64_extend8_s,
+            i64_extend16_s,
+            i64_extend32_s,
             f32_demote_f64,
             f64_promote_f32,
             i32_reinterpret_f32,
             i64_reinterpret_f64,
             f32_reinterpret_i32,
             f64_reinterpret_i64,
-            return
+            i32_trunc_f32_s,
+            i32_trunc_f32_u,
+            i32_trunc_f64_s,
+            i32_trunc_f64_u,
+            i64_trunc_f32_s,
+            i64_trunc_f32_u,
+            i64_trunc_f64_s,
+            i64_trunc_f64_u,
+            i32_trunc_sat_f32_s,
+            i32_trunc_sat_f32_u,
+            i32_trunc_sat_f64_s,
+            i32_trunc_sat_f64_u,
+            i64_trunc_sat_f32_s,
+            i64_trunc_sat_f32_u,
+            i64_trunc_sat_f64_s,
+            i64_trunc_sat_f64_u
         },
         global: {
-            else, select, end, nop, unreachable,
+            else, select, end, nop, unreachable, return, i32_sub, i64_sub,
+            i32_mul, i64_mul, i32_div_u, i64_div_u, i32_clz, i64_clz,
+            i32_lt_s, i32_gt_s, i32_le_s, i32_ge_s,
+            i64_lt_s, i64_gt_s, i64_le_s, i64_ge_s,
+            typed_select: {
+                ty: ValType
+            },
+            ref_null: {
+                hty: HeapType
+            },
+            ref_is_null,
+            ref_func: {
+                function_index: u32
+            },
             if: {
                 blockty: BlockType
             },
@@ -289,7 +300,38 @@ impl<'a> VisitOperator<'a> for Function {
                 type_index: u32,
                 table_index: u32,
                 table_byte: u8
-            }
+            },
+            memory_grow: {
+                mem: u32,
+                mem_byte: u8
+            },
+            memory_size: {
+                mem: u32,
+                mem_byte: u8
+            },
+            i32_load: { memarg: MemArg },
+            i64_load: { memarg: MemArg },
+            f32_load: { memarg: MemArg },
+            f64_load: { memarg: MemArg },
+            i32_load8_s: { memarg: MemArg },
+            i32_load8_u: { memarg: MemArg },
+            i32_load16_s: { memarg: MemArg },
+            i32_load16_u: { memarg: MemArg },
+            i64_load8_s: { memarg: MemArg },
+            i64_load8_u: { memarg: MemArg },
+            i64_load16_s: { memarg: MemArg },
+            i64_load16_u: { memarg: MemArg },
+            i64_load32_s: { memarg: MemArg },
+            i64_load32_u: { memarg: MemArg },
+            i32_store: { memarg: MemArg },
+            i64_store: { memarg: MemArg },
+            f32_store: { memarg: MemArg },
+            f64_store: { memarg: MemArg },
+            i32_store8: { memarg: MemArg },
+            i32_store16: { memarg: MemArg },
+            i64_store8: { memarg: MemArg },
+            i64_store16: { memarg: MemArg },
+            i64_store32: { memarg: MemArg }
         }
     }
 }
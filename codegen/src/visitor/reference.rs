@@ -0,0 +1,29 @@
+//! Reference instructions
+//!
+//! The function references are words on the stack, a function is
+//! referenced by its index plus one and the null reference is zero,
+//! which is also the initial value of the locals.
+
+use crate::{wasm::ToLSBytes, Error, Function, Result};
+use wasmparser::HeapType;
+
+impl Function {
+    /// Push the null reference.
+    pub fn _ref_null(&mut self, hty: HeapType) -> Result<()> {
+        if hty != HeapType::Func {
+            return Err(Error::UnsupportedOperator(format!("ref.null {hty:?}")));
+        }
+
+        self.masm._push0()
+    }
+
+    /// Check if the reference is null.
+    pub fn _ref_is_null(&mut self) -> Result<()> {
+        self.masm._eqz()
+    }
+
+    /// Push the reference of the function.
+    pub fn _ref_func(&mut self, function_index: u32) -> Result<()> {
+        self.masm.push(&(function_index + 1).to_ls_bytes())
+    }
+}
@@ -0,0 +1,88 @@
+//! Stack scheduling
+//!
+//! The operand order of some EVM opcodes is the reverse of WASM, e.g.
+//! `SUB` subtracts the second item from the top. Instead of swapping
+//! the operands at runtime, the instructions producing them are
+//! reordered if they are simple loads without side effects.
+
+use crate::{Function, Result};
+use opcodes::ShangHai as OpCode;
+
+impl Function {
+    /// Subtract two 32-bit integers.
+    pub fn _i32_sub(&mut self) -> Result<()> {
+        self.sub()
+    }
+
+    /// Subtract two 64-bit integers.
+    pub fn _i64_sub(&mut self) -> Result<()> {
+        self.sub()
+    }
+
+    /// Subtract the top of the stack from the second item.
+    fn sub(&mut self) -> Result<()> {
+        if self.swap_operands() {
+            self.masm.asm._sub()
+        } else {
+            self.masm._sub()
+        }
+    }
+
+    /// Swap the instructions of the last two operands, returns if
+    /// they have been swapped.
+    ///
+    /// Only the operands pushed by `PUSH`, optionally followed by
+    /// `MLOAD` or `CALLDATALOAD`, as the last two instructions are
+    /// swapped, which covers constants and local variables, their
+    /// entries in the source map are swapped as well.
+    fn swap_operands(&mut self) -> bool {
+        let rhs = self.backtrace.pop();
+        let lhs = self.backtrace.pop();
+
+        let buffer = self.masm.asm.buffer_mut();
+        let len = lhs.len() + rhs.len();
+        let swappable = is_operand(&lhs)
+            && is_operand(&rhs)
+            && buffer.ends_with(&[lhs.as_slice(), rhs.as_slice()].concat());
+
+        if swappable {
+            let start = buffer.len() - len;
+            buffer.truncate(start);
+            buffer.extend_from_slice(&rhs);
+            buffer.extend_from_slice(&lhs);
+
+            let start = start as u16;
+            self.table
+                .swap_source(start, start + lhs.len() as u16, rhs.len() as u16);
+
+            self.backtrace.push(rhs);
+            self.backtrace.push(lhs);
+        } else {
+            self.backtrace.push(lhs);
+            self.backtrace.push(rhs);
+        }
+
+        swappable
+    }
+}
+
+/// If the instruction only pushes a value without side effects.
+fn is_operand(instr: &[u8]) -> bool {
+    let Some((&op, rest)) = instr.split_first() else {
+        return false;
+    };
+
+    let push0 = u8::from(OpCode::PUSH0);
+    let push32 = u8::from(OpCode::PUSH32);
+    if !(push0..=push32).contains(&op) {
+        return false;
+    }
+
+    match rest.get((op - push0) as usize..) {
+        Some([]) => true,
+        Some([load]) => [OpCode::MLOAD, OpCode::CALLDATALOAD]
+            .into_iter()
+            .any(|op| *load == u8::from(op)),
+        _ => false,
+    }
+}
@@ -0,0 +1,30 @@
+//! Storage instructions
+
+use crate::{Function, Result};
+
+impl Function {
+    /// Store the value in storage only if it differs from the present
+    /// one, the `SSTORE` is skipped at the cost of a `SLOAD` otherwise.
+    ///
+    /// STACK: [value, key] -> []
+    pub(crate) fn sstore_if_changed(&mut self) -> Result<()> {
+        // [value, key] -> [value, key, unchanged]
+        self.masm._dup1()?;
+        self.masm._sload()?;
+        self.masm._dup3()?;
+        self.masm._eq()?;
+
+        let skip = self.masm.pc_offset();
+        self.masm.increment_sp(1)?;
+        self.masm._jumpi()?;
+
+        self.masm._dup2()?;
+        self.masm._dup2()?;
+        self.masm._sstore()?;
+
+        self.table.label(skip, self.masm.pc_offset());
+        self.masm._jumpdest()?;
+        self.masm._drop()?;
+        self.masm._drop()
+    }
+}
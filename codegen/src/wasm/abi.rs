@@ -88,6 +88,7 @@ macro_rules! offset {
 }
 
 offset! {
+    (u128, 16),
     (usize, 8),
     (u64, 8),
     (i64, 8),
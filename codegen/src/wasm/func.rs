@@ -1,10 +1,16 @@
 //! Function handler
-use crate::{wasm::Exports, Error, Result};
+use crate::{
+    wasm::{Calls, Env, Exports, Table},
+    Error, Result,
+};
+use opcodes::ShangHai as OpCode;
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     ops::{Deref, DerefMut},
 };
-use wasmparser::{FuncType, FuncValidator, FunctionBody, ValidatorResources, WasmModuleResources};
+use wasmparser::{
+    FuncType, FuncValidator, FunctionBody, Operator, ValidatorResources, WasmModuleResources,
+};
 
 /// Function with validator.
 pub struct Function<'f> {
@@ -56,9 +62,19 @@ impl<'f> Functions<'f> {
 
     /// Remove all selector functions
     pub fn drain_selectors(&mut self, exports: &Exports) -> Self {
+        self.drain(exports.selectors())
+    }
+
+    /// Remove all storage layout functions
+    pub fn drain_layouts(&mut self, exports: &Exports) -> Self {
+        self.drain(exports.layouts())
+    }
+
+    /// Remove the functions of the indexes
+    fn drain(&mut self, indexes: Vec<u32>) -> Self {
         let mut functions = Self::default();
 
-        for index in exports.selectors() {
+        for index in indexes {
             if let Some(function) = self.0.remove(&index) {
                 functions.0.insert(index, function);
             }
@@ -71,6 +87,86 @@ impl<'f> Functions<'f> {
     pub fn into_funcs(self) -> Vec<Function<'f>> {
         self.0.into_values().collect()
     }
+
+    /// Get the functions reachable from the functions by the internal
+    /// calls, the indirect calls may reach all of the functions in the
+    /// table.
+    pub fn calls(&self, table: &Table) -> Result<Calls> {
+        let mut direct = BTreeMap::<u32, BTreeSet<u32>>::new();
+        for (index, func) in self.0.iter() {
+            let callees = direct.entry(*index).or_default();
+            let mut reader = func.body.get_operators_reader()?;
+            while !reader.eof() {
+                match reader.read()? {
+                    Operator::Call { function_index } if self.0.contains_key(&function_index) => {
+                        callees.insert(function_index);
+                    }
+                    Operator::CallIndirect { .. } => {
+                        callees.extend(table.values().map(|(func, _)| *func));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut calls = Calls::default();
+        for index in direct.keys() {
+            let mut reachable = BTreeSet::new();
+            let mut queue = vec![*index];
+            while let Some(caller) = queue.pop() {
+                for callee in direct.get(&caller).into_iter().flatten() {
+                    if reachable.insert(*callee) {
+                        queue.push(*callee);
+                    }
+                }
+            }
+
+            calls.insert(*index, reachable);
+        }
+
+        Ok(calls)
+    }
+
+    /// Check if the function and the functions it calls read or
+    /// write neither the state nor the environment.
+    pub fn check_pure(&self, env: &Env, name: &str) -> Result<()> {
+        let impure = |op: OpCode| Error::ImpureFunction(name.into(), op);
+        let mut visited = BTreeSet::new();
+        let mut queue = vec![env.query_func(name)?];
+
+        while let Some(index) = queue.pop() {
+            let Some(func) = self.0.get(&index) else {
+                continue;
+            };
+
+            if !visited.insert(index) {
+                continue;
+            }
+
+            let mut reader = func.body.get_operators_reader()?;
+            while !reader.eof() {
+                match reader.read()? {
+                    Operator::Call { function_index } => match env.imports.get(&function_index) {
+                        Some(host) => {
+                            if let Some(op) = host.impure_opcode() {
+                                return Err(impure(op));
+                            }
+                        }
+                        None => queue.push(function_index),
+                    },
+                    Operator::GlobalGet { global_index }
+                        if env.globals.get(&global_index).is_some_and(|g| g.mutable) =>
+                    {
+                        return Err(impure(OpCode::SLOAD));
+                    }
+                    Operator::GlobalSet { .. } => return Err(impure(OpCode::SSTORE)),
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<'f> Deref for Functions<'f> {
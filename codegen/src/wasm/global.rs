@@ -0,0 +1,125 @@
+//! WASM globals
+
+use crate::{wasm::ToLSBytes, Error, Result};
+use smallvec::SmallVec;
+use wasmparser::{ConstExpr, GlobalType, Operator, ValType};
+
+/// WASM global with the evaluated init expression.
+///
+/// - **immutable**: baked into the bytecode as constant.
+/// - **mutable**: backed by a storage slot initialized in the
+///   constructor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Global {
+    /// The type of the global.
+    pub ty: ValType,
+    /// If the global is mutable.
+    pub mutable: bool,
+    /// The evaluated init value.
+    pub value: i64,
+}
+
+impl Global {
+    /// Create global from the init expression.
+    ///
+    /// Supports `i32.const`, `i64.const`, `global.get` of the
+    /// imported globals and the integer arithmetic of the extended
+    /// constant expressions.
+    ///
+    /// The floats of `f32.const` and `f64.const` are evaluated to their
+    /// IEEE 754 bit patterns, which are pushed as they are.
+    pub fn new(
+        index: u32,
+        ty: ValType,
+        mutable: bool,
+        init: &ConstExpr<'_>,
+        globals: &super::Globals,
+    ) -> Result<Self> {
+        let mut stack: SmallVec<[i64; 4]> = Default::default();
+        let mut reader = init.get_operators_reader();
+        let unsupported = || Error::UnsupportedGlobalInit(index);
+
+        while !reader.eof() {
+            let op = reader.read()?;
+            if let Operator::End = op {
+                break;
+            }
+
+            let value = match op {
+                Operator::I32Const { value } => value as i64,
+                Operator::I64Const { value } => value,
+                Operator::F32Const { value } => value.bits() as i32 as i64,
+                Operator::F64Const { value } => value.bits() as i64,
+                Operator::GlobalGet { global_index } => {
+                    globals.get(&global_index).ok_or_else(unsupported)?.value
+                }
+                _ => {
+                    let rhs = stack.pop().ok_or_else(unsupported)?;
+                    let lhs = stack.pop().ok_or_else(unsupported)?;
+                    match op {
+                        Operator::I32Add => (lhs as i32).wrapping_add(rhs as i32) as i64,
+                        Operator::I32Sub => (lhs as i32).wrapping_sub(rhs as i32) as i64,
+                        Operator::I32Mul => (lhs as i32).wrapping_mul(rhs as i32) as i64,
+                        Operator::I64Add => lhs.wrapping_add(rhs),
+                        Operator::I64Sub => lhs.wrapping_sub(rhs),
+                        Operator::I64Mul => lhs.wrapping_mul(rhs),
+                        _ => {
+                            tracing::error!(
+                                "Unsupported init expression of global {index}: {op:?}"
+                            );
+                            return Err(unsupported());
+                        }
+                    }
+                }
+            };
+
+            stack.push(value);
+        }
+
+        let value = stack.pop().ok_or_else(unsupported)?;
+        if !stack.is_empty()
+            || !matches!(
+                ty,
+                ValType::I32 | ValType::I64 | ValType::F32 | ValType::F64
+            )
+        {
+            return Err(unsupported());
+        }
+
+        Ok(Self { ty, mutable, value })
+    }
+
+    /// Create global from the imported one.
+    ///
+    /// Only the bases of the dynamic linking, `env.__memory_base` and
+    /// `env.__table_base`, are supported, the module is placed at the
+    /// beginning of the memory and the table, so they are zero.
+    pub fn imported(module: &str, name: &str, ty: GlobalType) -> Option<Self> {
+        if module != "env"
+            || !matches!(name, "__memory_base" | "__table_base")
+            || ty.mutable
+            || !matches!(ty.content_type, ValType::I32 | ValType::I64)
+        {
+            return None;
+        }
+
+        Some(Self {
+            ty: ty.content_type,
+            mutable: false,
+            value: 0,
+        })
+    }
+
+    /// Get the value in the lowest significant bytes.
+    pub fn to_ls_bytes(&self) -> SmallVec<[u8; 8]> {
+        match self.ty {
+            ValType::I32 | ValType::F32 => SmallVec::from_slice(&(self.value as i32).to_ls_bytes()),
+            _ => self.value.to_ls_bytes(),
+        }
+    }
+
+    /// The storage key of the backing slot of a mutable global.
+    pub fn storage_key(index: u32) -> [u8; 32] {
+        zabi::selector::keccak256(format!("zink.global.{index}").as_bytes())
+    }
+}
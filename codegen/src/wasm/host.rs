@@ -3,7 +3,11 @@
 use crate::{Error, Result};
 use anyhow::anyhow;
 use core::str::FromStr;
-use opcodes::{OpCode as _, ShangHai as OpCode};
+use opcodes::{Group, OpCode as _, ShangHai as OpCode};
+
+/// Opcodes introduced in Cancun, which are not available in
+/// Shanghai, the target fork of the compiler.
+const CANCUN_OPCODES: [&str; 5] = ["blobhash", "blobbasefee", "mcopy", "tload", "tstore"];
 
 /// EVM built-in function.
 #[derive(Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq)]
@@ -16,12 +20,40 @@ pub enum HostFunc {
     //
     /// Emit ABI to the compiler.
     EmitABI,
+    /// Emit the storage layout to the compiler.
+    EmitStorage,
     /// check equal of two addresses
     AddressEq,
     /// Push u256 max to stack
     U256MAX,
+    /// 128-bit arithmetic with the result wrapped to 128 bits
+    U128(OpCode),
+    /// Push u128 max to stack
+    U128MAX,
+    /// Wrap a 64-bit integer as u128
+    U128FromU64,
+    /// Push `bytesN` left-aligned in its word
+    FixedBytes(u8),
+    /// Copy calldata into memory
+    Calldata,
+    /// Push the selector of the calldata
+    Selector,
+    /// Push the offset of the free memory
+    FreeMemory,
     /// Revert messages with length of slots
     Revert(usize),
+    /// Revert with message in the ABI encoding of `Error(string)`
+    RevertError,
+    /// Emit event with the counts of the indexed topics and the data words
+    Emit(usize, usize),
+    /// Revert with the panic code in the ABI encoding of `Panic(uint256)`
+    RevertPanic,
+    /// Revert with the return data of the last call
+    BubbleRevert,
+    /// Push the immutable baked into the runtime bytecode
+    Immutable,
+    /// Store the value in storage only if it changes
+    SStoreIfChanged,
     /// Compiler labels
     Label(CompilerLabel),
 }
@@ -42,6 +74,35 @@ impl HostFunc {
             _ => 0,
         }
     }
+
+    /// Get the opcode if the host function reads or writes the state
+    /// or the environment, which is forbidden in pure functions.
+    pub fn impure_opcode(&self) -> Option<OpCode> {
+        let op = match self {
+            Self::Evm(op) => op,
+            Self::SStoreIfChanged => return Some(OpCode::SSTORE),
+            _ => return None,
+        };
+
+        let impure = match op.group() {
+            Group::BlockInformation | Group::Logging => true,
+            Group::EnvironmentalInformation => !matches!(
+                op,
+                OpCode::CALLDATALOAD
+                    | OpCode::CALLDATASIZE
+                    | OpCode::CALLDATACOPY
+                    | OpCode::CODESIZE
+                    | OpCode::CODECOPY
+                    | OpCode::RETURNDATASIZE
+                    | OpCode::RETURNDATACOPY
+            ),
+            Group::StackMemoryStorageFlow => matches!(op, OpCode::SLOAD | OpCode::SSTORE),
+            Group::System => !matches!(op, OpCode::RETURN | OpCode::REVERT | OpCode::INVALID),
+            _ => false,
+        };
+
+        impure.then_some(*op)
+    }
 }
 
 impl TryFrom<(&str, &str)> for HostFunc {
@@ -53,6 +114,24 @@ impl TryFrom<(&str, &str)> for HostFunc {
             ("asm", name) => {
                 if name.starts_with("sload") {
                     Ok(Self::Evm(OpCode::SLOAD))
+                } else if name.starts_with("mload") {
+                    Ok(Self::Evm(OpCode::MLOAD))
+                } else if name.starts_with("immutable") {
+                    Ok(Self::Immutable)
+                } else if name == "revert_error" {
+                    Ok(Self::RevertError)
+                } else if name == "revert_panic" {
+                    Ok(Self::RevertPanic)
+                } else if let Some((topics, words)) = name
+                    .strip_prefix("emit")
+                    .and_then(|counts| counts.split_once('_'))
+                {
+                    Ok(Self::Emit(
+                        topics.parse().map_err(|e| anyhow!("{e}"))?,
+                        words.parse().map_err(|e| anyhow!("{e}"))?,
+                    ))
+                } else if let Some(size) = name.strip_prefix("push_bytes") {
+                    Ok(Self::FixedBytes(size.parse().map_err(|e| anyhow!("{e}"))?))
                 } else if name.starts_with("revert") {
                     let count = name.trim_start_matches("revert");
 
@@ -64,14 +143,35 @@ impl TryFrom<(&str, &str)> for HostFunc {
             }
             ("evm", name) => Ok(Self::Evm(OpCode::from_str(name).map_err(|_| {
                 tracing::error!("Failed to load host function: {:?}", import);
-                Error::HostFuncNotFound(module.into(), name.into())
+                if CANCUN_OPCODES.contains(&name) {
+                    Error::OpcodeNotAvailable {
+                        opcode: name.to_uppercase(),
+                        fork: "Shanghai",
+                    }
+                } else {
+                    Error::HostFuncNotFound(module.into(), name.into())
+                }
             })?)),
             ("zinkc", "emit_abi") => Ok(Self::EmitABI),
+            ("zinkc", "emit_storage") => Ok(Self::EmitStorage),
             ("zinkc", "address_eq") => Ok(Self::Evm(OpCode::EQ)),
+            ("zinkc", "address_is_zero") => Ok(Self::Evm(OpCode::ISZERO)),
             ("zinkc", "u256_add") => Ok(Self::Evm(OpCode::ADD)),
             ("zinkc", "u256_sub") => Ok(Self::Evm(OpCode::SUB)),
             ("zinkc", "u256_lt") => Ok(Self::Evm(OpCode::LT)),
             ("zinkc", "u256_max") => Ok(Self::U256MAX),
+            ("zinkc", "u128_add") => Ok(Self::U128(OpCode::ADD)),
+            ("zinkc", "u128_sub") => Ok(Self::U128(OpCode::SUB)),
+            ("zinkc", "u128_mul") => Ok(Self::U128(OpCode::MUL)),
+            ("zinkc", "u128_lt") => Ok(Self::Evm(OpCode::LT)),
+            ("zinkc", "u128_max") => Ok(Self::U128MAX),
+            ("zinkc", "u128_from_u64") => Ok(Self::U128FromU64),
+            ("zinkc", "calldata") => Ok(Self::Calldata),
+            ("zinkc", "selector") => Ok(Self::Selector),
+            ("zinkc", "free_memory") => Ok(Self::FreeMemory),
+            ("zinkc", "bubble_revert") => Ok(Self::BubbleRevert),
+            ("zinkc", "sstore_if_changed") => Ok(Self::SStoreIfChanged),
+            ("zinkc", "keccak256_memory") => Ok(Self::Evm(OpCode::KECCAK256)),
             ("zinkc", "label_reserve_mem_32") => Ok(Self::Label(CompilerLabel::ReserveMemory32)),
             ("zinkc", "label_reserve_mem_64") => Ok(Self::Label(CompilerLabel::ReserveMemory64)),
             _ => {
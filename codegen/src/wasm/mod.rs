@@ -3,19 +3,21 @@
 mod abi;
 mod data;
 mod func;
+mod global;
 mod host;
 
 pub use self::{
     abi::{ToLSBytes, Type},
     data::Data,
     func::{Function, Functions},
+    global::Global,
     host::HostFunc,
 };
-use crate::{Error, Result};
+use crate::{layout::SlotInfo, Error, Pool, Result};
 use host::CompilerLabel;
 use smallvec::SmallVec;
-use std::collections::BTreeMap;
-use wasmparser::Operator;
+use std::collections::{BTreeMap, BTreeSet};
+use wasmparser::{FuncType, Operator};
 use zabi::Abi;
 
 macro_rules! impl_deref {
@@ -46,8 +48,12 @@ macro_rules! impl_deref {
 impl_deref! {
     ("WASM import section", Imports, BTreeMap<u32, HostFunc>),
     ("WASM export section", Exports, BTreeMap<u32, String>),
+    ("WASM global section", Globals, BTreeMap<u32, Global>),
     ("WASM slot registry", Slots, BTreeMap<u32, u32>),
-    ("WASM function registry", Funcs, BTreeMap<u32, (u32, u32)>)
+    ("WASM function registry", Funcs, BTreeMap<u32, (u32, u32)>),
+    ("WASM type section", Types, BTreeMap<u32, FuncType>),
+    ("WASM function table", Table, BTreeMap<u32, (u32, FuncType)>),
+    ("WASM call graph", Calls, BTreeMap<u32, BTreeSet<u32>>)
 }
 
 /// A struct that holds the environment wasm module.
@@ -57,14 +63,35 @@ pub struct Env {
     pub imports: Imports,
     /// WASM exports
     pub exports: Exports,
+    /// WASM globals
+    pub globals: Globals,
     /// Function memory slots
     pub slots: Slots,
     /// Function params count
     pub funcs: Funcs,
+    /// Function types by their type indexes
+    pub types: Types,
+    /// Functions and their types in the table by their table indexes
+    pub table: Table,
+    /// Functions reachable from the functions by the internal calls
+    pub calls: Calls,
+    /// Memory slots reserved by all functions
+    pub memory: u32,
+    /// Initial pages of the WASM memory
+    pub pages: u64,
     /// WASM data slots
     pub data: Data,
     /// Current function index
     pub index: Option<u32>,
+    /// If skip the `SSTORE`s writing the present values
+    pub sstore_if_changed: bool,
+    /// If keep the induction variables of the loops in memory
+    pub no_loop_induction: bool,
+    /// If check the widths of the values consumed by the integer
+    /// instructions
+    pub strict_width: bool,
+    /// Constants stored in memory at the entry of the runtime bytecode
+    pub pool: Pool,
 }
 
 impl Env {
@@ -80,29 +107,57 @@ impl Env {
 
     /// Load abi from function
     pub fn load_abi(&self, fun: &Function<'_>) -> Result<Abi> {
+        let abi = self
+            .load_emitted(fun, HostFunc::EmitABI, "emit_abi")?
+            .ok_or(Error::InvalidSelector)?;
+
+        Abi::from_hex(String::from_utf8_lossy(&abi)).map_err(Into::into)
+    }
+
+    /// Load the storage layout from functions
+    pub fn load_layout(&self, funs: &Functions<'_>) -> Result<Vec<SlotInfo>> {
+        let mut layout: Vec<SlotInfo> = Default::default();
+        for (_, fun) in funs.iter() {
+            let info = self
+                .load_emitted(fun, HostFunc::EmitStorage, "emit_storage")?
+                .ok_or_else(|| Error::InvalidSlotInfo(format!("function {}", fun.index())))?;
+
+            layout.push(String::from_utf8_lossy(&info).parse()?);
+        }
+
+        layout.sort_by_key(|info| info.slot);
+        Ok(layout)
+    }
+
+    /// Load the data emitted to the compiler by the host function,
+    /// the function is expected to be `[i32.const ptr, i32.const len,
+    /// call host]`.
+    fn load_emitted(
+        &self,
+        fun: &Function<'_>,
+        host: HostFunc,
+        name: &str,
+    ) -> Result<Option<Vec<u8>>> {
         let mut reader = fun.body.get_operators_reader()?;
 
         let Operator::I32Const { value: offset } = reader.read()? else {
-            return Err(Error::InvalidSelector);
+            return Ok(None);
         };
         let Operator::I32Const { value: length } = reader.read()? else {
-            return Err(Error::InvalidSelector);
+            return Ok(None);
         };
-
-        // Validate zinkc helper `emit_abi`
         let Operator::Call {
             function_index: index,
         } = reader.read()?
         else {
-            return Err(Error::InvalidSelector);
+            return Ok(None);
         };
 
-        if !self.imports.is_emit_abi(index) {
-            return Err(Error::FuncNotImported("emit_abi".into()));
+        if self.imports.get(&index) != Some(&host) {
+            return Err(Error::FuncNotImported(name.into()));
         }
 
-        let abi = self.data.load(offset, length as usize)?;
-        Abi::from_hex(String::from_utf8_lossy(&abi)).map_err(Into::into)
+        self.data.load(offset, length as usize).map(Some)
     }
 
     /// Query exported function from selector.
@@ -135,6 +190,18 @@ impl Env {
         self.imports.len() as u32 == index
     }
 
+    /// If the call to the function may re-enter the current function,
+    /// i.e. the current function is reachable from the callee.
+    pub fn is_reentrant(&self, callee: u32) -> bool {
+        self.index.is_some_and(|index| {
+            index == callee
+                || self
+                    .calls
+                    .get(&callee)
+                    .is_some_and(|calls| calls.contains(&index))
+        })
+    }
+
     /// Clone a new environment with function index provided
     pub fn with_index(&self, index: u32) -> Self {
         let mut this = self.clone();
@@ -188,9 +255,19 @@ impl Imports {
 impl Exports {
     /// Get all function selectors
     pub fn selectors(&self) -> Vec<u32> {
+        self.ends_with("_selector")
+    }
+
+    /// Get all storage layout functions
+    pub fn layouts(&self) -> Vec<u32> {
+        self.ends_with("_storage_layout")
+    }
+
+    /// Get the functions exported with the suffix
+    fn ends_with(&self, suffix: &str) -> Vec<u32> {
         self.iter()
             .filter_map(|(index, export)| {
-                if export.ends_with("_selector") {
+                if export.ends_with(suffix) {
                     Some(*index)
                 } else {
                     None
@@ -0,0 +1,239 @@
+//! Widths of the stack values.
+//!
+//! The values on the stack of EVM are words, the host functions may
+//! leave values wider than their WASM types, e.g. an address typed as
+//! `i32`, which are consumed by the integer instructions as they are
+//! without being wrapped.
+//!
+//! With the `strict_width` option, the wide values are tracked on the
+//! operand stack of WASM and consuming them with the integer
+//! instructions of the narrower types fails the compilation.
+//!
+//! The values sign-extended to 256 bits, e.g. the results of the
+//! sign extension instructions, are tracked as well, the signed
+//! comparisons skip sign-extending them again. The results of the sign
+//! extension instructions are wrapped to their widths lazily, i.e.
+//! before they are consumed by the other instructions.
+use crate::{
+    wasm::{Env, HostFunc},
+    Error, Result,
+};
+use opcodes::ShangHai as OpCode;
+use std::collections::{BTreeMap, BTreeSet};
+use wasmparser::{FuncValidator, Operator, ValType, ValidatorResources, WasmModuleResources};
+
+/// Wide and sign-extended values on the operand stack.
+#[derive(Debug, Default)]
+pub struct Widths {
+    /// Bits of the wide values by their heights on the operand stack.
+    wide: BTreeMap<u32, u16>,
+    /// Heights of the values sign-extended to 256 bits.
+    extended: BTreeSet<u32>,
+    /// Bytes of the sign-extended values not wrapped to their widths
+    /// yet by their heights on the operand stack.
+    unwrapped: BTreeMap<u32, usize>,
+    /// Height of the operand stack before the visiting operator.
+    height: u32,
+}
+
+impl Widths {
+    /// Check the widths of the operands consumed by the operator, the
+    /// operand stack has `height` values before the operator.
+    pub fn check(&self, op: &Operator<'_>, height: u32) -> Result<()> {
+        let Some((pops, bits)) = consumes(op) else {
+            return Ok(());
+        };
+
+        match self
+            .wide
+            .range(height.saturating_sub(pops)..height)
+            .find(|(_, wide)| **wide > bits)
+        {
+            Some((_, wide)) => Err(Error::WidthMismatch(format!("{op:?}"), *wide)),
+            None => Ok(()),
+        }
+    }
+
+    /// Enter the operator, the operand stack has `height` values
+    /// before it.
+    pub fn enter(&mut self, height: u32) {
+        self.height = height;
+    }
+
+    /// If the operand at `depth` from the top of the operand stack is
+    /// sign-extended to 256 bits.
+    pub fn is_extended(&self, depth: u32) -> bool {
+        self.height
+            .checked_sub(depth + 1)
+            .is_some_and(|height| self.extended.contains(&height))
+    }
+
+    /// Take the sign-extended values to be wrapped to their widths
+    /// before the operator, returns their depths from the top of the
+    /// operand stack with their bytes.
+    ///
+    /// The values are kept sign-extended for the signed comparisons
+    /// and the instructions pushing a value over them.
+    pub fn take_unwrapped(&mut self, op: &Operator<'_>) -> Vec<(u8, usize)> {
+        use Operator::*;
+
+        let keeps = |depth: u32| match op {
+            I32LtS | I32GtS | I32LeS | I32GeS | I64LtS | I64GtS | I64LeS | I64GeS => depth < 2,
+            I32Const { .. } | I64Const { .. } | LocalGet { .. } | GlobalGet { .. } => depth == 0,
+            _ => false,
+        };
+
+        let mut taken = Vec::new();
+        for (height, bytes) in std::mem::take(&mut self.unwrapped) {
+            match self.height.checked_sub(height + 1) {
+                Some(depth) if keeps(depth) => {
+                    self.unwrapped.insert(height, bytes);
+                }
+                depth => {
+                    self.extended.remove(&height);
+                    taken.extend(depth.map(|depth| (depth as u8, bytes)));
+                }
+            }
+        }
+
+        taken
+    }
+
+    /// Update the wide values with the visited operator.
+    pub fn visit(
+        &mut self,
+        op: &Operator<'_>,
+        height: u32,
+        validator: &FuncValidator<ValidatorResources>,
+        env: &Env,
+    ) -> Result<()> {
+        let after = validator.operand_stack_height();
+        let Operator::Call { function_index } = op else {
+            let base = consumes(op).map_or(height, |(pops, _)| height.saturating_sub(pops));
+            self.wide.split_off(&base.min(after));
+            self.extended.split_off(&base.min(after));
+            self.unwrapped.split_off(&base.min(after));
+            if is_control(op) {
+                // the values may come from the other branches.
+                self.extended.clear();
+            } else if extends(op) {
+                self.extended.insert(after - 1);
+                if let Some(bytes) = unwrapped(op) {
+                    self.unwrapped.insert(after - 1, bytes);
+                }
+            }
+
+            return Ok(());
+        };
+
+        let ty = validator
+            .resources()
+            .type_of_function(*function_index)
+            .ok_or(Error::FuncNotFound(*function_index))?;
+        self.wide
+            .split_off(&height.saturating_sub(ty.params().len() as u32));
+        self.extended
+            .split_off(&height.saturating_sub(ty.params().len() as u32));
+        self.unwrapped
+            .split_off(&height.saturating_sub(ty.params().len() as u32));
+
+        let Some(HostFunc::Evm(opcode)) = env.imports.get(function_index) else {
+            return Ok(());
+        };
+
+        let declared = match ty.results() {
+            [ValType::I32] => 32,
+            [ValType::I64] => 64,
+            _ => return Ok(()),
+        };
+
+        let bits = bits(*opcode);
+        if bits > declared {
+            self.wide.insert(after - 1, bits);
+        }
+
+        Ok(())
+    }
+}
+
+/// If the instruction leaves a value sign-extended to 256 bits.
+///
+/// The constants are pushed in the two's complement of their widths,
+/// only the non-negative ones are sign-extended as well.
+fn extends(op: &Operator<'_>) -> bool {
+    use Operator::*;
+
+    match op {
+        I32Const { value } => *value >= 0,
+        I64Const { value } => *value >= 0,
+        _ => matches!(
+            op,
+            I32Extend8S | I32Extend16S | I64Extend8S | I64Extend16S | I64Extend32S
+        ),
+    }
+}
+
+/// Get the bytes of the result of the sign extension instruction,
+/// which is left sign-extended to 256 bits.
+fn unwrapped(op: &Operator<'_>) -> Option<usize> {
+    use Operator::*;
+
+    match op {
+        I32Extend8S | I32Extend16S => Some(4),
+        I64Extend8S | I64Extend16S | I64Extend32S => Some(8),
+        _ => None,
+    }
+}
+
+/// If the instruction is a control instruction.
+fn is_control(op: &Operator<'_>) -> bool {
+    use Operator::*;
+
+    matches!(
+        op,
+        Block { .. }
+            | Loop { .. }
+            | If { .. }
+            | Else
+            | End
+            | Br { .. }
+            | BrIf { .. }
+            | BrTable { .. }
+            | Return
+            | Unreachable
+    )
+}
+
+/// Get the bits of the value left by the opcode.
+fn bits(opcode: OpCode) -> u16 {
+    match opcode {
+        OpCode::CALLDATASIZE
+        | OpCode::RETURNDATASIZE
+        | OpCode::CODESIZE
+        | OpCode::EXTCODESIZE
+        | OpCode::MSIZE
+        | OpCode::PC => 32,
+        OpCode::TIMESTAMP | OpCode::NUMBER | OpCode::GAS | OpCode::GASLIMIT => 64,
+        _ => 256,
+    }
+}
+
+/// Get the number of the operands consumed by the integer instruction
+/// and the bits of them, the wrapping instruction consumes any width.
+fn consumes(op: &Operator<'_>) -> Option<(u32, u16)> {
+    use Operator::*;
+
+    Some(match op {
+        I32WrapI64 => (1, 256),
+        I32Eqz | I32Clz | I32Ctz | I32Popcnt | I32Extend8S | I32Extend16S | I64ExtendI32S
+        | I64ExtendI32U => (1, 32),
+        I64Eqz | I64Clz | I64Ctz | I64Popcnt | I64Extend8S | I64Extend16S | I64Extend32S => (1, 64),
+        I32Eq | I32Ne | I32LtS | I32LtU | I32GtS | I32GtU | I32LeS | I32LeU | I32GeS | I32GeU
+        | I32Add | I32Sub | I32Mul | I32DivS | I32DivU | I32RemS | I32RemU | I32And | I32Or
+        | I32Xor | I32Shl | I32ShrS | I32ShrU | I32Rotl | I32Rotr => (2, 32),
+        I64Eq | I64Ne | I64LtS | I64LtU | I64GtS | I64GtU | I64LeS | I64LeU | I64GeS | I64GeU
+        | I64Add | I64Sub | I64Mul | I64DivS | I64DivU | I64RemS | I64RemU | I64And | I64Or
+        | I64Xor | I64Shl | I64ShrS | I64ShrU | I64Rotl | I64Rotr => (2, 64),
+        _ => return None,
+    })
+}
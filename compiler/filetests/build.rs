@@ -134,6 +134,9 @@ impl Tests {
         module = match module {
             "if" => "_if",
             "loop" => "_loop",
+            "const" => "_const",
+            "ref" => "_ref",
+            "struct" => "_struct",
             _ => module,
         };
 
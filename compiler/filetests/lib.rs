@@ -29,15 +29,37 @@ impl Test {
         tracing::info!("Compiling {module}::{name}");
 
         let compiler = zinkc::Compiler::default();
-        // TODO: after #248
-        if name == "fibonacci" {
+        let result = compiler.compile(wasm);
+
+        // Tests prefixed with `invalid` are expected to fail, either
+        // with or without the dispatcher.
+        if name.starts_with("invalid") {
+            let dispatcher = zinkc::Compiler::new(zinkc::Config::default().dispatcher(true));
+            anyhow::ensure!(
+                result.is_err() || dispatcher.compile(wasm).is_err(),
+                "{module}::{name} should fail to compile"
+            );
             return Ok(());
         }
-        compiler.compile(&wasm)?;
+
+        result?;
         Ok(())
     }
 }
 
+/// Get the big-endian word of the bit pattern, for the inputs and the
+/// results of the tests of the integers and the floats.
+pub fn word(bits: u64) -> [u8; 32] {
+    let mut word = [0; 32];
+    word[24..].copy_from_slice(&bits.to_be_bytes());
+    word
+}
+
+/// Get the big-endian words of the bit patterns, see [`word`].
+pub fn words<const N: usize>(bits: [u64; N]) -> [[u8; 32]; N] {
+    bits.map(word)
+}
+
 /// Generate tests for different modules.
 #[allow(clippy::crate_in_macro_def)]
 #[macro_export]
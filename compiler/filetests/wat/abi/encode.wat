@@ -0,0 +1,29 @@
+(module
+  (type (;0;) (func (param i32)))
+  (type (;1;) (func (result i32)))
+  (type (;2;) (func))
+  (type (;3;) (func (param i32 i32) (result i32)))
+  (import "zinkc" "free_memory" (func (;0;) (type 1)))
+  (import "asm" "push_u256" (func (;1;) (type 0)))
+  (import "asm" "push_u32" (func (;2;) (type 0)))
+  (import "evm" "mstore" (func (;3;) (type 2)))
+  (import "zinkc" "keccak256_memory" (func (;4;) (type 3)))
+  (func (;5;) (type 3) (param i32 i32) (result i32)
+    (local i32)
+    call 0
+    local.set 2
+    local.get 0
+    call 1
+    local.get 2
+    call 2
+    call 3
+    local.get 1
+    call 2
+    local.get 2
+    i32.const 32
+    i32.add
+    call 2
+    call 3
+    i32.const 64
+    local.get 2
+    call 4))
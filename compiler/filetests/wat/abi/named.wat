@@ -0,0 +1,15 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (type (;2;) (func))
+  (import "zinkc" "emit_abi" (func (;0;) (type 0)))
+  (func (;1;) (type 1) (param i32) (result i32)
+    i32.const 42)
+  (func (;2;) (type 2)
+    i32.const 1048576
+    i32.const 68
+    call 0)
+  (memory (;0;) 17)
+  (export "balance_of" (func 1))
+  (export "balance_of_selector" (func 2))
+  (data (;0;) (i32.const 1048576) "0x0a62616c616e63655f6f660101056f776e65720a010762616c616e636508020000"))
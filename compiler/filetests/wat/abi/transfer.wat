@@ -0,0 +1,26 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (type (;2;) (func (param i32 i32) (result i32)))
+  (type (;3;) (func))
+  (import "asm" "revert_error" (func (;0;) (type 0)))
+  (import "zinkc" "address_is_zero" (func (;1;) (type 1)))
+  (import "zinkc" "emit_abi" (func (;2;) (type 0)))
+  (func (;3;) (type 2) (param i32 i32) (result i32)
+    local.get 0
+    call 1
+    if
+      i32.const 1048576
+      i32.const 12
+      call 0
+    end
+    local.get 1)
+  (func (;4;) (type 3)
+    i32.const 1048588
+    i32.const 72
+    call 2)
+  (memory (;0;) 17)
+  (export "transfer" (func 3))
+  (export "transfer_selector" (func 4))
+  (data (;0;) (i32.const 1048576) "zero address")
+  (data (;1;) (i32.const 1048588) "0x087472616e73666572010202746f0a06616d6f756e740801066f757470757408020000"))
@@ -0,0 +1,6 @@
+(module
+  (type (;0;) (func (param i32) (result i32)))
+  (import "evm" "balance" (func (;0;) (type 0)))
+  (func (;1;) (type 0) (param i32) (result i32)
+    local.get 0
+    call 0))
@@ -0,0 +1,18 @@
+(module
+  (type (;0;) (func (param i32 i32 i32 i32 i32 i32 i32) (result i32)))
+  (type (;1;) (func (result i32)))
+  (type (;2;) (func (param i32) (result i32)))
+  (import "evm" "call" (func (;0;) (type 0)))
+  (import "evm" "callvalue" (func (;1;) (type 1)))
+  (func (;2;) (type 2) (param i32) (result i32)
+    ;; forward half of the value to the account, keep the rest
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    call 1
+    i32.const 1
+    i32.shr_u
+    local.get 0
+    i32.const 100000
+    call 0))
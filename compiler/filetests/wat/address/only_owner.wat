@@ -0,0 +1,21 @@
+(module
+  (type (;0;) (func (result i32)))
+  (type (;1;) (func (param i32 i32) (result i32)))
+  (type (;2;) (func (param i32 i32)))
+  (import "evm" "caller" (func (;0;) (type 0)))
+  (import "zinkc" "address_eq" (func (;1;) (type 1)))
+  (import "asm" "revert_error" (func (;2;) (type 2)))
+  (import "env" "memory" (memory (;0;) 17))
+  (func (;3;) (type 0) (result i32)
+    ;; owner 0x1234
+    call 0
+    i32.const 4660
+    call 1
+    i32.eqz
+    if
+      i32.const 1048576
+      i32.const 9
+      call 2
+    end
+    i32.const 1)
+  (data (;0;) (i32.const 1048576) "not owner"))
@@ -0,0 +1,17 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (type (;2;) (func (param i32 i32) (result i32)))
+  (import "asm" "revert_error" (func (;0;) (type 0)))
+  (import "zinkc" "address_is_zero" (func (;1;) (type 1)))
+  (import "env" "memory" (memory (;0;) 17))
+  (func (;2;) (type 2) (param i32 i32) (result i32)
+    local.get 0
+    call 1
+    if
+      i32.const 1048576
+      i32.const 12
+      call 0
+    end
+    local.get 1)
+  (data (;0;) (i32.const 1048576) "zero address"))
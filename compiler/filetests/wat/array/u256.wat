@@ -0,0 +1,55 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (result i32)))
+  (type (;2;) (func))
+  (import "zinkc" "emit_abi" (func (;0;) (type 0)))
+  (import "evm" "mstore" (func (;1;) (type 0)))
+  (func (;2;) (type 1) (result i32)
+    i32.const 3
+    i32.const 128
+    call 1
+    i32.const 1
+    i32.const 160
+    call 1
+    i32.const 2
+    i32.const 192
+    call 1
+    i32.const 3
+    i32.const 224
+    call 1
+    i32.const 128)
+  (func (;3;) (type 1) (result i32)
+    i32.const 128)
+  (func (;4;) (type 1) (result i32)
+    i32.const 2
+    i32.const 0
+    call 1
+    i32.const 7
+    i32.const 32
+    call 1
+    i32.const 8
+    i32.const 64
+    call 1
+    i32.const 0)
+  (func (;5;) (type 2)
+    i32.const 1048576
+    i32.const 42
+    call 0)
+  (func (;6;) (type 2)
+    i32.const 1048618
+    i32.const 44
+    call 0)
+  (func (;7;) (type 2)
+    i32.const 1048662
+    i32.const 40
+    call 0)
+  (memory (;0;) 17)
+  (export "list" (func 2))
+  (export "empty" (func 3))
+  (export "low" (func 4))
+  (export "list_selector" (func 5))
+  (export "empty_selector" (func 6))
+  (export "low_selector" (func 7))
+  (data (;0;) (i32.const 1048576) "0x046c697374010001066f75747075740f08020000")
+  (data (;1;) (i32.const 1048618) "0x05656d707479010001066f75747075740f08020000")
+  (data (;2;) (i32.const 1048662) "0x036c6f77010001066f75747075740f08020000"))
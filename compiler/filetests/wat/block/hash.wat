@@ -0,0 +1,13 @@
+(module
+  (import "evm" "number" (func $number (result i64)))
+  (import "evm" "blockhash" (func $blockhash (param i64) (result i64)))
+  (func (export "main") (param i64) (result i64)
+    ;; the number of the block if the depth is 0, otherwise the hash
+    ;; of the block at the depth before it.
+    (local.get 0)
+    (i64.eqz)
+    (if (result i64)
+      (then
+        (call $number))
+      (else
+        (call $blockhash (i64.sub (call $number) (local.get 0)))))))
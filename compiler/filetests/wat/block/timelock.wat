@@ -0,0 +1,19 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (result i64)))
+  (type (;2;) (func (result i64)))
+  (import "asm" "revert_error" (func (;0;) (type 0)))
+  (import "evm" "timestamp" (func (;1;) (type 1)))
+  (import "evm" "number" (func (;2;) (type 1)))
+  (import "env" "memory" (memory (;0;) 17))
+  (func (;3;) (type 2) (result i64)
+    call 1
+    i64.const 1000
+    i64.lt_u
+    if
+      i32.const 1048576
+      i32.const 6
+      call 0
+    end
+    call 2)
+  (data (;0;) (i32.const 1048576) "locked"))
@@ -0,0 +1,16 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32 i32) (result i32)))
+  (import "evm" "sstore" (func (;0;) (type 0)))
+  (func (;1;) (type 1) (param i32 i32) (result i32)
+    local.get 0
+    i32.const 0
+    i32.ne
+    if (result i32)
+      i32.const 1
+    else
+      local.get 1
+      i32.const 0
+      call 0
+      local.get 1
+    end))
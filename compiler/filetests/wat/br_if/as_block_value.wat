@@ -0,0 +1,9 @@
+;;! target = "evm"
+(module
+  (func (export "as-block-value") (param i32) (result i32)
+    (block (result i32)
+      (drop (br_if 0 (i32.const 1) (local.get 0)))
+      (i32.const 2)
+    )
+  )
+)
@@ -0,0 +1,11 @@
+;;! target = "evm"
+(module
+  (func (export "as-block-value-dropped") (param i32) (result i32)
+    (block (result i32)
+      ;; dropped by the branch if it is taken
+      (i32.const 9)
+      (br_if 0 (i32.const 1) (local.get 0))
+      (i32.add)
+    )
+  )
+)
@@ -0,0 +1,44 @@
+;;! target = "evm"
+(module
+  (func (export "dispatch") (param i32) (result i32)
+    block
+      block
+        block
+          block
+            block
+              block
+                block
+                  block
+                    block
+                      block
+                        local.get 0
+                        br_table 0 1 2 3 4 5 6 7 8 9
+                      end
+                      i32.const 100
+                      return
+                    end
+                    i32.const 101
+                    return
+                  end
+                  i32.const 102
+                  return
+                end
+                i32.const 103
+                return
+              end
+              i32.const 104
+              return
+            end
+            i32.const 105
+            return
+          end
+          i32.const 106
+          return
+        end
+        i32.const 107
+        return
+      end
+      i32.const 108
+      return
+    end
+    i32.const 109))
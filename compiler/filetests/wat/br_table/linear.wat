@@ -0,0 +1,78 @@
+;;! target = "evm"
+(module
+  (func (export "linear") (param i32) (result i32)
+    block
+      block
+        block
+          block
+            block
+              block
+                block
+                  block
+                    block
+                      block
+                        local.get 0
+                        i32.eqz
+                        br_if 0
+                        local.get 0
+                        i32.const 1
+                        i32.eq
+                        br_if 1
+                        local.get 0
+                        i32.const 2
+                        i32.eq
+                        br_if 2
+                        local.get 0
+                        i32.const 3
+                        i32.eq
+                        br_if 3
+                        local.get 0
+                        i32.const 4
+                        i32.eq
+                        br_if 4
+                        local.get 0
+                        i32.const 5
+                        i32.eq
+                        br_if 5
+                        local.get 0
+                        i32.const 6
+                        i32.eq
+                        br_if 6
+                        local.get 0
+                        i32.const 7
+                        i32.eq
+                        br_if 7
+                        local.get 0
+                        i32.const 8
+                        i32.eq
+                        br_if 8
+                        br 9
+                      end
+                      i32.const 100
+                      return
+                    end
+                    i32.const 101
+                    return
+                  end
+                  i32.const 102
+                  return
+                end
+                i32.const 103
+                return
+              end
+              i32.const 104
+              return
+            end
+            i32.const 105
+            return
+          end
+          i32.const 106
+          return
+        end
+        i32.const 107
+        return
+      end
+      i32.const 108
+      return
+    end
+    i32.const 109))
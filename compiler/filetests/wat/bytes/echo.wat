@@ -0,0 +1,3 @@
+(module
+  (func (param i32) (result i32)
+    local.get 0))
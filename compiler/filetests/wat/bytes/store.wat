@@ -0,0 +1,18 @@
+(module
+  (type (;0;) (func (param i32)))
+  (type (;1;) (func))
+  (import "asm" "push_bytes4" (func (;0;) (type 0)))
+  (import "asm" "push_bytes20" (func (;1;) (type 0)))
+  (import "asm" "push_u32" (func (;2;) (type 0)))
+  (import "evm" "sstore" (func (;3;) (type 1)))
+  (func (;4;) (param i32 i32)
+    local.get 0
+    call 0
+    i32.const 0
+    call 2
+    call 3
+    local.get 1
+    call 1
+    i32.const 1
+    call 2
+    call 3))
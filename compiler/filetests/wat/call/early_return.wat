@@ -0,0 +1,20 @@
+(module
+  (type (;0;) (func (param i32) (result i32)))
+  (func (;0;) (type 0) (param i32) (result i32)
+    local.get 0
+    call 1
+    i32.const 100
+    i32.add)
+  (func (;1;) (type 0) (param i32) (result i32)
+    ;; left under the result of the early return
+    i32.const 1
+    local.get 0
+    i32.eqz
+    if  ;; label = @1
+      i32.const 7
+      return
+    end
+    drop
+    local.get 0
+    i32.const 1
+    i32.add))
@@ -0,0 +1,18 @@
+(module
+  (type $unary (func (param i32) (result i32)))
+  (type $binary (func (param i32 i32) (result i32)))
+  (table 3 funcref)
+  (elem (i32.const 0) $double $add $square)
+
+  ;; call the unary function at the index of the table with the value
+  (func (export "main") (param i32) (param i32) (result i32)
+    (call_indirect (type $unary) (local.get 1) (local.get 0)))
+
+  (func $double (type $unary)
+    (i32.mul (local.get 0) (i32.const 2)))
+
+  (func $add (type $binary)
+    (i32.add (local.get 0) (local.get 1)))
+
+  (func $square (type $unary)
+    (i32.mul (local.get 0) (local.get 0))))
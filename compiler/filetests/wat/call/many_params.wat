@@ -0,0 +1,69 @@
+(module
+  (func (export "main") (param i32) (result i32)
+    ;; 20 parameters, 4 of them deeper than the reachable depth
+    (call $sum
+      (local.get 0)
+      (i32.const 1)
+      (i32.const 2)
+      (i32.const 3)
+      (i32.const 4)
+      (i32.const 5)
+      (i32.const 6)
+      (i32.const 7)
+      (i32.const 8)
+      (i32.const 9)
+      (i32.const 10)
+      (i32.const 11)
+      (i32.const 12)
+      (i32.const 13)
+      (i32.const 14)
+      (i32.const 15)
+      (i32.const 16)
+      (i32.const 17)
+      (i32.const 18)
+      (i32.const 50))
+  )
+
+  ;; sum of the parameters 1..19 minus the parameter 0
+  (func $sum (param i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32 i32) (result i32)
+    (local.get 1)
+    (local.get 2)
+    (i32.add)
+    (local.get 3)
+    (i32.add)
+    (local.get 4)
+    (i32.add)
+    (local.get 5)
+    (i32.add)
+    (local.get 6)
+    (i32.add)
+    (local.get 7)
+    (i32.add)
+    (local.get 8)
+    (i32.add)
+    (local.get 9)
+    (i32.add)
+    (local.get 10)
+    (i32.add)
+    (local.get 11)
+    (i32.add)
+    (local.get 12)
+    (i32.add)
+    (local.get 13)
+    (i32.add)
+    (local.get 14)
+    (i32.add)
+    (local.get 15)
+    (i32.add)
+    (local.get 16)
+    (i32.add)
+    (local.get 17)
+    (i32.add)
+    (local.get 18)
+    (i32.add)
+    (local.get 19)
+    (i32.add)
+    (local.get 0)
+    (i32.sub)
+  )
+)
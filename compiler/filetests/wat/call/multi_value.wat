@@ -0,0 +1,30 @@
+(module
+  (func (export "main") (param i32 i32) (result i32)
+    (local i32)
+    (call $divmod (local.get 0) (local.get 1))
+
+    ;; quotient * 10 + remainder
+    (local.set 2)
+    (i32.const 10)
+    (i32.mul)
+    (local.get 2)
+    (i32.add)
+  )
+
+  (func $divmod (param i32 i32) (result i32 i32)
+    ;; left under the results of the early return
+    (i32.const 1)
+    (local.get 1)
+    (i32.eqz)
+    (if
+      (then
+        (i32.const 4)
+        (i32.const 2)
+        (return)
+      )
+    )
+    (drop)
+    (i32.div_u (local.get 0) (local.get 1))
+    (i32.rem_u (local.get 0) (local.get 1))
+  )
+)
@@ -0,0 +1,17 @@
+(module
+  (type (;0;) (func (param i32 i32) (result i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (type (;2;) (func (result i32)))
+  (import "zinkc" "calldata" (func (;0;) (type 0)))
+  (import "evm" "keccak256" (func (;1;) (type 0)))
+  (import "evm" "calldataload" (func (;2;) (type 1)))
+  (func (;3;) (type 2) (result i32)
+    (local i32)
+    i32.const 32
+    call 2
+    local.set 0
+    local.get 0
+    i32.const 64
+    local.get 0
+    call 0
+    call 1))
@@ -0,0 +1,4 @@
+(module
+  (func (param i64) (result i64)
+    local.get 0
+    i64.clz))
@@ -0,0 +1,11 @@
+(module
+  (func (param i32) (result i32)
+    ;; clz(x) * 3 with a helper shared by all calls
+    local.get 0
+    i32.clz
+    local.get 0
+    i32.clz
+    i32.add
+    local.get 0
+    i32.clz
+    i32.add))
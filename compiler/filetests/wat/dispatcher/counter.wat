@@ -0,0 +1,49 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (type (;2;) (func (result i32)))
+  (type (;3;) (func (param i32)))
+  (type (;4;) (func))
+  (import "evm" "sstore" (func (;0;) (type 0)))
+  (import "evm" "sload" (func (;1;) (type 1)))
+  (import "zinkc" "emit_abi" (func (;2;) (type 0)))
+  (func (;3;) (type 2) (result i32)
+    i32.const 0
+    call 1)
+  (func (;4;) (type 3) (param i32)
+    local.get 0
+    i32.const 0
+    call 0)
+  (func (;5;) (type 2) (result i32)
+    call 3)
+  (func (;6;) (type 3) (param i32)
+    local.get 0
+    call 4)
+  (func (;7;) (type 2) (result i32)
+    call 3
+    i32.const 1
+    i32.add
+    call 4
+    call 3)
+  (func (;8;) (type 4)
+    i32.const 1048576
+    i32.const 38
+    call 2)
+  (func (;9;) (type 4)
+    i32.const 1048614
+    i32.const 36
+    call 2)
+  (func (;10;) (type 4)
+    i32.const 1048650
+    i32.const 50
+    call 2)
+  (memory (;0;) 17)
+  (export "get" (func 5))
+  (export "set" (func 6))
+  (export "increment" (func 7))
+  (export "get_selector" (func 8))
+  (export "set_selector" (func 9))
+  (export "increment_selector" (func 10))
+  (data (;0;) (i32.const 1048576) "0x03676574010001066f757470757406020000")
+  (data (;1;) (i32.const 1048614) "0x0373657401010576616c75650600020000")
+  (data (;2;) (i32.const 1048650) "0x09696e6372656d656e74010001066f757470757406020000"))
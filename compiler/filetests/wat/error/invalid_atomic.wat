@@ -0,0 +1,5 @@
+(module
+  (memory (;0;) 1)
+  (func (param i32) (result i32)
+    local.get 0
+    i32.atomic.load))
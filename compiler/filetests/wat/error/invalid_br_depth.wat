@@ -0,0 +1,5 @@
+(module
+  (func (result i32)
+    (block
+      br 2)
+    i32.const 0))
@@ -0,0 +1,6 @@
+(module
+  (global (;0;) i32 (i32.const 42))
+  (func (result i32)
+    i32.const 1
+    global.set 0
+    global.get 0))
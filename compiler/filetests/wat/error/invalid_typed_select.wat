@@ -0,0 +1,6 @@
+(module
+  (func (param i64 i64) (result i32)
+    local.get 0
+    local.get 1
+    i32.const 1
+    select (result i32)))
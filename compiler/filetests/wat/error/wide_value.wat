@@ -0,0 +1,12 @@
+;;! target = "evm"
+(module
+  (import "evm" "caller" (func $caller (result i32)))
+  (import "evm" "calldatasize" (func $calldatasize (result i32)))
+  (func (export "wide_value") (result i32)
+    ;; the size of calldata fits in i32
+    (i32.add (call $calldatasize) (i32.const 1))
+
+    ;; the address of the caller does not
+    (i32.add (call $caller))
+  )
+)
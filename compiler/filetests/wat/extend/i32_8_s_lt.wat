@@ -0,0 +1,6 @@
+(module
+  (func (param i32) (result i32)
+    local.get 0
+    i32.extend8_s
+    i32.const 0
+    i32.lt_s))
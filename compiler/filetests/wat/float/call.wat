@@ -0,0 +1,17 @@
+(module
+  (func (param i32) (result i32)
+    local.get 0
+    f32.reinterpret_i32
+    call 1
+    i32.reinterpret_f32)
+  (func (param f32) (result f32)
+    (local f32 i32)
+    local.get 0
+    local.set 1
+    i32.const 1
+    local.set 2
+    local.get 2
+    i32.const 2
+    i32.add
+    local.set 2
+    local.get 1))
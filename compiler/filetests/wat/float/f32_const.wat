@@ -0,0 +1,4 @@
+(module
+  (func (result i32)
+    f32.const -7.5
+    i32.trunc_f32_s))
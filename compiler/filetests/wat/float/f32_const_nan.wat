@@ -0,0 +1,4 @@
+(module
+  (func (result i32)
+    f32.const nan:0x200001
+    i32.reinterpret_f32))
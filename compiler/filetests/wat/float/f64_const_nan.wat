@@ -0,0 +1,4 @@
+(module
+  (func (result i64)
+    f64.const -nan:0x4000000000001
+    i64.reinterpret_f64))
@@ -0,0 +1,5 @@
+(module
+  (func (param i32) (result i32)
+    local.get 0
+    f32.reinterpret_i32
+    i32.trunc_sat_f32_s))
@@ -0,0 +1,5 @@
+(module
+  (func (param i64) (result i64)
+    local.get 0
+    f64.reinterpret_i64
+    i64.trunc_f64_u))
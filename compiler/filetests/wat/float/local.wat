@@ -0,0 +1,13 @@
+(module
+  (func (param f64) (result i64)
+    (local f64 i64)
+    local.get 0
+    local.set 1
+    i64.const 7
+    local.set 2
+    local.get 2
+    i64.const 35
+    i64.mul
+    local.set 2
+    local.get 1
+    i64.reinterpret_f64))
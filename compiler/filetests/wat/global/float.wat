@@ -0,0 +1,14 @@
+(module
+  (global (;0;) f64 (f64.const -0x1.4p+0))
+  (global (;1;) f32 (f32.const nan:0x200001))
+  (global (;2;) f64 (f64.const -0x1.4p+0))
+  (func (param i32) (result i64)
+    local.get 0
+    if (result i64) ;; label = @1
+      global.get 1
+      i32.reinterpret_f32
+      i64.extend_i32_u
+    else
+      global.get 2
+      i64.reinterpret_f64
+    end))
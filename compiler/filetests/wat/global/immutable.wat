@@ -0,0 +1,5 @@
+(module
+  (global (;0;) i32 (i32.mul (i32.const 6) (i32.const 7)))
+  (global (;1;) i64 (i64.sub (i64.const 50) (i64.const 8)))
+  (func (result i32)
+    global.get 0))
@@ -0,0 +1,9 @@
+(module
+  (import "env" "__memory_base" (global (;0;) i32))
+  (global (;1;) (mut i32) (i32.add (global.get 0) (i32.const 42)))
+  (func (param i32) (result i32)
+    global.get 1
+    local.get 0
+    i32.add
+    global.set 1
+    global.get 1))
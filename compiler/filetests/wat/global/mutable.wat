@@ -0,0 +1,8 @@
+(module
+  (global (;0;) (mut i32) (i32.add (i32.const 40) (i32.const 2)))
+  (func (param i32) (result i32)
+    global.get 0
+    local.get 0
+    i32.add
+    global.set 0
+    global.get 0))
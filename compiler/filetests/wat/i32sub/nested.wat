@@ -0,0 +1,9 @@
+(module
+    (func (param i32) (param i32) (param i32) (result i32)
+    (local.get 0)
+    (local.get 1)
+    (local.get 2)
+    (i32.add)
+    (i32.sub)
+    )
+)
@@ -0,0 +1,9 @@
+(module
+    (func (param i64) (param i64) (param i64) (result i64)
+    (local.get 0)
+    (local.get 1)
+    (local.get 2)
+    (i64.add)
+    (i64.sub)
+    )
+)
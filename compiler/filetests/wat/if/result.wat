@@ -0,0 +1,11 @@
+(module
+  (func (export "result") (param i32 i32) (result i32)
+    (call $select (local.get 0) (local.get 1))
+  )
+
+  (func $select (param i32 i32) (result i32)
+    (if (result i32) (local.get 0) (then (i32.const 1)) (else (i32.const 2)))
+    (local.get 1)
+    (i32.add)
+  )
+)
@@ -0,0 +1,17 @@
+(module
+  (type (;0;) (func))
+  (type (;1;) (func (param i32 i32 i32 i32 i32 i32 i32 i32)))
+  (import "evm" "log3" (func (;0;) (type 1)))
+  (import "env" "memory" (memory (;0;) 17))
+  (func (;1;) (type 0)
+    i32.const 1048576
+    i32.const 32
+    i32.const 1048608
+    i32.const 32
+    i32.const 1048640
+    i32.const 32
+    i32.const 1048672
+    i32.const 32
+    call 0)
+  (export "transfer" (func 1))
+  (data (;0;) (i32.const 1048576) "\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\00\2a\dd\f2\52\ad\1b\e2\c8\9b\69\c2\b0\68\fc\37\8d\aa\95\2b\a7\f1\63\c4\a1\16\28\f5\5a\4d\f5\23\b3\ef\00\00\00\00\00\00\00\00\00\00\00\00\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\00\00\00\00\00\00\00\00\00\00\00\00\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"))
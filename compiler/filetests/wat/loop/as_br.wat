@@ -0,0 +1,22 @@
+(module
+  (func (export "as_br") (param i32) (result i32)
+    (local i32)
+    block (result i32) ;; label = @1
+      loop ;; label = @2
+        local.get 1
+        i32.const 1
+        i32.add
+        local.tee 1
+        local.get 0
+        i32.lt_u
+        br_if 0 (;@2;)
+
+        ;; dropped by the branch
+        i32.const 7
+        local.get 1
+        br 1 (;@1;)
+      end
+      unreachable
+    end
+  )
+)
@@ -0,0 +1,25 @@
+(module
+  (func (export "br_if_value") (param i32) (result i32)
+    (local i32)
+    block (result i32) ;; label = @1
+      loop ;; label = @2
+        local.get 1
+        i32.const 1
+        i32.add
+        local.set 1
+
+        ;; dropped by the branch if it is taken
+        i32.const 7
+        local.get 1
+        local.get 1
+        local.get 0
+        i32.ge_u
+        br_if 1 (;@1;)
+        drop
+        drop
+        br 0 (;@2;)
+      end
+      unreachable
+    end
+  )
+)
@@ -0,0 +1,19 @@
+(module
+  (func (export "counter") (param i32) (result i32)
+    (local i32)
+    loop (result i32) ;; label = @1
+      local.get 1
+      i32.const 1
+      i32.add
+      local.tee 1
+      local.get 0
+      i32.lt_u
+      br_if 0 (;@1;)
+
+      ;; the counter is stored back under the result
+      i32.const 7
+    end
+    local.get 1
+    i32.add
+  )
+)
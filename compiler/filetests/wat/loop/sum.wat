@@ -0,0 +1,25 @@
+(module
+  (func (export "sum") (param i32) (result i32)
+    (local i32 i32)
+    block ;; label = @1
+      loop ;; label = @2
+        local.get 1
+        local.get 0
+        i32.ge_u
+        br_if 1 (;@1;)
+
+        local.get 2
+        local.get 1
+        i32.add
+        local.set 2
+
+        local.get 1
+        i32.const 1
+        i32.add
+        local.set 1
+        br 0 (;@2;)
+      end
+    end
+    local.get 2
+  )
+)
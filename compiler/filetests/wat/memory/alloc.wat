@@ -0,0 +1,11 @@
+(module
+  (memory 0)
+  (func (param i32) (result i32)
+    ;; allocate 100 bytes, which is rounded up to 1 page
+    i32.const 96
+    i32.const 1
+    i32.store
+    local.get 0
+    memory.grow
+    drop
+    memory.size))
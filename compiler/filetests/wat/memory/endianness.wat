@@ -0,0 +1,22 @@
+(module
+  (memory 1)
+  (func (param i32) (result i32)
+    ;; stored in little-endian as [04, 03, 02, 01]
+    i32.const 1024
+    i32.const 0x01020304
+    i32.store
+
+    ;; the word is loaded back in the same order
+    i32.const 1024
+    i32.load
+    i32.const 0x01020304
+    i32.ne
+    if
+      unreachable
+    end
+
+    ;; the byte at 1024 + param
+    i32.const 1024
+    local.get 0
+    i32.add
+    i32.load8_u))
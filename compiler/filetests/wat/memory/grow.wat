@@ -0,0 +1,6 @@
+(module
+  (memory 1)
+  (func (param i32) (result i32)
+    ;; the previous size is returned, never -1
+    local.get 0
+    memory.grow))
@@ -0,0 +1,6 @@
+(module
+  (import "env" "memory" (memory (;0;) 17))
+  (memory (;1;) 1)
+  (func (result i32)
+    i32.const 0
+    i32.load 1))
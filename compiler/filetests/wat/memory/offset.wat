@@ -0,0 +1,22 @@
+(module
+  (memory 1)
+  (func (param i32) (result i32)
+    ;; *(ptr + 4) = param, where ptr = 1024
+    i32.const 1024
+    i32.const 0x11223344
+    i32.store
+    i32.const 1024
+    local.get 0
+    i32.store offset=4 align=1
+
+    ;; the neighbour at ptr is kept
+    i32.const 1024
+    i32.load
+    i32.const 0x11223344
+    i32.ne
+    if
+      unreachable
+    end
+
+    i32.const 1024
+    i32.load offset=4 align=2))
@@ -0,0 +1,7 @@
+(module
+  (memory 1)
+  (func (param i32) (result i32)
+    local.get 0
+    memory.grow
+    drop
+    memory.size))
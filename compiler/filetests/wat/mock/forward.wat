@@ -0,0 +1,23 @@
+(module
+  (type (;0;) (func (param i32 i32 i32 i32 i32 i32 i32) (result i32)))
+  (type (;1;) (func (param i32 i32)))
+  (import "evm" "call" (func (;0;) (type 0)))
+  (import "evm" "mstore" (func (;1;) (type 1)))
+  (func (;2;) (param i32) (result i32)
+    ;; write the selector of `setValue(uint256)` at 0x1c
+    i32.const 0x55241077
+    i32.const 0
+    call 1
+    ;; write the value at 0x20
+    local.get 0
+    i32.const 32
+    call 1
+    ;; forward the value to the dependency at 0x1234
+    i32.const 0
+    i32.const 0
+    i32.const 36
+    i32.const 28
+    i32.const 0
+    i32.const 4660
+    i32.const 1000000
+    call 0))
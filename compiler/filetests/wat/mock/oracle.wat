@@ -0,0 +1,33 @@
+(module
+  (type (;0;) (func (param i32 i32 i32 i32 i32 i32 i32) (result i32)))
+  (type (;1;) (func (param i32 i32)))
+  (type (;2;) (func (param i32) (result i32)))
+  (type (;3;) (func (result i32)))
+  (import "evm" "call" (func (;0;) (type 0)))
+  (import "evm" "mstore" (func (;1;) (type 1)))
+  (import "evm" "mload" (func (;2;) (type 2)))
+  (func (;3;) (type 3) (result i32)
+    ;; write the selector of `latestAnswer()` at 0x1c
+    i32.const 0x50d25bcd
+    i32.const 0
+    call 1
+    ;; call the oracle at 0x1234, the answer is written at 0x20
+    i32.const 32
+    i32.const 32
+    i32.const 4
+    i32.const 28
+    i32.const 0
+    i32.const 4660
+    i32.const 1000000
+    call 0
+    drop
+    ;; 1 if the answer is greater than 100, 2 otherwise
+    i32.const 32
+    call 2
+    i32.const 100
+    i32.gt_u
+    if (result i32)
+      i32.const 1
+    else
+      i32.const 2
+    end))
@@ -0,0 +1,15 @@
+;; `a + b` of u32 built in debug mode, the addition is checked and
+;; panics on overflow, the panic is lowered to `unreachable`.
+(module
+  (func (param i32 i32) (result i32)
+    (local i32)
+    local.get 0
+    local.get 1
+    i32.add
+    local.tee 2
+    local.get 0
+    i32.lt_u
+    if
+      unreachable
+    end
+    local.get 2))
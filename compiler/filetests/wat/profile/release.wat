@@ -0,0 +1,6 @@
+;; `a + b` of u32 built in release mode, overflow checks are omitted.
+(module
+  (func (param i32 i32) (result i32)
+    local.get 0
+    local.get 1
+    i32.add))
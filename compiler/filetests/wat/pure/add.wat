@@ -0,0 +1,17 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (type (;2;) (func))
+  (import "zinkc" "emit_abi" (func (;0;) (type 0)))
+  (func (;1;) (type 1) (param i32) (result i32)
+    local.get 0
+    i32.const 1
+    i32.add)
+  (func (;2;) (type 2)
+    i32.const 1048576
+    i32.const 44
+    call 0)
+  (memory (;0;) 17)
+  (export "add" (func 1))
+  (export "add_selector" (func 2))
+  (data (;0;) (i32.const 1048576) "0x03616464010101780201066f757470757402000000"))
@@ -0,0 +1,20 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (type (;2;) (func (result i32)))
+  (type (;3;) (func))
+  (import "zinkc" "emit_abi" (func (;0;) (type 0)))
+  (import "evm" "sload" (func (;1;) (type 1)))
+  (func (;2;) (type 2) (result i32)
+    call 3)
+  (func (;3;) (type 2) (result i32)
+    i32.const 0
+    call 1)
+  (func (;4;) (type 3)
+    i32.const 1048576
+    i32.const 38
+    call 0)
+  (memory (;0;) 17)
+  (export "get" (func 2))
+  (export "get_selector" (func 4))
+  (data (;0;) (i32.const 1048576) "0x03676574010001066f757470757402000000"))
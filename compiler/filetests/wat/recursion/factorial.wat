@@ -0,0 +1,21 @@
+(module
+ (type (;0;) (func (param i32) (result i32)))
+ (func (;0;) (type 0) (param i32) (result i32)
+             local.get 0
+             call 1)
+ (func (;1;) (type 0) (param i32) (result i32)
+             local.get 0
+             i32.const 2
+             i32.lt_u
+             if (result i32)
+             i32.const 1
+             else
+             local.get 0
+             local.get 0
+             i32.const 1
+             i32.sub
+             call 1
+             i32.mul
+             end)
+ (export "factorial" (func 0))
+ (export "recursion" (func 1)))
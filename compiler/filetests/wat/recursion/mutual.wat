@@ -0,0 +1,34 @@
+(module
+ (type (;0;) (func (param i32) (result i32)))
+ (func (;0;) (type 0) (param i32) (result i32)
+             local.get 0
+             call 1)
+ (func (;1;) (type 0) (param i32) (result i32)
+             local.get 0
+             i32.eqz
+             if (result i32)
+             i32.const 0
+             else
+             local.get 0
+             i32.const 1
+             i32.sub
+             call 2
+             local.get 0
+             i32.add
+             end)
+ (func (;2;) (type 0) (param i32) (result i32)
+             local.get 0
+             i32.eqz
+             if (result i32)
+             i32.const 0
+             else
+             local.get 0
+             i32.const 1
+             i32.sub
+             call 1
+             local.get 0
+             i32.const 2
+             i32.mul
+             i32.add
+             end)
+ (export "mutual" (func 0)))
@@ -0,0 +1,17 @@
+(module
+  (elem declare func $callee)
+
+  ;; reference the function if the flag is set, otherwise null
+  (func (param i32) (result i32)
+    (local funcref)
+    local.get 0
+    if (result funcref)
+      ref.func $callee
+    else
+      ref.null func
+    end
+    local.set 1
+    local.get 1
+    ref.is_null)
+
+  (func $callee))
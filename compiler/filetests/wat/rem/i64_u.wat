@@ -0,0 +1,7 @@
+(module
+  (func (param i64 i64 i64) (result i64)
+    (local.get 0)
+    (local.get 1)
+    (i64.add)
+    (local.get 2)
+    (i64.rem_u)))
@@ -0,0 +1,10 @@
+(module
+  (type (;0;) (func (param i32)))
+  (import "asm" "revert_panic" (func (;0;) (type 0)))
+  (func (;1;) (type 0) (param i32)
+    local.get 0
+    i32.eqz
+    if
+      i32.const 1
+      call 0
+    end))
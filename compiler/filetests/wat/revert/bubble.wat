@@ -0,0 +1,19 @@
+(module
+  (type (;0;) (func (param i32 i32 i32 i32 i32 i32 i32) (result i32)))
+  (type (;1;) (func))
+  (import "evm" "call" (func (;0;) (type 0)))
+  (import "zinkc" "bubble_revert" (func (;1;) (type 1)))
+  (func (;2;) (type 1)
+    ;; call 0x1234 without calldata
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 4660
+    i32.const 1000000
+    call 0
+    i32.eqz
+    if
+      call 1
+    end))
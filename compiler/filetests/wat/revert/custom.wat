@@ -0,0 +1,17 @@
+(module
+  (type (;0;) (func (param i64 i32)))
+  (type (;1;) (func (param i32 i32)))
+  (type (;2;) (func))
+  (import "evm" "mstore" (func (;0;) (type 0)))
+  (import "evm" "revert" (func (;1;) (type 1)))
+  (func (;2;) (type 2)
+    ;; selector of InsufficientBalance(uint256)
+    i64.const 2456179537
+    i32.const 0
+    call 0
+    i64.const 42
+    i32.const 32
+    call 0
+    i32.const 36
+    i32.const 28
+    call 1))
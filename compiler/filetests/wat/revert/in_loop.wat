@@ -0,0 +1,27 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (import "asm" "revert_error" (func (;0;) (type 0)))
+  (import "env" "memory" (memory (;0;) 17))
+  (func (;1;) (type 1) (param i32) (result i32)
+    (local i32)
+    ;; count to 10, revert if the counter reaches the limit
+    loop  ;; label = @1
+      local.get 1
+      local.get 0
+      i32.eq
+      if  ;; label = @2
+        i32.const 1048576
+        i32.const 13
+        call 0
+      end
+      local.get 1
+      i32.const 1
+      i32.add
+      local.tee 1
+      i32.const 10
+      i32.lt_u
+      br_if 0 (;@1;)
+    end
+    local.get 1)
+  (data (;0;) (i32.const 1048576) "limit reached"))
@@ -0,0 +1,14 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32)))
+  (import "asm" "revert_error" (func (;0;) (type 0)))
+  (import "env" "memory" (memory (;0;) 17))
+  (func (;1;) (type 1) (param i32)
+    local.get 0
+    i32.eqz
+    if
+      i32.const 1048576
+      i32.const 18
+      call 0
+    end)
+  (data (;0;) (i32.const 1048576) "require works fine"))
@@ -0,0 +1,24 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (result i32)))
+  (type (;2;) (func))
+  (import "zinkc" "emit_abi" (func (;0;) (type 0)))
+  (func (;1;) (type 1) (result i32)
+    i32.const 1)
+  (func (;2;) (type 1) (result i32)
+    i32.const 2)
+  (func (;3;) (type 2)
+    i32.const 1048576
+    i32.const 46
+    call 0)
+  (func (;4;) (type 2)
+    i32.const 1048622
+    i32.const 38
+    call 0)
+  (memory (;0;) 17)
+  (export "one" (func 1))
+  (export "two" (func 2))
+  (export "one_selector" (func 3))
+  (export "two_selector" (func 4))
+  (data (;0;) (i32.const 1048576) "0x036f6e65010001066f75747075740202015fdf05d700")
+  (data (;1;) (i32.const 1048622) "0x0374776f010001066f757470757402020000"))
@@ -0,0 +1,11 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32)))
+  (import "evm" "sstore" (func (;0;) (type 0)))
+  (func (;1;) (type 1) (param i32)
+    local.get 0
+    call 2)
+  (func (;2;) (type 1) (param i32)
+    local.get 0
+    i32.const 0
+    call 0))
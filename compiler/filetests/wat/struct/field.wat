@@ -0,0 +1,43 @@
+(module
+  (type (;0;) (func (param i32 i32)))
+  (type (;1;) (func (param i32) (result i32)))
+  (type (;2;) (func (param i32) (result i64)))
+  (type (;3;) (func (param i32 i64) (result i64)))
+  (type (;4;) (func))
+  (import "zinkc" "emit_abi" (func (;0;) (type 0)))
+  (import "evm" "mload" (func (;1;) (type 1)))
+  (func (;2;) (type 2) (param i32) (result i64)
+    local.get 0
+    i64.load offset=8)
+  (func (;3;) (type 3) (param i32 i64) (result i64)
+    local.get 0
+    i64.load offset=16
+    local.get 1
+    i64.add)
+  (func (;4;) (type 1) (param i32) (result i32)
+    local.get 0
+    i32.const 32
+    i32.add
+    call 1)
+  (func (;5;) (type 4)
+    i32.const 1048576
+    i32.const 62
+    call 0)
+  (func (;6;) (type 4)
+    i32.const 1048638
+    i32.const 76
+    call 0)
+  (func (;7;) (type 4)
+    i32.const 1048714
+    i32.const 60
+    call 0)
+  (memory (;0;) 17)
+  (export "second" (func 2))
+  (export "nested" (func 3))
+  (export "words" (func 4))
+  (export "second_selector" (func 5))
+  (export "nested_selector" (func 6))
+  (export "words_selector" (func 7))
+  (data (;0;) (i32.const 1048576) "0x067365636f6e64010104706169721002060701066f757470757407020000")
+  (data (;1;) (i32.const 1048638) "0x066e657374656401020576616c75651002071002060701780701066f757470757407020000")
+  (data (;2;) (i32.const 1048714) "0x05776f726473010104706169721002080801066f757470757408020000"))
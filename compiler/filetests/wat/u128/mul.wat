@@ -0,0 +1,7 @@
+(module
+  (type (;0;) (func (param i32 i32) (result i32)))
+  (import "zinkc" "u128_mul" (func (;0;) (type 0)))
+  (func (;1;) (type 0) (param i32 i32) (result i32)
+    local.get 0
+    local.get 1
+    call 0))
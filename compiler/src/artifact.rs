@@ -2,6 +2,7 @@
 
 use crate::Config;
 use zabi::Abi;
+use zingen::layout::SlotInfo;
 
 /// Zink compiler artifact
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -11,6 +12,16 @@ pub struct Artifact {
     pub abi: Vec<Abi>,
     /// Compiler configuration.
     pub config: Config,
+    /// Bytecode of the constructor function running in the init code,
+    /// which initializes the mutable globals, empty if the contract has
+    /// neither constructor function nor mutable globals.
+    pub constructor_bytecode: Vec<u8>,
+    /// Storage slots of the `#[zink::storage]` declarations.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub layout: Vec<SlotInfo>,
     /// Runtime bytecode of the contract.
     pub runtime_bytecode: Vec<u8>,
+    /// Program counters of the runtime bytecode with the offsets of
+    /// their originating WASM instructions, ordered by program counter.
+    pub source_map: Vec<(usize, usize)>,
 }
@@ -21,6 +21,21 @@ pub struct Compile {
     /// If enable dispatcher.
     #[clap(short, long)]
     dispatcher: bool,
+    /// If skip the `SSTORE`s writing the present values.
+    #[clap(long)]
+    sstore_if_changed: bool,
+    /// If keep the induction variables of the loops in memory.
+    #[clap(long)]
+    no_loop_induction: bool,
+    /// If fail on the values wider than their types.
+    #[clap(long)]
+    strict_width: bool,
+    /// If verify the emitted bytecode.
+    #[clap(long)]
+    verify: bool,
+    /// Load the constants pushed at least the times from memory.
+    #[clap(long, value_name = "THRESHOLD")]
+    const_pool: Option<usize>,
 }
 
 impl Compile {
@@ -32,7 +47,15 @@ impl Compile {
             env::current_dir()?.join(self.input.with_extension(""))
         };
 
-        let compiler = Compiler::new(Config::default().dispatcher(self.dispatcher));
+        let compiler = Compiler::new(
+            Config::default()
+                .dispatcher(self.dispatcher)
+                .sstore_if_changed(self.sstore_if_changed)
+                .no_loop_induction(self.no_loop_induction)
+                .strict_width(self.strict_width)
+                .verify(self.verify)
+                .const_pool(self.const_pool),
+        );
         let artifact = compiler.compile(&fs::read(&self.input)?)?;
 
         output.parent().map(fs::create_dir_all);
@@ -1,12 +1,16 @@
 //! Zink compiler
 
 use crate::{parser::Parser, Artifact, Config, Error, Result};
-use zabi::Abi;
+use zabi::{Abi, StateMutability};
 use zingen::{
+    layout::SlotInfo,
     wasm::{self, Env},
-    Buffer, Dispatcher, Function, JumpTable, BUFFER_LIMIT,
+    Buffer, Constructor, Dispatcher, Function, JumpTable, Pool, BUFFER_LIMIT,
 };
 
+/// Name of the exported constructor function.
+const CONSTRUCTOR: &str = "constructor";
+
 /// Zink Compiler
 #[derive(Default)]
 pub struct Compiler {
@@ -14,8 +18,12 @@ pub struct Compiler {
     pub(crate) abi: Vec<Abi>,
     /// EVM bytecode buffer.
     pub(crate) buffer: Buffer,
+    /// Storage layout of the compiled contract.
+    pub(crate) layout: Vec<SlotInfo>,
     /// Compiler configuration.
     pub config: Config,
+    /// Constants loaded from memory in the runtime bytecode.
+    pool: Vec<Vec<u8>>,
     /// Global jump table.
     table: JumpTable,
 }
@@ -31,36 +39,119 @@ impl Compiler {
 
     /// Compile wasm module to evm bytecode.
     ///
+    /// The input is expected to be built in release mode, the overflow
+    /// checks of debug builds are compiled as they are, branches to
+    /// `INVALID`, which bloat the bytecode.
+    ///
+    /// With the constant pool enabled, the module is compiled without
+    /// the pool first for collecting the constants of the pool.
+    ///
     /// Returns runtime bytecode.
     pub fn compile(mut self, wasm: &[u8]) -> Result<Artifact> {
+        if let Some(threshold) = self.config.const_pool {
+            let artifact = Compiler::new(self.config.clone().const_pool(None)).compile(wasm)?;
+            self.pool = Pool::collect(&artifact.runtime_bytecode, threshold);
+        }
+
         let mut parser = Parser::try_from(wasm)?;
-        let env = parser.env.clone();
+        let env = self.env(&parser);
 
+        self.layout = env.load_layout(&parser.drain_layouts())?;
         self.compile_dispatcher(&mut parser)?;
+        self.check_pure(&env, &parser.funcs)?;
+
+        let constructor = env.query_func(CONSTRUCTOR).ok();
+        if let Some(index) = constructor {
+            parser.funcs.remove(&index);
+        }
+
         for func in parser.funcs.into_funcs() {
             self.compile_func(env.with_index(func.index()), func)?;
         }
 
         self.table.code_offset(self.buffer.len() as u16);
         self.table.relocate(&mut self.buffer)?;
-        self.artifact()
+
+        let constructor_bytecode = match constructor {
+            Some(index) => self.compile_constructor(wasm, index)?,
+            None => Constructor::globals_body(&env.globals)?.to_vec(),
+        };
+        if self.config.verify {
+            zingen::verify(&self.buffer)?;
+            // the jumps out of the constructor function are patched in
+            // the init code.
+            let mut init_code = Constructor::default();
+            init_code.body(&constructor_bytecode);
+            zingen::verify(&init_code.init_code(0)?)?;
+        }
+
+        self.artifact(constructor_bytecode)
+    }
+
+    /// Get the environment of the parsed module with the options of
+    /// the config.
+    fn env(&self, parser: &Parser) -> Env {
+        let mut env = parser.env.clone();
+        env.sstore_if_changed = self.config.sstore_if_changed;
+        env.no_loop_induction = self.config.no_loop_induction;
+        env.strict_width = self.config.strict_width;
+        env.pool = Pool::new(self.pool.clone(), env.memory);
+        env.memory += env.pool.len() as u32;
+        env
+    }
+
+    /// Compile the constructor function to the bytecode running in the
+    /// init code.
+    ///
+    /// The constructor function is compiled as the main function of a
+    /// program of its own, the other functions of the module are
+    /// compiled as internal functions following it.
+    fn compile_constructor(&self, wasm: &[u8], index: u32) -> Result<Vec<u8>> {
+        let mut parser = Parser::try_from(wasm)?;
+        let mut env = self.env(&parser);
+        // the pool is only stored at the entry of the runtime bytecode.
+        env.pool = Default::default();
+        parser.drain_selectors();
+        parser.drain_layouts();
+
+        let mut func = parser
+            .funcs
+            .remove(&index)
+            .ok_or(zingen::Error::FuncNotFound(index))?;
+        // all of the functions following the constructor function are
+        // internal ones.
+        let mut compiler = Compiler::new(Config::default().dispatcher(true));
+        let codegen = Function::constructor(env.with_index(index), func.sig()?)?;
+        compiler.emit_func(index, codegen, &mut func)?;
+        for func in parser.funcs.into_funcs() {
+            compiler.compile_func(env.with_index(func.index()), func)?;
+        }
+
+        compiler.table.code_offset(compiler.buffer.len() as u16);
+        compiler.table.relocate(&mut compiler.buffer)?;
+        Ok(compiler.buffer.to_vec())
     }
 
     /// Generate artifact
     ///
     /// yields runtime bytecode and construct bytecode
-    fn artifact(self) -> Result<Artifact> {
+    fn artifact(self, constructor_bytecode: Vec<u8>) -> Result<Artifact> {
         let Compiler {
             abi,
             buffer,
             config,
+            layout,
+            table,
             ..
         } = self;
 
         Ok(Artifact {
             abi,
             config,
+            constructor_bytecode,
+            layout,
             runtime_bytecode: buffer.to_vec(),
+            source_map: table.source_map(),
         })
     }
 
@@ -69,7 +160,7 @@ impl Compiler {
     /// Drain selectors anyway, compile dispatcher if it is enabled.
     fn compile_dispatcher(&mut self, parser: &mut Parser) -> Result<()> {
         let selectors = parser.drain_selectors();
-        let env = parser.env.clone();
+        let env = self.env(parser);
 
         if !self.config.dispatcher {
             self.abi.append(&mut env.load_abis(&selectors)?);
@@ -87,6 +178,17 @@ impl Compiler {
         Ok(())
     }
 
+    /// Check the pure functions don't access the state or the environment.
+    fn check_pure(&self, env: &Env, funcs: &wasm::Functions<'_>) -> Result<()> {
+        for abi in &self.abi {
+            if abi.state_mutability == StateMutability::Pure {
+                funcs.check_pure(env, abi.symbol())?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Compile WASM function.
     fn compile_func(&mut self, env: Env, mut func: wasm::Function<'_>) -> Result<()> {
         let func_index = func.index();
@@ -96,15 +198,25 @@ impl Compiler {
         tracing::debug!("compile function {func_index} {:?}, abi: {abi:#?}", sig);
         let is_main = !self.config.dispatcher && env.is_main(func_index);
 
-        let mut codegen = Function::new(env, sig, abi, is_main)?;
+        let codegen = Function::new(env, sig, abi, is_main)?;
+        self.emit_func(func_index, codegen, &mut func)
+    }
+
+    /// Emit the locals and the operators of the WASM function with
+    /// the code generator.
+    fn emit_func(
+        &mut self,
+        func_index: u32,
+        mut codegen: Function,
+        func: &mut wasm::Function<'_>,
+    ) -> Result<()> {
         let mut locals_reader = func.body.get_locals_reader()?;
         let mut ops_reader = func.body.get_operators_reader()?;
 
         codegen.emit_locals(&mut locals_reader, &mut func.validator)?;
         codegen.emit_operators(&mut ops_reader, &mut func.validator)?;
 
-        self.emit_buffer(func_index, codegen)?;
-        Ok(())
+        self.emit_buffer(func_index, codegen)
     }
 
     /// Emit buffer to the inner buffer.
@@ -124,6 +236,6 @@ impl Compiler {
     /// Get abi from env and function index
     fn abi(&self, env: &Env, index: u32) -> Option<Abi> {
         let name = env.exports.get(&index)?;
-        self.abi.iter().find(|a| name == &a.name).cloned()
+        self.abi.iter().find(|a| name == a.symbol()).cloned()
     }
 }
@@ -4,12 +4,27 @@
 use ccli::clap;
 
 /// Zink compiler configuration.
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "cli", derive(clap::Parser))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Config {
     /// If enable dispatcher.
     pub dispatcher: bool,
+    /// If skip the `SSTORE`s writing the present values, which costs
+    /// an extra `SLOAD` for every `SSTORE`.
+    pub sstore_if_changed: bool,
+    /// If keep the induction variables of the loops in memory instead
+    /// of the stack.
+    pub no_loop_induction: bool,
+    /// If fail on the integer instructions consuming the values wider
+    /// than their types, which are left by the host functions.
+    pub strict_width: bool,
+    /// If verify the jump targets, the push data and the opcodes of
+    /// the emitted bytecode.
+    pub verify: bool,
+    /// Load the wide constants pushed at least the times of the
+    /// threshold from memory if it shrinks the bytecode.
+    pub const_pool: Option<usize>,
 }
 
 impl Config {
@@ -18,4 +33,34 @@ impl Config {
         self.dispatcher = dispatcher;
         self
     }
+
+    /// With sstore_if_changed value.
+    pub fn sstore_if_changed(mut self, sstore_if_changed: bool) -> Self {
+        self.sstore_if_changed = sstore_if_changed;
+        self
+    }
+
+    /// With no_loop_induction value.
+    pub fn no_loop_induction(mut self, no_loop_induction: bool) -> Self {
+        self.no_loop_induction = no_loop_induction;
+        self
+    }
+
+    /// With strict_width value.
+    pub fn strict_width(mut self, strict_width: bool) -> Self {
+        self.strict_width = strict_width;
+        self
+    }
+
+    /// With verify value.
+    pub fn verify(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// With const_pool value.
+    pub fn const_pool(mut self, const_pool: Option<usize>) -> Self {
+        self.const_pool = const_pool;
+        self
+    }
 }
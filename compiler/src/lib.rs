@@ -7,7 +7,7 @@ pub use crate::{
     config::Config,
     result::{Error, Result},
 };
-pub use zingen::{Constructor, InitStorage};
+pub use zingen::{layout::SlotInfo, Constructor, Immutables, InitStorage};
 
 mod artifact;
 pub mod cli;
@@ -3,10 +3,14 @@
 use crate::{Error, Result};
 use std::iter::IntoIterator;
 use wasmparser::{
-    Data, DataKind, Export, ExternalKind, Import, Operator, Payload, SectionLimited, TypeRef,
-    ValidPayload, Validator,
+    Data, DataKind, Element, ElementItems, ElementKind, Export, ExternalKind, Global, Import,
+    MemoryType, Operator, Payload, RecGroup, SectionLimited, TypeRef, ValidPayload, Validator,
+    WasmFeatures,
+};
+use zingen::wasm::{
+    Data as DataSet, Env, Exports, Functions, Global as GlobalSlot, Globals, HostFunc, Imports,
+    Types,
 };
-use zingen::wasm::{Data as DataSet, Env, Exports, Functions, HostFunc, Imports};
 
 /// WASM module parser
 #[derive(Default)]
@@ -20,20 +24,58 @@ pub struct Parser<'p> {
 impl<'p> Parser<'p> {
     /// Parse WASM module.
     pub fn parse(&mut self, wasm: &'p [u8]) -> Result<()> {
-        let mut validator = Validator::new();
+        // Multiple memories and atomic operators pass the validation
+        // for being rejected with clear errors in the compilation.
+        let mut validator = Validator::new_with_features(WasmFeatures {
+            extended_const: true,
+            multi_memory: true,
+            threads: true,
+            ..Default::default()
+        });
 
         // Compile functions.
+        let mut memories = 0;
+        let mut imported_globals = 0;
+        let mut elements = Vec::new();
         for payload in wasmparser::Parser::new(0).parse_all(wasm) {
             let payload = payload?;
             let valid_payload = validator.payload(&payload)?;
 
             match &payload {
-                Payload::ImportSection(reader) => self.env.imports = Self::imports(reader)?,
+                Payload::ImportSection(reader) => {
+                    for memory in Self::imported_memories(reader)? {
+                        memories += 1;
+                        self.env.pages = memory.initial;
+                    }
+                    let globals = Self::imported_globals(reader)?;
+                    imported_globals = globals.len() as u32;
+                    for (index, global) in (0..).zip(globals) {
+                        if let Some(global) = global {
+                            self.env.globals.insert(index, global);
+                        }
+                    }
+                    self.env.imports = Self::imports(reader)?
+                }
+                Payload::MemorySection(reader) => {
+                    for memory in reader.clone() {
+                        memories += 1;
+                        self.env.pages = memory?.initial;
+                    }
+                }
                 Payload::DataSection(reader) => self.env.data = Self::data(reader)?,
+                Payload::ElementSection(reader) => elements = Self::elements(reader)?,
+                Payload::TypeSection(reader) => self.env.types = Self::types(reader)?,
                 Payload::ExportSection(reader) => self.env.exports = Self::exports(reader)?,
+                Payload::GlobalSection(reader) => {
+                    Self::globals(reader, imported_globals, &mut self.env.globals)?
+                }
                 _ => {}
             }
 
+            if memories > 1 {
+                return Err(Error::MultipleMemories(memories));
+            }
+
             if let ValidPayload::Func(to_validator, body) = valid_payload {
                 self.funcs
                     .add(to_validator.into_validator(Default::default()), body);
@@ -56,14 +98,22 @@ impl<'p> Parser<'p> {
                 .funcs
                 .insert(fun.index(), (params as u32, sig.results().len() as u32));
 
-            slots += locals;
+            // params of external functions are reserved as well for
+            // caching their calldata.
+            slots += locals + params as u32;
+        }
 
-            // process prarams for internal functions only
-            if !self.env.is_external(fun.index()) && !self.env.is_main(fun.index()) {
-                slots += params as u32;
-            }
+        self.env.memory = slots;
+
+        // the imported functions are host functions which could not
+        // be called indirectly.
+        for (index, func) in elements {
+            let func = self.funcs.get(&func).ok_or(Error::InvalidElement)?;
+            self.env.table.insert(index, (func.index(), func.sig()?));
         }
 
+        self.env.calls = self.funcs.calls(&self.env.table)?;
+
         Ok(())
     }
 
@@ -72,6 +122,11 @@ impl<'p> Parser<'p> {
         self.funcs.drain_selectors(&self.env.exports)
     }
 
+    /// Drain storage layout functions from parsed functions
+    pub fn drain_layouts(&mut self) -> Functions<'p> {
+        self.funcs.drain_layouts(&self.env.exports)
+    }
+
     /// Parse data section.
     fn data(reader: &SectionLimited<Data>) -> Result<DataSet> {
         let mut dataset = DataSet::default();
@@ -95,6 +150,57 @@ impl<'p> Parser<'p> {
         Ok(dataset)
     }
 
+    /// Parse type section.
+    fn types(reader: &SectionLimited<RecGroup>) -> Result<Types> {
+        let mut types = Types::default();
+        for (index, ty) in reader.clone().into_iter_err_on_gc_types().enumerate() {
+            types.insert(index as u32, ty?);
+        }
+
+        Ok(types)
+    }
+
+    /// Parse element section, returns the functions by their indexes
+    /// in the table.
+    fn elements(reader: &SectionLimited<Element>) -> Result<Vec<(u32, u32)>> {
+        let mut elements = Vec::new();
+        for element in reader.clone() {
+            let Element { kind, items, .. } = element?;
+            let ElementKind::Active {
+                table_index: None | Some(0),
+                offset_expr,
+            } = kind
+            else {
+                continue;
+            };
+
+            let Operator::I32Const { value: offset } =
+                offset_expr.get_binary_reader().read_operator()?
+            else {
+                return Err(Error::InvalidElement);
+            };
+
+            let funcs = match items {
+                ElementItems::Functions(funcs) => funcs
+                    .into_iter()
+                    .collect::<std::result::Result<Vec<_>, _>>()?,
+                ElementItems::Expressions(_, exprs) => exprs
+                    .into_iter()
+                    .map(|expr| match expr?.get_binary_reader().read_operator()? {
+                        Operator::RefFunc { function_index } => Ok(function_index),
+                        _ => Err(Error::InvalidElement),
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+            };
+
+            for (index, func) in funcs.into_iter().enumerate() {
+                elements.push((offset as u32 + index as u32, func));
+            }
+        }
+
+        Ok(elements)
+    }
+
     /// Parse export section
     pub fn exports(reader: &SectionLimited<Export>) -> Result<Exports> {
         let mut exports = Exports::default();
@@ -111,6 +217,26 @@ impl<'p> Parser<'p> {
         Ok(exports)
     }
 
+    /// Parse global section into the globals.
+    ///
+    /// The indexes of the defined globals follow the imported ones.
+    pub fn globals(
+        reader: &SectionLimited<Global>,
+        imported: u32,
+        globals: &mut Globals,
+    ) -> Result<()> {
+        for (index, global) in reader.clone().into_iter().enumerate() {
+            let Global { ty, init_expr } = global?;
+            let index = imported + index as u32;
+            let global = GlobalSlot::new(index, ty.content_type, ty.mutable, &init_expr, globals)?;
+
+            tracing::trace!("global {index}: {global:?}");
+            globals.insert(index, global);
+        }
+
+        Ok(())
+    }
+
     /// Parse import section.
     pub fn imports(reader: &SectionLimited<Import>) -> Result<Imports> {
         // TODO: use real index from WASM. (#122)
@@ -132,6 +258,33 @@ impl<'p> Parser<'p> {
 
         Ok(imports)
     }
+
+    /// Get the imported globals, the unsupported ones are `None`.
+    fn imported_globals(reader: &SectionLimited<Import>) -> Result<Vec<Option<GlobalSlot>>> {
+        let mut globals = Vec::new();
+        for import in reader.clone() {
+            let Import { module, name, ty } = import?;
+            if let TypeRef::Global(ty) = ty {
+                let global = GlobalSlot::imported(module, name, ty);
+                tracing::trace!("imported global {module}::{name}: {global:?}");
+                globals.push(global);
+            }
+        }
+
+        Ok(globals)
+    }
+
+    /// Get the imported memories.
+    fn imported_memories(reader: &SectionLimited<Import>) -> Result<Vec<MemoryType>> {
+        let mut memories = Vec::new();
+        for import in reader.clone() {
+            if let TypeRef::Memory(memory) = import?.ty {
+                memories.push(memory);
+            }
+        }
+
+        Ok(memories)
+    }
 }
 
 impl<'p> TryFrom<&'p [u8]> for Parser<'p> {
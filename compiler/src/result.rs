@@ -18,6 +18,12 @@ pub enum Error {
     /// Failed to parse WASM data with data reader.
     #[error("Invalid data offset")]
     InvalidDataOffset,
+    /// Failed to initialize the table with the element segment.
+    #[error("Invalid element segment, only the defined functions at constant offsets of the first table are supported")]
+    InvalidElement,
+    /// Failed to map multiple memories to the single memory of EVM.
+    #[error("Multiple memories are not supported, found {0} while the EVM has a single memory")]
+    MultipleMemories(u32),
 }
 
 /// Zinkc result
@@ -22,6 +22,9 @@ pub struct Abi {
     pub inputs: Vec<Arg>,
     /// An array of arguments, similar to inputs.
     pub outputs: Vec<Arg>,
+    /// State mutability of the function.
+    #[cfg_attr(feature = "serde", serde(rename = "stateMutability"))]
+    pub state_mutability: StateMutability,
 }
 
 #[cfg(feature = "syn")]
@@ -58,6 +61,7 @@ impl From<&syn::Signature> for Abi {
             name,
             inputs,
             outputs,
+            state_mutability: Default::default(),
         }
     }
 }
@@ -106,3 +110,56 @@ impl fmt::Display for Type {
         write!(f, "{ty}")
     }
 }
+
+/// State mutability of the function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum StateMutability {
+    /// Reads neither the state nor the environment.
+    Pure,
+    /// Reads but does not modify the state.
+    View,
+    /// Does not accept ether.
+    #[default]
+    NonPayable,
+    /// Accepts ether.
+    Payable,
+}
+
+impl From<&str> for StateMutability {
+    fn from(s: &str) -> Self {
+        match s {
+            "pure" => StateMutability::Pure,
+            "view" => StateMutability::View,
+            "payable" => StateMutability::Payable,
+            _ => StateMutability::NonPayable,
+        }
+    }
+}
+
+impl FromStr for StateMutability {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl AsRef<str> for StateMutability {
+    fn as_ref(&self) -> &str {
+        match self {
+            StateMutability::Pure => "pure",
+            StateMutability::View => "view",
+            StateMutability::NonPayable => "nonpayable",
+            StateMutability::Payable => "payable",
+        }
+    }
+}
+
+impl fmt::Display for StateMutability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mutability: &str = self.as_ref();
+        write!(f, "{mutability}")
+    }
+}
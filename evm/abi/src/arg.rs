@@ -3,7 +3,7 @@
 use core::{convert::Infallible, fmt, str::FromStr};
 
 #[cfg(not(feature = "std"))]
-use crate::std::{String, ToString};
+use crate::std::{Box, String, ToString, Vec};
 
 /// Arg of solidity ABI.
 #[derive(Clone, Debug, Default)]
@@ -16,6 +16,14 @@ pub struct Arg {
     pub ty: Param,
 }
 
+/// The canonical names of `bytes1` to `bytes32`.
+const FIXED_BYTES: [&str; 32] = [
+    "bytes1", "bytes2", "bytes3", "bytes4", "bytes5", "bytes6", "bytes7", "bytes8", "bytes9",
+    "bytes10", "bytes11", "bytes12", "bytes13", "bytes14", "bytes15", "bytes16", "bytes17",
+    "bytes18", "bytes19", "bytes20", "bytes21", "bytes22", "bytes23", "bytes24", "bytes25",
+    "bytes26", "bytes27", "bytes28", "bytes29", "bytes30", "bytes31", "bytes32",
+];
+
 /// The canonical type of the parameter.
 #[derive(Clone, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -48,10 +56,16 @@ pub enum Param {
     /// A byte array.
     #[default]
     Bytes,
+    /// A fixed-size byte array `bytesN`, `N` in `1..=32`.
+    FixedBytes(u8),
     /// A string type.
     String,
     /// An unknown type.
     Unknown(String),
+    /// A dynamic array `T[]`.
+    Array(Box<Param>),
+    /// A tuple `(T1,T2,..)`, which is the ABI of the structs.
+    Tuple(Vec<Param>),
 }
 
 impl From<&str> for Param {
@@ -67,10 +81,94 @@ impl From<&str> for Param {
             "U256" | "u256" | "uint256" => Param::UInt256,
             "bool" => Param::Bool,
             "address" | "Address" => Param::Address,
-            "Bytes" | "Vec<u8>" => Param::Bytes,
+            "Bytes" | "Vec<u8>" | "&[u8]" => Param::Bytes,
             "String" | "String32" => Param::String,
-            _ => Param::Unknown(s.to_string()),
+            _ if s.ends_with("[]") => Param::Array(Box::new(Param::from(&s[..s.len() - 2]))),
+            _ if s.starts_with("Vec<") && s.ends_with('>') => {
+                Param::Array(Box::new(Param::from(&s[4..s.len() - 1])))
+            }
+            _ if s.len() > 2 && s.starts_with('(') && s.ends_with(')') => {
+                Param::Tuple(fields(&s[1..s.len() - 1]).map(Param::from).collect())
+            }
+            _ => s
+                .strip_prefix("bytes")
+                .or_else(|| s.strip_prefix("Bytes"))
+                .and_then(|n| n.parse().ok())
+                .filter(|n| (1..=32).contains(n))
+                .map(Param::FixedBytes)
+                .unwrap_or_else(|| Param::Unknown(s.to_string())),
+        }
+    }
+}
+
+/// Split the fields of a tuple at the top-level commas.
+fn fields(s: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0;
+    s.split(move |c| {
+        match c {
+            '(' | '<' | '[' => depth += 1,
+            ')' | '>' | ']' => depth -= 1,
+            _ => {}
         }
+
+        c == ',' && depth == 0
+    })
+    .filter(|field| !field.is_empty())
+}
+
+impl Param {
+    /// Get the size of the static encoding of the parameter in bytes,
+    /// the fields of the tuples are encoded in place, returns `None`
+    /// for the dynamic types.
+    pub fn size(&self) -> Option<usize> {
+        match self {
+            Param::Bytes | Param::String | Param::Array(_) | Param::Unknown(_) => None,
+            Param::Tuple(fields) => fields.iter().map(Param::size).sum(),
+            _ => Some(32),
+        }
+    }
+
+    /// Get the offset of the field at the path in the static encoding
+    /// of the tuple, the path is the indexes of the fields from the
+    /// outermost tuple, e.g. `[1, 0]` for the first field of the second
+    /// field.
+    pub fn offset_of(&self, path: &[usize]) -> Option<usize> {
+        let Some((index, path)) = path.split_first() else {
+            return Some(0);
+        };
+
+        let Param::Tuple(fields) = self else {
+            return None;
+        };
+
+        let base = fields
+            .get(..*index)?
+            .iter()
+            .map(Param::size)
+            .sum::<Option<usize>>()?;
+        Some(base + fields.get(*index)?.offset_of(path)?)
+    }
+
+    /// If the parameter is a solidity fixed point number, `fixed`,
+    /// `ufixed`, `fixedMxN` or `ufixedMxN`.
+    pub fn is_fixed(&self) -> bool {
+        let Param::Unknown(ty) = self else {
+            return false;
+        };
+
+        let Some(mxn) = ty
+            .strip_prefix("ufixed")
+            .or_else(|| ty.strip_prefix("fixed"))
+        else {
+            return false;
+        };
+
+        mxn.is_empty()
+            || mxn.split_once('x').is_some_and(|(m, n)| {
+                [m, n]
+                    .iter()
+                    .all(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()))
+            })
     }
 }
 
@@ -82,6 +180,8 @@ impl FromStr for Param {
     }
 }
 
+/// Get the name of the type, the arrays and the tuples are named
+/// `array` and `tuple`, see [`fmt::Display`] for their full types.
 impl AsRef<str> for Param {
     fn as_ref(&self) -> &str {
         match self {
@@ -97,16 +197,33 @@ impl AsRef<str> for Param {
             Param::Address => "address",
             Param::Bool => "boolean",
             Param::Bytes => "bytes",
+            Param::FixedBytes(n) => FIXED_BYTES[(*n as usize).clamp(1, 32) - 1],
             Param::String => "string",
             Param::Unknown(ty) => ty.as_ref(),
+            Param::Array(_) => "array",
+            Param::Tuple(_) => "tuple",
         }
     }
 }
 
 impl fmt::Display for Param {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let p: &str = self.as_ref();
-        write!(f, "{p}")
+        match self {
+            Param::Array(ty) => write!(f, "{ty}[]"),
+            Param::Tuple(fields) => {
+                write!(f, "(")?;
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{field}")?;
+                }
+
+                write!(f, ")")
+            }
+            _ => write!(f, "{}", self.as_ref()),
+        }
     }
 }
 
@@ -115,7 +232,7 @@ impl From<&Box<syn::Type>> for Param {
     fn from(ty: &Box<syn::Type>) -> Self {
         use quote::ToTokens;
 
-        let ident = ty.into_token_stream().to_string();
+        let ident = ty.into_token_stream().to_string().replace(' ', "");
         Self::from(ident.as_str())
     }
 }
@@ -12,12 +12,13 @@ pub(crate) mod std {
     extern crate alloc;
 
     pub use alloc::{
+        boxed::Box,
         string::{String, ToString},
         vec::Vec,
     };
 }
 
 pub use self::{
-    abi::Abi,
+    abi::{Abi, StateMutability},
     arg::{Arg, Param},
 };
@@ -37,6 +37,19 @@ macro_rules! opcodes {
             )*
         }
 
+        impl $version {
+            /// Get the opcode of the byte, returns `None` if the byte
+            /// is not an opcode of this version.
+            pub fn from_byte(byte: u8) -> Option<Self> {
+                match byte {
+                    $(
+                        $opcode => Some(Self::$name),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+
         impl From<u8> for $version {
             fn from(value: u8) -> Self {
                 match value {
@@ -8,7 +8,8 @@ use zink::{
     DoubleKeyMapping,
 };
 
-#[zink::storage(Address, Address, U256)]
+/// mapping(address => mapping(address => uint256))
+#[zink::storage(Address, Mapping<Address, U256>)]
 pub struct Allowance;
 
 #[zink::external]
@@ -110,3 +111,55 @@ fn test_approval() -> anyhow::Result<()> {
     assert_eq!(half_value.to_bytes32(), allowance);
     Ok(())
 }
+
+#[test]
+fn independent_allowances() -> anyhow::Result<()> {
+    use zint::{keccak256, Bytes32, Contract, EVM};
+
+    let owners = [[1; 20], [2; 20]];
+    let spenders = [[3; 20], [4; 20]];
+    let allowances = [[1, 2], [3, 4]];
+
+    let mut evm = EVM::default().commit(true);
+    let contract = Contract::search("approval")?.compile()?;
+    let address = evm.deploy(&contract.bytecode()?)?.address;
+
+    for (owner, values) in owners.into_iter().zip(allowances) {
+        evm = evm.caller(owner);
+        for (spender, value) in spenders.into_iter().zip(values) {
+            let info = evm
+                .calldata(&contract.encode(&[
+                    b"approve(address,uint256)".to_vec(),
+                    spender.to_bytes32().to_vec(),
+                    value.to_bytes32().to_vec(),
+                ])?)
+                .call(address)?;
+            assert_eq!(info.ret, true.to_bytes32());
+        }
+    }
+
+    for (owner, values) in owners.into_iter().zip(allowances) {
+        for (spender, value) in spenders.into_iter().zip(values) {
+            // keccak(spender . keccak(owner . slot)) as solidity
+            let inner =
+                keccak256(&[owner.to_bytes32(), Allowance::STORAGE_SLOT.to_bytes32()].concat());
+            let key = keccak256(&[spender.to_bytes32(), inner].concat());
+            assert_eq!(
+                key,
+                Allowance::storage_key(Address(owner), Address(spender))
+            );
+            assert_eq!(evm.storage(address, key)?, value.to_bytes32());
+
+            let info = evm
+                .calldata(&contract.encode(&[
+                    b"allowance(address,address)".to_vec(),
+                    owner.to_bytes32().to_vec(),
+                    spender.to_bytes32().to_vec(),
+                ])?)
+                .call(address)?;
+            assert_eq!(info.ret, value.to_bytes32());
+        }
+    }
+
+    Ok(())
+}
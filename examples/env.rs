@@ -0,0 +1,45 @@
+//! Contract environment example.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+extern crate zink;
+
+use zink::{env, primitives::Address};
+
+/// Get the address of the contract.
+#[zink::external]
+pub fn this() -> Address {
+    env::address()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
+
+#[test]
+fn test() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+    let mut contract = Contract::search("env")?.compile()?;
+
+    let info = contract.execute(["this()".as_bytes()])?;
+    assert_eq!(info.ret, zint::CONTRACT.to_bytes32());
+
+    let address = [9; 20];
+    let info = contract
+        .set_address(address)
+        .execute(["this()".as_bytes()])?;
+    assert_eq!(info.ret, address.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn deployed() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+    let mut contract = Contract::search("env")?.compile()?;
+
+    let mut evm = contract.deploy()?;
+    let info = evm
+        .calldata(&contract.encode(["this()".as_bytes()])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, contract.address.to_bytes32());
+    Ok(())
+}
@@ -1,4 +1,4 @@
-//! Constructor example.
+//! ERC20 example.
 #![cfg_attr(target_arch = "wasm32", no_std)]
 #![cfg_attr(target_arch = "wasm32", no_main)]
 
@@ -6,7 +6,7 @@ extern crate zink;
 
 use zink::{
     primitives::{Address, String32, U256},
-    DoubleKeyMapping, Mapping, Storage,
+    DoubleKeyMapping, Event, Mapping, Storage,
 };
 
 #[zink::storage(String32)]
@@ -16,14 +16,34 @@ pub struct Name;
 pub struct Symbol;
 
 #[zink::storage(U256)]
-pub struct TotalSupply;
+struct TotalSupply;
 
 #[zink::storage(Address, U256)]
-pub struct Balances;
+struct Balances;
 
 #[zink::storage(Address, Address, U256)]
 pub struct Allowance;
 
+/// Emitted when `value` tokens are moved from `from` to `to`.
+#[derive(Event)]
+struct Transfer {
+    #[indexed]
+    from: Address,
+    #[indexed]
+    to: Address,
+    value: U256,
+}
+
+/// Emitted when the allowance of `spender` for `owner` is set.
+#[derive(Event)]
+struct Approval {
+    #[indexed]
+    owner: Address,
+    #[indexed]
+    spender: Address,
+    value: U256,
+}
+
 /// Get value from the storage.
 #[zink::external]
 pub fn init(name: String32, symbol: String32) {
@@ -37,6 +57,20 @@ pub fn decimals() -> u32 {
     8
 }
 
+/// `totalSupply()`
+#[zink::external(selector = "0x18160ddd")]
+#[zink::view]
+pub fn total_supply() -> U256 {
+    TotalSupply::get()
+}
+
+/// `balanceOf(address)`
+#[zink::external(selector = "0x70a08231")]
+#[zink::view]
+pub fn balance_of(owner: Address) -> U256 {
+    Balances::get(owner)
+}
+
 #[zink::external]
 pub fn transfer(to: Address, value: U256) -> bool {
     let owner = Address::caller();
@@ -51,7 +85,8 @@ pub fn approve(spender: Address, value: U256) -> bool {
     true
 }
 
-#[zink::external]
+/// `transferFrom(address,address,uint256)`
+#[zink::external(selector = "0x23b872dd")]
 pub fn transfer_from(from: Address, to: Address, value: U256) -> bool {
     let spender = Address::caller();
     _spend_allowance(from, spender, value);
@@ -59,6 +94,13 @@ pub fn transfer_from(from: Address, to: Address, value: U256) -> bool {
     true
 }
 
+/// Mint tokens to the account, which is not access controlled in
+/// this example.
+#[zink::external]
+pub fn mint(account: Address, value: U256) {
+    _mint(account, value)
+}
+
 #[no_mangle]
 fn _transfer(from: Address, to: Address, value: U256) {
     if from.eq(Address::empty()) {
@@ -88,8 +130,10 @@ fn _update(from: Address, to: Address, value: U256) {
     if to.eq(Address::empty()) {
         TotalSupply::set(TotalSupply::get().sub(value));
     } else {
-        TotalSupply::set(TotalSupply::get().add(value));
+        Balances::set(to, Balances::get(to).add(value));
     }
+
+    Transfer { from, to, value }.emit();
 }
 
 #[no_mangle]
@@ -121,6 +165,12 @@ fn _approve(owner: Address, spender: Address, value: U256) {
     }
 
     Allowance::set(owner, spender, value);
+    Approval {
+        owner,
+        spender,
+        value,
+    }
+    .emit();
 }
 
 #[no_mangle]
@@ -187,7 +237,7 @@ fn deploy() -> anyhow::Result<()> {
 
     // 4. get total supply
     let info = evm
-        .calldata(&contract.encode(&[b"total_supply()".to_vec()])?)
+        .calldata(&contract.encode(&[b"totalSupply()".to_vec()])?)
         .call(address)?;
     assert_eq!(info.ret, 42u64.to_bytes32());
 
@@ -228,3 +278,174 @@ fn deploy() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn selectors() -> anyhow::Result<()> {
+    use zint::Contract;
+
+    let contract = Contract::search("erc20")?.compile()?;
+    let selector = |name: &str| {
+        contract
+            .artifact
+            .abi
+            .iter()
+            .find(|abi| abi.name == name)
+            .map(|abi| hex::encode(abi.selector()))
+    };
+
+    for (name, expected) in [
+        ("total_supply", "18160ddd"),
+        ("balance_of", "70a08231"),
+        ("transfer", "a9059cbb"),
+        ("approve", "095ea7b3"),
+        ("transfer_from", "23b872dd"),
+        ("allowance", "dd62ed3e"),
+    ] {
+        assert_eq!(selector(name).as_deref(), Some(expected), "{name}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn mint_then_transfer() -> anyhow::Result<()> {
+    use zint::{keccak256, Bytes32, Contract, EVM, U256};
+
+    let (alice, bob) = ([1; 20], [2; 20]);
+    let mut evm = EVM::default().commit(true).caller(alice);
+    let contract = Contract::search("erc20")?.compile()?;
+    let address = evm.deploy(&contract.bytecode()?)?.address;
+    let transfer = keccak256(b"Transfer(address,address,uint256)");
+
+    // 1. mint to alice
+    let info = evm
+        .calldata(&contract.encode(&[
+            b"mint(address,uint256)".to_vec(),
+            alice.to_bytes32().to_vec(),
+            100.to_bytes32().to_vec(),
+        ])?)
+        .call(address)?;
+    assert!(info.revert.is_none(), "{info:?}");
+    assert_eq!(
+        info.logs[0].topics(),
+        vec![transfer, [0; 20].to_bytes32(), alice.to_bytes32()]
+    );
+    assert_eq!(info.logs[0].data.data.to_vec(), 100.to_bytes32());
+
+    // 2. transfer from alice to bob
+    let info = evm
+        .calldata(&contract.encode(&[
+            b"transfer(address,uint256)".to_vec(),
+            bob.to_bytes32().to_vec(),
+            30.to_bytes32().to_vec(),
+        ])?)
+        .call(address)?;
+    assert_eq!(info.ret, true.to_bytes32(), "{info:?}");
+    assert_eq!(
+        info.logs[0].topics(),
+        vec![transfer, alice.to_bytes32(), bob.to_bytes32()]
+    );
+    assert_eq!(info.logs[0].data.data.to_vec(), 30.to_bytes32());
+
+    // 3. check the balances and the total supply
+    for (account, balance) in [(alice, 70), (bob, 30)] {
+        let info = evm
+            .calldata(&contract.encode(&[
+                b"balanceOf(address)".to_vec(),
+                account.to_bytes32().to_vec(),
+            ])?)
+            .call(address)?;
+        assert_eq!(info.decode::<U256>()?, U256::from(balance));
+    }
+
+    let info = evm
+        .calldata(&contract.encode(&[b"totalSupply()".to_vec()])?)
+        .call(address)?;
+    assert_eq!(info.decode::<U256>()?, U256::from(100));
+
+    // 4. transfer more than the balance
+    let info = evm
+        .calldata(&contract.encode(&[
+            b"transfer(address,uint256)".to_vec(),
+            bob.to_bytes32().to_vec(),
+            71.to_bytes32().to_vec(),
+        ])?)
+        .call(address)?;
+    assert_eq!(info.revert, Some("Insufficient balance".into()));
+    Ok(())
+}
+
+#[test]
+fn approve_then_transfer_from() -> anyhow::Result<()> {
+    use zint::{keccak256, Bytes32, Contract, EVM};
+
+    let (alice, bob) = ([1; 20], [2; 20]);
+    let mut evm = EVM::default().commit(true).caller(alice);
+    let contract = Contract::search("erc20")?.compile()?;
+    let address = evm.deploy(&contract.bytecode()?)?.address;
+
+    evm.calldata(&contract.encode(&[
+        b"mint(address,uint256)".to_vec(),
+        alice.to_bytes32().to_vec(),
+        100.to_bytes32().to_vec(),
+    ])?)
+    .call(address)?;
+
+    // 1. alice approves bob
+    let info = evm
+        .calldata(&contract.encode(&[
+            b"approve(address,uint256)".to_vec(),
+            bob.to_bytes32().to_vec(),
+            50.to_bytes32().to_vec(),
+        ])?)
+        .call(address)?;
+    assert_eq!(info.ret, true.to_bytes32(), "{info:?}");
+    assert_eq!(
+        info.logs[0].topics(),
+        vec![
+            keccak256(b"Approval(address,address,uint256)"),
+            alice.to_bytes32(),
+            bob.to_bytes32()
+        ]
+    );
+    assert_eq!(info.logs[0].data.data.to_vec(), 50.to_bytes32());
+
+    // 2. bob transfers from alice to himself
+    evm = evm.caller(bob);
+    let info = evm
+        .calldata(&contract.encode(&[
+            b"transferFrom(address,address,uint256)".to_vec(),
+            alice.to_bytes32().to_vec(),
+            bob.to_bytes32().to_vec(),
+            20.to_bytes32().to_vec(),
+        ])?)
+        .call(address)?;
+    assert_eq!(info.ret, true.to_bytes32(), "{info:?}");
+
+    // 3. the allowance is spent
+    let info = evm
+        .calldata(&contract.encode(&[
+            b"allowance(address,address)".to_vec(),
+            alice.to_bytes32().to_vec(),
+            bob.to_bytes32().to_vec(),
+        ])?)
+        .call(address)?;
+    assert_eq!(info.ret, 30.to_bytes32());
+
+    let info = evm
+        .calldata(&contract.encode(&[b"balanceOf(address)".to_vec(), bob.to_bytes32().to_vec()])?)
+        .call(address)?;
+    assert_eq!(info.ret, 20.to_bytes32());
+
+    // 4. transfer more than the allowance
+    let info = evm
+        .calldata(&contract.encode(&[
+            b"transferFrom(address,address,uint256)".to_vec(),
+            alice.to_bytes32().to_vec(),
+            bob.to_bytes32().to_vec(),
+            31.to_bytes32().to_vec(),
+        ])?)
+        .call(address)?;
+    assert_eq!(info.revert, Some("ERC20 Insufficient allowance".into()));
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! Immutable example.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+extern crate zink;
+
+use zink::{primitives::Address, Immutable};
+
+/// Contract owner set by the constructor
+#[zink::immutable(Address)]
+pub struct Owner;
+
+/// Get the owner of the contract.
+#[zink::external]
+pub fn owner() -> Address {
+    Owner::get()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
+
+#[test]
+fn test() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+
+    let mut contract = Contract::search("immutable")?.compile()?;
+    let owner = [7; 20];
+    let mut evm = contract
+        .immutables(
+            [(Owner::IMMUTABLE_SLOT, owner.to_bytes32().to_vec().into())]
+                .into_iter()
+                .collect(),
+        )?
+        .deploy()?
+        .commit(true);
+
+    let info = evm
+        .calldata(&contract.encode(&[b"owner()".to_vec()])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, owner.to_bytes32().to_vec());
+    Ok(())
+}
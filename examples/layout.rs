@@ -0,0 +1,64 @@
+//! Storage layout example.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+extern crate zink;
+
+use zink::{
+    primitives::{Address, U128, U256},
+    Mapping, Storage,
+};
+
+/// Owner of the contract
+#[zink::storage(Address)]
+pub struct Owner;
+
+/// Counter with value type `u32`
+#[zink::storage(u32)]
+pub struct Count;
+
+/// Limit with value type `U128`
+#[zink::storage(U128)]
+struct Limit;
+
+/// Balances of the accounts
+#[zink::storage(Address, U256)]
+pub struct Balances;
+
+/// Initialize the storages.
+#[zink::external]
+pub fn init(owner: Address, count: u32) {
+    Owner::set(owner);
+    Count::set(count);
+    Limit::set(U128::max());
+    Balances::set(owner, U256::max());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
+
+#[test]
+fn storage_layout() -> anyhow::Result<()> {
+    use zint::{Contract, SlotInfo};
+
+    let contract = Contract::search("layout")?.compile()?;
+    let slot = |name: &str, slot: u32, width: u8| SlotInfo {
+        name: name.into(),
+        slot,
+        offset: 0,
+        width,
+    };
+
+    // values smaller than a word are not packed, each of them
+    // occupies a slot of its own.
+    assert_eq!(
+        contract.storage_layout(),
+        vec![
+            slot("Owner", 0, 20),
+            slot("Count", 1, 4),
+            slot("Limit", 2, 16),
+            slot("Balances", 3, 32),
+        ]
+    );
+    Ok(())
+}
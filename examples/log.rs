@@ -4,12 +4,33 @@
 
 extern crate zink;
 
-use zink::Event;
+use zink::{
+    primitives::{Address, U256},
+    Event,
+};
 
 /// A `Ping` event.
 #[derive(Event)]
 struct Ping;
 
+/// A `Transfer` event with the indexed fields declared first.
+#[derive(Event)]
+struct Transfer {
+    #[indexed]
+    from: Address,
+    #[indexed]
+    to: Address,
+    value: U256,
+}
+
+/// A `Deposit` event with the indexed field declared last.
+#[derive(Event)]
+struct Deposit {
+    value: U256,
+    #[indexed]
+    owner: Address,
+}
+
 #[zink::external]
 pub fn log0() {
     Ping.log0();
@@ -35,6 +56,16 @@ pub fn log4() {
     Ping.log4(b"pong", b"ping", b"pong", b"pong");
 }
 
+#[zink::external]
+pub fn transfer(from: Address, to: Address, value: U256) {
+    Transfer { from, to, value }.emit();
+}
+
+#[zink::external]
+pub fn deposit(owner: Address, value: U256) {
+    Deposit { value, owner }.emit();
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn main() {}
 
@@ -97,3 +128,38 @@ fn test() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn indexed() -> anyhow::Result<()> {
+    use zint::{keccak256, Bytes32, Contract};
+    let mut contract = Contract::search("log")?.compile()?;
+    let (from, to) = ([1; 20], [2; 20]);
+
+    let info = contract.execute(&[
+        b"transfer(address,address,uint256)".to_vec(),
+        from.to_bytes32().to_vec(),
+        to.to_bytes32().to_vec(),
+        42.to_bytes32().to_vec(),
+    ])?;
+    assert_eq!(
+        info.logs[0].topics(),
+        vec![
+            keccak256(b"Transfer(address,address,uint256)"),
+            from.to_bytes32(),
+            to.to_bytes32()
+        ]
+    );
+    assert_eq!(info.logs[0].data.data.to_vec(), 42.to_bytes32());
+
+    let info = contract.execute(&[
+        b"deposit(address,uint256)".to_vec(),
+        from.to_bytes32().to_vec(),
+        42.to_bytes32().to_vec(),
+    ])?;
+    assert_eq!(
+        info.logs[0].topics(),
+        vec![keccak256(b"Deposit(uint256,address)"), from.to_bytes32()]
+    );
+    assert_eq!(info.logs[0].data.data.to_vec(), 42.to_bytes32());
+    Ok(())
+}
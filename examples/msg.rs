@@ -0,0 +1,71 @@
+//! Message example.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+extern crate zink;
+
+use zink::{
+    msg,
+    primitives::{Address, Bytes4, U256},
+};
+
+/// Get the sender of the message.
+#[zink::external]
+pub fn sender() -> Address {
+    msg::sender()
+}
+
+/// Get the selector of the message.
+#[zink::external]
+pub fn sig() -> Bytes4 {
+    msg::sig()
+}
+
+/// Get the value of the message.
+#[zink::external]
+#[zink::payable]
+pub fn value() -> U256 {
+    msg::value()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
+
+#[test]
+fn test() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+    let mut contract = Contract::search("msg")?.compile()?;
+
+    let caller = [7; 20];
+    let info = contract
+        .with_caller(caller)
+        .execute(["sender()".as_bytes()])?;
+    assert_eq!(info.ret, caller.to_bytes32());
+
+    let mut selector = [0; 32];
+    selector[..4].copy_from_slice(&zint::keccak256(b"sig()")[..4]);
+    let info = contract.execute(["sig()".as_bytes()])?;
+    assert_eq!(info.ret, selector);
+    Ok(())
+}
+
+#[test]
+fn payable() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract, U256};
+    let mut contract = Contract::search("msg")?.compile()?;
+
+    let info = contract
+        .with_value(U256::from(42))
+        .execute(["value()".as_bytes()])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+
+    // functions are not payable by default
+    let info = contract
+        .with_value(U256::from(42))
+        .execute(["sender()".as_bytes()])?;
+    assert_eq!(info.revert, Some(String::new()));
+
+    let info = contract.execute(["sender()".as_bytes()])?;
+    assert!(info.revert.is_none());
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! Native unit test example.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+#![cfg_attr(target_arch = "wasm32", no_main)]
+extern crate zink;
+
+/// Squares the number.
+#[zink::external]
+pub fn square(x: u64) -> u64 {
+    x * x
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
+
+#[test]
+fn native() {
+    assert_eq!(square(3), 9);
+}
+
+#[test]
+fn test() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+    let mut contract = Contract::search("native")?.compile()?;
+
+    let info = contract.execute(["square(uint64)".as_bytes(), &3u64.to_bytes32()])?;
+    assert_eq!(info.ret, 9u64.to_bytes32());
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Overloaded functions.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+extern crate zink;
+
+use zink::primitives::{Address, U256};
+
+/// `f(uint256)`
+#[zink::external(name = "f")]
+pub fn f_uint256(value: U256) -> U256 {
+    value
+}
+
+/// `f(address)`
+#[zink::external(name = "f")]
+pub fn f_address(account: Address) -> Address {
+    account
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
+
+#[test]
+fn overload() -> anyhow::Result<()> {
+    use zabi::selector::selector_of;
+    use zint::{Bytes32, Contract};
+
+    let mut contract = Contract::search("overload")?.compile()?;
+    let account = [1; 20];
+
+    let info = contract.execute(&[b"f(uint256)".to_vec(), 42.to_bytes32().to_vec()])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+
+    let info = contract.execute(&[b"f(address)".to_vec(), account.to_bytes32().to_vec()])?;
+    assert_eq!(info.ret, account.to_bytes32());
+
+    // both of them are listed as `f` in the ABI.
+    let selectors = contract
+        .artifact
+        .abi
+        .iter()
+        .filter(|abi| abi.name == "f")
+        .map(|abi| abi.selector())
+        .collect::<Vec<_>>();
+    assert_eq!(selectors.len(), 2);
+    for signature in ["f(uint256)", "f(address)"] {
+        assert!(selectors.contains(&selector_of(signature)), "{signature}");
+    }
+
+    Ok(())
+}
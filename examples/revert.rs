@@ -13,6 +13,12 @@ pub fn run_revert() {
     zink::revert!("revert works")
 }
 
+/// revert with the panic code of arithmetic overflow
+#[zink::external]
+pub fn run_panic() {
+    zink::revert_with_code!(0x11)
+}
+
 #[test]
 fn test_revert() -> anyhow::Result<()> {
     use zint::Contract;
@@ -22,3 +28,58 @@ fn test_revert() -> anyhow::Result<()> {
     assert_eq!(info.revert, Some("revert works".into()));
     Ok(())
 }
+
+#[test]
+fn test_revert_traced() -> anyhow::Result<()> {
+    use opcodes::ShangHai as OpCode;
+    use zint::Contract;
+    let mut contract = Contract::search("revert")?.compile()?;
+
+    let (info, steps) = contract.execute_traced(["run_revert()".as_bytes()])?;
+    assert_eq!(info.revert, Some("revert works".into()));
+    assert_eq!(
+        steps.last().map(|step| step.opcode),
+        Some(OpCode::REVERT.into())
+    );
+    Ok(())
+}
+
+#[test]
+fn gas_snapshot() -> anyhow::Result<()> {
+    use zint::Contract;
+    let mut contract = Contract::search("revert")?.compile()?;
+
+    let info = contract.gas_snapshot("revert", ["run_revert()".as_bytes()])?;
+    assert_eq!(info.revert, Some("revert works".into()));
+    Ok(())
+}
+
+#[test]
+fn gas_budget() -> anyhow::Result<()> {
+    use zint::Contract;
+    let mut contract = Contract::search("revert")?.compile()?;
+
+    let info = contract.assert_gas_lt(30_000, ["run_revert()".as_bytes()])?;
+    assert_eq!(info.revert, Some("revert works".into()));
+
+    // the intrinsic gas of the transaction exceeds the budget.
+    assert!(contract
+        .assert_gas_lt(21_000, ["run_revert()".as_bytes()])
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn test_revert_with_code() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+    let mut contract = Contract::search("revert")?.compile()?;
+
+    let (success, output) = contract.call_raw(&zint::keccak256(b"run_panic()")[..4])?;
+    assert!(!success);
+    assert_eq!(
+        output,
+        [&zint::keccak256(b"Panic(uint256)")[..4], &0x11.to_bytes32()].concat()
+    );
+    assert_eq!(hex::encode(&output[..4]), "4e487b71");
+    Ok(())
+}
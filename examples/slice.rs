@@ -0,0 +1,76 @@
+//! Example of the byte slice parameters.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+extern crate zink;
+
+/// Get the length of the bytes.
+#[zink::external]
+pub fn length(data: &[u8]) -> u32 {
+    data.len() as u32
+}
+
+/// Get the byte at the index.
+#[zink::external]
+pub fn at(data: &[u8], index: u32) -> u8 {
+    data[index as usize]
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
+
+/// Encode the calldata of the function with the bytes and the
+/// trailing static arguments.
+#[cfg(test)]
+fn calldata(signature: &str, data: &[u8], args: &[[u8; 32]]) -> Vec<u8> {
+    use zint::Bytes32;
+
+    let head = (32 * (1 + args.len())) as u32;
+    let mut calldata = zint::keccak256(signature.as_bytes())[..4].to_vec();
+    calldata.extend(head.to_bytes32());
+    args.iter().for_each(|arg| calldata.extend(arg));
+    calldata.extend((data.len() as u32).to_bytes32());
+    calldata.extend(data);
+    calldata.resize((calldata.len() - 4).next_multiple_of(32) + 4, 0);
+    calldata
+}
+
+#[test]
+fn slice_length() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+
+    let mut contract = Contract::search("slice")?.compile()?;
+    let mut evm = contract.deploy()?;
+
+    for data in [&[][..], b"zink", &[42; 100]] {
+        let info = evm
+            .calldata(&calldata("length(bytes)", data, &[]))
+            .call(contract.address)?;
+        assert_eq!(info.ret, (data.len() as u32).to_bytes32());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn slice_index() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+
+    let mut contract = Contract::search("slice")?.compile()?;
+    let mut evm = contract.deploy()?;
+
+    let data = b"zink";
+    for (index, byte) in data.iter().enumerate() {
+        let info = evm
+            .calldata(&calldata("at(bytes,uint32)", data, &[index.to_bytes32()]))
+            .call(contract.address)?;
+        assert_eq!(info.ret, byte.to_bytes32());
+    }
+
+    // out of bounds
+    let info = evm
+        .calldata(&calldata("at(bytes,uint32)", data, &[4.to_bytes32()]))
+        .call(contract.address)?;
+    assert!(info.halt.is_some() || info.revert.is_some(), "{info:?}");
+    Ok(())
+}
@@ -16,6 +16,19 @@ pub fn set(value: i32) {
     Counter::set(value);
 }
 
+/// set value to the storage if it changes.
+#[zink::external]
+pub fn set_if_changed(value: i32) {
+    Counter::set_if_changed(value);
+}
+
+/// get value from the storage.
+#[zink::external]
+#[zink::view]
+pub fn get() -> i32 {
+    Counter::get()
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 fn main() {}
 
@@ -42,3 +55,68 @@ fn value() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn void_return() -> anyhow::Result<()> {
+    use opcodes::ShangHai as OpCode;
+    use zint::{Bytes32, Contract};
+
+    let mut contract = Contract::search("storage")?.compile()?;
+    let (info, steps) =
+        contract.execute_traced(&[b"set(int32)".to_vec(), 42.to_bytes32().to_vec()])?;
+    assert!(info.halt.is_none());
+    assert!(info.revert.is_none());
+    assert!(info.ret.is_empty());
+
+    // returns with empty data instead of `STOP`.
+    assert_eq!(
+        steps.last().map(|step| step.opcode),
+        Some(OpCode::RETURN.into())
+    );
+    Ok(())
+}
+
+#[test]
+fn set_if_changed() -> anyhow::Result<()> {
+    use opcodes::ShangHai as OpCode;
+    use zint::{Bytes32, Contract};
+
+    // counts the executed `SSTORE`s, the storage starts with zero.
+    let sstores = |contract: &mut Contract, method: &[u8], value: i32| -> anyhow::Result<usize> {
+        let (info, steps) =
+            contract.execute_traced(&[method.to_vec(), value.to_bytes32().to_vec()])?;
+        assert!(info.halt.is_none());
+        assert!(info.revert.is_none());
+        Ok(steps
+            .iter()
+            .filter(|step| step.opcode == u8::from(OpCode::SSTORE))
+            .count())
+    };
+
+    let mut contract = Contract::search("storage")?.compile()?;
+    assert_eq!(sstores(&mut contract, b"set_if_changed(int32)", 0)?, 0);
+    assert_eq!(sstores(&mut contract, b"set_if_changed(int32)", 42)?, 1);
+    assert_eq!(sstores(&mut contract, b"set(int32)", 0)?, 1);
+
+    let mut contract = Contract::search("storage")?.sstore_if_changed().compile()?;
+    assert_eq!(sstores(&mut contract, b"set(int32)", 0)?, 0);
+    assert_eq!(sstores(&mut contract, b"set(int32)", 42)?, 1);
+    Ok(())
+}
+
+#[test]
+fn state_mutability() -> anyhow::Result<()> {
+    use zint::Contract;
+
+    let contract = Contract::search("storage")?.compile()?;
+    let abi: Vec<serde_json::Value> = serde_json::from_str(&contract.json_abi()?)?;
+    let mutability = |name: &str| {
+        abi.iter()
+            .find(|abi| abi["name"] == name)
+            .map(|abi| abi["stateMutability"].clone())
+    };
+
+    assert_eq!(mutability("get"), Some("view".into()));
+    assert_eq!(mutability("set"), Some("nonpayable".into()));
+    Ok(())
+}
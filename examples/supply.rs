@@ -0,0 +1,53 @@
+//! Constructor function example.
+#![cfg_attr(target_arch = "wasm32", no_std)]
+#![cfg_attr(target_arch = "wasm32", no_main)]
+
+extern crate zink;
+
+use zink::Storage;
+
+/// Total supply set by the constructor.
+#[zink::storage(u32)]
+pub struct Supply;
+
+/// Set the total supply, reverts on the zero supply.
+#[zink::constructor]
+pub fn init(supply: u32) {
+    zink::require!(supply > 0, "zero supply");
+    Supply::set(supply);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
+
+#[test]
+fn deploy() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract};
+
+    let mut contract = Contract::search("supply")?.compile()?;
+    let mut evm = contract.deploy_with([42u32])?;
+    let info = evm
+        .calldata(&contract.encode(&["supply()"])?)
+        .call(contract.address)?;
+
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn revert() -> anyhow::Result<()> {
+    use zint::{Bytes32, Contract, EVM};
+
+    let mut contract = Contract::search("supply")?.compile()?;
+    let bytecode = [contract.bytecode()?, 0u32.to_bytes32().to_vec()].concat();
+    let info = EVM::default().deploy(&bytecode)?;
+    assert_eq!(info.revert, Some("zero supply".into()));
+    assert_eq!(info.address, [0; 20]);
+
+    let error = contract
+        .deploy_with([0u32])
+        .err()
+        .expect("deployment reverted");
+    assert_eq!(error.to_string(), "deployment reverted: zero supply");
+    Ok(())
+}
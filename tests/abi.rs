@@ -0,0 +1,86 @@
+//! ABI tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{keccak256, Bytes32, Contract};
+
+const ABI: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/abi/Transfer.json");
+
+#[test]
+fn transfer() -> Result<()> {
+    let mut contract = Contract::from(Test::ABI_TRANSFER)
+        .compile()?
+        .with_abi(ABI)?;
+
+    let call = contract.call("transfer")?.arg([1; 20]).arg(42);
+    assert_eq!(call.calldata()?[..4], [0xa9, 0x05, 0x9c, 0xbb]);
+
+    let info = call.execute()?;
+    assert_eq!(info.ret, 42.to_bytes32());
+
+    let info = contract
+        .call("transfer(address,uint256)")?
+        .arg([0; 20])
+        .arg(42)
+        .execute()?;
+    assert_eq!(info.revert, Some("zero address".into()));
+    Ok(())
+}
+
+#[test]
+fn unresolved() -> Result<()> {
+    let mut contract = Contract::from(Test::ABI_TRANSFER)
+        .compile()?
+        .with_abi(ABI)?;
+
+    assert!(contract.call("Transfer").is_err());
+    assert!(contract.call("transfer")?.arg([1; 20]).execute().is_err());
+    Ok(())
+}
+
+#[test]
+fn encode() -> Result<()> {
+    let mut contract = Contract::from(Test::ABI_ENCODE).pure().compile()?;
+
+    let a = [0xab; 32];
+    let info = contract.execute([a, 7.to_bytes32()])?;
+    assert_eq!(info.ret, keccak256(&[a, 7.to_bytes32()].concat()));
+    Ok(())
+}
+
+#[test]
+fn decode() -> Result<()> {
+    let mut contract = Contract::from(Test::ABI_DECODE).pure().compile()?;
+
+    let info = contract.execute([[0xab; 32], 7.to_bytes32()])?;
+    assert_eq!(info.ret, 7.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn named() -> Result<()> {
+    let mut contract = Contract::from(Test::ABI_NAMED).compile()?;
+    let abi = contract.json_abi()?;
+    assert!(abi.contains(r#""name": "balance""#), "{abi}");
+
+    let info = contract.execute([
+        b"balance_of(address)".to_vec(),
+        [1; 20].to_bytes32().to_vec(),
+    ])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn encode_packed() -> Result<()> {
+    let mut contract = Contract::from(Test::ABI_ENCODE_PACKED).pure().compile()?;
+
+    let address = [0xab; 20];
+    let info = contract.execute([address.to_bytes32(), 7.to_bytes32()])?;
+    assert_eq!(
+        info.ret,
+        keccak256(&[address.as_slice(), &[0, 0, 0, 7]].concat())
+    );
+    Ok(())
+}
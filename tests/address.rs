@@ -0,0 +1,96 @@
+//! address tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract, CONTRACT, U256};
+
+#[test]
+fn transfer() -> Result<()> {
+    let mut contract = Contract::from(Test::ADDRESS_TRANSFER).pure().compile()?;
+
+    let info = contract.execute([[0; 20].to_bytes32(), 42.to_bytes32()])?;
+    assert_eq!(info.revert, Some("zero address".into()));
+
+    let info = contract.execute([[1; 20].to_bytes32(), 42.to_bytes32()])?;
+    assert!(info.revert.is_none());
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn only_owner() -> Result<()> {
+    let mut contract = Contract::from(Test::ADDRESS_ONLY_OWNER).pure().compile()?;
+    let mut owner = [0; 20];
+    owner[18..].copy_from_slice(&[0x12, 0x34]);
+
+    let info = contract.with_caller(owner).execute::<()>([])?;
+    assert!(info.revert.is_none());
+    assert_eq!(info.ret, true.to_bytes32());
+
+    // the caller only applies to the previous execution
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.revert, Some("not owner".into()));
+
+    let info = contract.with_caller([8; 20]).execute::<()>([])?;
+    assert_eq!(info.revert, Some("not owner".into()));
+
+    let info = contract.set_caller(owner).execute::<()>([])?;
+    assert!(info.revert.is_none());
+    let info = contract.execute::<()>([])?;
+    assert!(info.revert.is_none());
+    Ok(())
+}
+
+#[test]
+fn prank() -> Result<()> {
+    let mut contract = Contract::from(Test::ADDRESS_ONLY_OWNER).pure().compile()?;
+    let mut owner = [0; 20];
+    owner[18..].copy_from_slice(&[0x12, 0x34]);
+
+    contract.start_prank(owner)?;
+    for _ in 0..2 {
+        let info = contract.execute::<()>([])?;
+        assert!(info.revert.is_none());
+        assert_eq!(info.ret, true.to_bytes32());
+    }
+
+    assert!(contract.start_prank([8; 20]).is_err());
+
+    contract.stop_prank();
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.revert, Some("not owner".into()));
+    Ok(())
+}
+
+#[test]
+fn balance() -> Result<()> {
+    let mut contract = Contract::from(Test::ADDRESS_BALANCE).pure().compile()?;
+    let account = [7; 20];
+
+    let info = contract.execute([account.to_bytes32()])?;
+    assert_eq!(info.ret, 0.to_bytes32());
+
+    contract.deal(account, U256::from(100));
+    let info = contract.execute([account.to_bytes32()])?;
+    assert_eq!(info.ret, 100.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn forward_value() -> Result<()> {
+    let mut contract = Contract::from(Test::ADDRESS_FORWARD).pure().compile()?;
+    let (caller, receiver) = ([7; 20], [8; 20]);
+
+    contract.deal(caller, U256::from(1000));
+    let info = contract
+        .with_caller(caller)
+        .with_value(U256::from(100))
+        .execute([receiver.to_bytes32()])?;
+    assert_eq!(info.ret, true.to_bytes32());
+
+    assert_eq!(contract.balance_of(caller), U256::from(900));
+    assert_eq!(contract.balance_of(CONTRACT), U256::from(50));
+    assert_eq!(contract.balance_of(receiver), U256::from(50));
+    Ok(())
+}
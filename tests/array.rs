@@ -0,0 +1,41 @@
+//! dynamic array tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn u256() -> Result<()> {
+    let mut contract = Contract::from(Test::ARRAY_U256).compile()?;
+
+    let info = contract.execute([b"list()".to_vec()])?;
+    assert_eq!(
+        info.ret,
+        [0x20, 3, 1, 2, 3]
+            .iter()
+            .flat_map(|n: &u64| n.to_bytes32())
+            .collect::<Vec<_>>()
+    );
+
+    let info = contract.execute([b"empty()".to_vec()])?;
+    assert_eq!(
+        info.ret,
+        [0x20, 0]
+            .iter()
+            .flat_map(|n: &u64| n.to_bytes32())
+            .collect::<Vec<_>>()
+    );
+
+    // the array in the first word of the memory is moved up for the
+    // ABI offset before it.
+    let info = contract.execute([b"low()".to_vec()])?;
+    assert_eq!(
+        info.ret,
+        [0x20, 2, 7, 8]
+            .iter()
+            .flat_map(|n: &u64| n.to_bytes32())
+            .collect::<Vec<_>>()
+    );
+    Ok(())
+}
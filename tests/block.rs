@@ -0,0 +1,45 @@
+//! block environment tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn timelock() -> Result<()> {
+    let mut contract = Contract::from(Test::BLOCK_TIMELOCK).pure().compile()?;
+
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.revert, Some("locked".into()));
+
+    contract.warp(999);
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.revert, Some("locked".into()));
+
+    contract.warp(1000).set_block_number(42);
+    let info = contract.execute::<()>([])?;
+    assert!(info.revert.is_none());
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn roll() -> Result<()> {
+    let mut contract = Contract::from(Test::BLOCK_HASH).pure().compile()?;
+
+    for number in [1u64, 2, 3, 100, 300] {
+        contract.roll(number);
+        let info = contract.execute([0u64])?;
+        assert_eq!(info.ret, number.to_bytes32());
+
+        let info = contract.execute([1u64])?;
+        assert_eq!(info.ret, zint::block_hash(number - 1));
+    }
+
+    // only the 256 most recent blocks have their hashes.
+    let info = contract.execute([256u64])?;
+    assert_eq!(info.ret, zint::block_hash(44));
+    let info = contract.execute([257u64])?;
+    assert_eq!(info.ret, [0; 32]);
+    Ok(())
+}
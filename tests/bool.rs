@@ -0,0 +1,46 @@
+//! boolean short-circuit tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract, U256};
+
+/// Opcode `ISZERO`.
+const ISZERO: u8 = 0x15;
+
+/// If the bytecode negates a value twice in a row.
+fn double_negation(bytecode: &[u8]) -> bool {
+    bytecode.windows(2).any(|ops| ops == [ISZERO, ISZERO])
+}
+
+#[test]
+fn and() -> Result<()> {
+    let mut contract = Contract::from(Test::BOOL_AND).pure().compile()?;
+    assert!(!double_negation(&contract.runtime_bytecode()));
+
+    // the second operand is skipped.
+    let info = contract.execute([0, 42])?;
+    assert_eq!(info.ret, 0.to_bytes32());
+    assert!(info.storage.is_empty());
+
+    let info = contract.execute([1, 42])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+    assert_eq!(info.storage.get(&U256::ZERO), Some(&U256::from(42)));
+    Ok(())
+}
+
+#[test]
+fn or() -> Result<()> {
+    let mut contract = Contract::from(Test::BOOL_OR).pure().compile()?;
+    assert!(!double_negation(&contract.runtime_bytecode()));
+
+    // the second operand is skipped.
+    let info = contract.execute([1, 42])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+    assert!(info.storage.is_empty());
+
+    let info = contract.execute([0, 42])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+    assert_eq!(info.storage.get(&U256::ZERO), Some(&U256::from(42)));
+    Ok(())
+}
@@ -1,19 +1,47 @@
 //! br_if tests for the zink compiler.
 use anyhow::Result;
 use filetests::Test;
-use zint::Contract;
+use zint::{Bytes32, Contract};
 
 #[test]
 fn as_block_last() -> Result<()> {
     let mut contract = Contract::from(Test::BR_IF_AS_BLOCK_LAST).pure().compile()?;
 
-    let info = contract.execute(&[0])?;
+    let info = contract.execute([0])?;
     assert!(info.halt.is_none());
     assert!(info.ret.is_empty());
 
-    let info = contract.execute(&[42])?;
+    let info = contract.execute([42])?;
     assert!(info.halt.is_none());
     assert!(info.ret.is_empty());
 
     Ok(())
 }
+
+#[test]
+fn as_block_value() -> Result<()> {
+    let mut contract = Contract::from(Test::BR_IF_AS_BLOCK_VALUE)
+        .pure()
+        .compile()?;
+
+    let info = contract.execute([0])?;
+    assert_eq!(info.ret, 2.to_bytes32());
+
+    let info = contract.execute([1])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn as_block_value_dropped() -> Result<()> {
+    let mut contract = Contract::from(Test::BR_IF_AS_BLOCK_VALUE_DROPPED)
+        .pure()
+        .compile()?;
+
+    let info = contract.execute([0])?;
+    assert_eq!(info.ret, 10.to_bytes32());
+
+    let info = contract.execute([1])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+    Ok(())
+}
@@ -0,0 +1,40 @@
+//! br_table tests for the zink compiler.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn dispatch() -> Result<()> {
+    let mut contract = Contract::from(Test::BR_TABLE_DISPATCH).pure().compile()?;
+
+    for index in 0..10 {
+        let info = contract.execute([index])?;
+        assert_eq!(info.ret, (100 + index).to_bytes32());
+    }
+
+    // out of bounds
+    let info = contract.execute([200])?;
+    assert_eq!(info.ret, 109.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn gas() -> Result<()> {
+    let mut dispatch = Contract::from(Test::BR_TABLE_DISPATCH).pure().compile()?;
+    let mut linear = Contract::from(Test::BR_TABLE_LINEAR).pure().compile()?;
+
+    for index in 0..10 {
+        assert_eq!(dispatch.execute([index])?.ret, linear.execute([index])?.ret);
+    }
+
+    // The cost of the jump table does not depend on the arm.
+    let first = dispatch.execute([1])?.gas;
+    let last = dispatch.execute([8])?.gas;
+    assert_eq!(first, last);
+
+    // The linear comparisons cost more for the later arms.
+    assert!(last < linear.execute([8])?.gas);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+//! Fixed-size byte array tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract, FixedBytes};
+
+#[test]
+fn left_aligned() -> Result<()> {
+    let mut contract = Contract::from(Test::BYTES_ECHO).pure().compile()?;
+
+    let bytes4 = FixedBytes([0xde, 0xad, 0xbe, 0xef]);
+    let calldata = contract.encode([bytes4])?;
+    assert_eq!(calldata[..4], bytes4.0);
+    assert!(calldata[4..].iter().all(|b| *b == 0));
+
+    // bytes4 keeps its alignment through the contract.
+    let info = contract.execute([bytes4])?;
+    assert_eq!(info.ret, calldata);
+
+    // uint32 is right-aligned.
+    let uint32 = contract.encode([0xdeadbeefu32])?;
+    assert_eq!(uint32, 0xdeadbeefu32.to_bytes32());
+    assert_ne!(uint32, calldata);
+
+    Ok(())
+}
+
+#[test]
+fn store() -> Result<()> {
+    let mut contract = Contract::from(Test::BYTES_STORE).pure().compile()?;
+    let mut evm = contract.deploy()?.commit(true);
+
+    let bytes4 = FixedBytes([0xde, 0xad, 0xbe, 0xef]);
+    let bytes20 = FixedBytes([0xab; 20]);
+    evm.calldata(&contract.encode([bytes4.to_bytes32(), bytes20.to_bytes32()])?)
+        .call(contract.address)?;
+    assert_eq!(
+        evm.storage(contract.address, 0.to_bytes32())?,
+        bytes4.to_bytes32()
+    );
+    assert_eq!(
+        evm.storage(contract.address, 1.to_bytes32())?,
+        bytes20.to_bytes32()
+    );
+
+    // the bytes following the leading ones are cleaned.
+    evm.calldata(&contract.encode([[0xff; 32], [0xff; 32]])?)
+        .call(contract.address)?;
+    assert_eq!(
+        evm.storage(contract.address, 0.to_bytes32())?,
+        FixedBytes([0xff; 4]).to_bytes32()
+    );
+    assert_eq!(
+        evm.storage(contract.address, 1.to_bytes32())?,
+        FixedBytes([0xff; 20]).to_bytes32()
+    );
+
+    Ok(())
+}
@@ -23,6 +23,49 @@ fn params() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn indirect() -> Result<()> {
+    let mut contract = Contract::from(Test::CALL_INDIRECT).pure().compile()?;
+
+    for (index, result) in [(0, 14), (2, 49)] {
+        let info = contract.execute([index, 7])?;
+        assert_eq!(info.ret, result.to_bytes32(), "index {index}");
+    }
+
+    // the function of the other type and the index out of the table.
+    for index in [1, 3] {
+        let info = contract.execute([index, 7])?;
+        assert!(info.halt.is_some(), "index {index}");
+    }
+    Ok(())
+}
+
+#[test]
+fn many_params() -> Result<()> {
+    let mut contract = Contract::from(Test::CALL_MANY_PARAMS).pure().compile()?;
+
+    for (param, sum) in [(1, 220), (21, 200)] {
+        let info = contract.execute([param])?;
+        assert_eq!(info.ret, sum.to_bytes32(), "param {param}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn multi_value() -> Result<()> {
+    let mut contract = Contract::from(Test::CALL_MULTI_VALUE).pure().compile()?;
+
+    // both of the quotient and the remainder are returned.
+    let info = contract.execute([17, 5])?;
+    assert_eq!(info.ret, 32.to_bytes32());
+
+    // the results of the early return are kept in order.
+    let info = contract.execute([9, 0])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
 #[test]
 fn as_if() -> Result<()> {
     let mut contract = Contract::from(Test::CALL_AS_IF).pure().compile()?;
@@ -40,3 +83,16 @@ fn as_if() -> Result<()> {
     assert_eq!(info.ret, 42.to_bytes32());
     Ok(())
 }
+
+#[test]
+fn early_return() -> Result<()> {
+    let mut contract = Contract::from(Test::CALL_EARLY_RETURN).pure().compile()?;
+
+    // the caller continues after the early return of the callee.
+    let info = contract.execute([0])?;
+    assert_eq!(info.ret, 107.to_bytes32());
+
+    let info = contract.execute([41])?;
+    assert_eq!(info.ret, 142.to_bytes32());
+    Ok(())
+}
@@ -0,0 +1,69 @@
+//! calldata tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use opcodes::ShangHai as OpCode;
+use zint::{Bytes32, Contract, EVM};
+
+/// Count the occurrences of the opcode in the bytecode.
+fn count(bytecode: &[u8], opcode: OpCode) -> usize {
+    let (push1, push32) = (u8::from(OpCode::PUSH1), u8::from(OpCode::PUSH32));
+    let (mut pc, mut count) = (0, 0);
+    while let Some(op) = bytecode.get(pc) {
+        if *op == u8::from(opcode) {
+            count += 1;
+        }
+
+        pc += 1;
+        if (push1..=push32).contains(op) {
+            pc += (op - push1 + 1) as usize;
+        }
+    }
+
+    count
+}
+
+#[test]
+fn once() -> Result<()> {
+    let mut contract = Contract::from(Test::CALLDATA_ONCE).pure().compile()?;
+    assert_eq!(count(&contract.runtime_bytecode(), OpCode::CALLDATALOAD), 2);
+
+    let info = contract.execute([20, 22])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn reuse() -> Result<()> {
+    let mut contract = Contract::from(Test::CALLDATA_REUSE).pure().compile()?;
+    assert_eq!(count(&contract.runtime_bytecode(), OpCode::CALLDATALOAD), 1);
+
+    let info = contract.execute([14])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn copy() -> Result<()> {
+    let contract = Contract::from(Test::CALLDATA_COPY).pure().compile()?;
+
+    // ABI encoding of the dynamic `bytes`, the length could be
+    // larger than the provided data which is zero-filled.
+    let hash = |data: &[u8], len: u8| -> Result<Vec<u8>> {
+        let mut calldata = [0x20.to_bytes32(), len.to_bytes32()].concat();
+        calldata.extend_from_slice(data);
+        Ok(EVM::interp(&contract.runtime_bytecode(), &calldata)?.ret)
+    };
+
+    assert_eq!(hash(&[], 0)?, zint::keccak256(&[]));
+    assert_eq!(hash(b"zink", 4)?, zint::keccak256(b"zink"));
+
+    let data = [0x42; 40];
+    assert_eq!(hash(&data, 40)?, zint::keccak256(&data));
+    assert_eq!(
+        hash(&data[..8], 16)?,
+        zint::keccak256(&[&data[..8], &[0; 8]].concat())
+    );
+    Ok(())
+}
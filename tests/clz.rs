@@ -0,0 +1,40 @@
+//! clz tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zingen::ExtFunc;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn shared_helper() -> Result<()> {
+    let mut contract = Contract::from(Test::CLZ_THREE).pure().compile()?;
+
+    // the helper is emitted once for all of the calls.
+    let helper = ExtFunc::clz(4).bytecode;
+    let bytecode = &contract.artifact.runtime_bytecode;
+    let copies = bytecode
+        .windows(helper.len())
+        .filter(|window| *window == helper.as_slice())
+        .count();
+    assert_eq!(copies, 1);
+
+    for (value, expected) in [(0, 96), (1, 93), (0x80, 72)] {
+        let info = contract.execute([value])?;
+        assert_eq!(info.ret, expected.to_bytes32(), "clz({value:#x}) * 3");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn i64() -> Result<()> {
+    let mut contract = Contract::from(Test::CLZ_I64).pure().compile()?;
+
+    for (value, expected) in [(0u64, 64), (1, 63), (0x80, 56)] {
+        let info = contract.execute([value])?;
+        assert_eq!(info.ret, expected.to_bytes32(), "clz({value:#x})");
+    }
+
+    Ok(())
+}
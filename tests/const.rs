@@ -0,0 +1,68 @@
+//! constant tests for the zink compiler.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+/// Opcode `PUSH4`.
+const PUSH4: u8 = 0x63;
+
+#[test]
+fn negative() -> Result<()> {
+    let mut contract = Contract::from(Test::CONST_NEGATIVE).pure().compile()?;
+    let bytecode = contract.runtime_bytecode();
+    assert!(bytecode
+        .windows(5)
+        .any(|ops| ops == [PUSH4, 0xff, 0xff, 0xff, 0xff]));
+
+    let info = contract.execute([0])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+
+    let info = contract.execute([42])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn eq_negative() -> Result<()> {
+    let mut contract = Contract::from(Test::CONST_EQ_NEGATIVE).pure().compile()?;
+
+    let info = contract.execute([u32::MAX])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+
+    let info = contract.execute([u32::MAX - 1])?;
+    assert_eq!(info.ret, 0.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn lt_u_negative() -> Result<()> {
+    let mut contract = Contract::from(Test::CONST_LT_U_NEGATIVE).pure().compile()?;
+
+    let info = contract.execute([u32::MAX])?;
+    assert_eq!(info.ret, 0.to_bytes32());
+
+    let info = contract.execute([u32::MAX - 1])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn pool() -> Result<()> {
+    let mut contract = Contract::from(Test::CONST_POOL).pure().compile()?;
+    let mut pooled = Contract::from(Test::CONST_POOL)
+        .pure()
+        .const_pool(2)
+        .compile()?;
+    assert!(pooled.runtime_bytecode().len() < contract.runtime_bytecode().len());
+
+    let topic = contract.execute::<()>([])?.logs[0].topics()[0];
+    let info = pooled.execute::<()>([])?;
+    assert_eq!(info.logs.len(), 3);
+    for log in info.logs {
+        assert_eq!(log.data.data.to_vec(), b"Ping".to_vec().to_bytes32());
+        assert_eq!(log.topics()[0], topic);
+    }
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! constructor tests for the zink compiler.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use opcodes::ShangHai as OpCode;
+use zint::Contract;
+
+#[test]
+fn init_code() -> Result<()> {
+    let contract = Contract::from(Test::CALL_PARAMS).pure().compile()?;
+    let init_code = contract.init_bytecode()?;
+    let runtime_bytecode = contract.runtime_bytecode();
+    assert_eq!(
+        [init_code.clone(), runtime_bytecode.clone()].concat(),
+        contract.bytecode()?
+    );
+
+    // [PUSH1, size, PUSH1, offset, PUSH0, CODECOPY, PUSH1, size, PUSH0, RETURN]
+    let size = runtime_bytecode.len() as u8;
+    let offset = init_code.len() as u8;
+    assert_eq!(
+        init_code,
+        [
+            OpCode::PUSH1,
+            OpCode::Data(size),
+            OpCode::PUSH1,
+            OpCode::Data(offset),
+            OpCode::PUSH0,
+            OpCode::CODECOPY,
+            OpCode::PUSH1,
+            OpCode::Data(size),
+            OpCode::PUSH0,
+            OpCode::RETURN,
+        ]
+        .into_iter()
+        .map(u8::from)
+        .collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
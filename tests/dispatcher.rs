@@ -0,0 +1,38 @@
+//! dispatcher tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn counter() -> Result<()> {
+    let mut contract = Contract::from(Test::DISPATCHER_COUNTER).compile()?;
+    let mut evm = contract.deploy()?.commit(true);
+
+    let info = evm
+        .calldata(&contract.encode([b"get()".to_vec()])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, 0.to_bytes32());
+
+    let info = evm
+        .calldata(&contract.encode([b"set(uint32)".to_vec(), 42.to_bytes32().to_vec()])?)
+        .call(contract.address)?;
+    assert!(info.ret.is_empty());
+
+    let info = evm
+        .calldata(&contract.encode([b"increment()".to_vec()])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, 43.to_bytes32());
+
+    let info = evm
+        .calldata(&contract.encode([b"increment()".to_vec()])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, 44.to_bytes32());
+
+    let info = evm
+        .calldata(&contract.encode([b"get()".to_vec()])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, 44.to_bytes32());
+    Ok(())
+}
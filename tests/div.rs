@@ -0,0 +1,91 @@
+//! integer division tests
+//!
+//! The fixtures of the signed and the unsigned instructions execute
+//! `(x + y) / z`, the sum overflows the width in some of the tests so the
+//! dividend carries high bits.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::{word, words, Test};
+use zint::Contract;
+
+/// Opcode `PUSH1`.
+const PUSH1: u8 = 0x60;
+
+/// Opcode `SHR`.
+const SHR: u8 = 0x1c;
+
+#[test]
+fn i32_div_u() -> Result<()> {
+    let mut contract = Contract::from(Test::DIV_I32_U).pure().compile()?;
+
+    assert_eq!(contract.execute(words([7, 3, 2]))?.ret, word(5));
+    assert_eq!(
+        contract.execute(words([u32::MAX as u64, 3, 2]))?.ret,
+        word(1)
+    );
+    Ok(())
+}
+
+#[test]
+fn i32_div_s() -> Result<()> {
+    let mut contract = Contract::from(Test::DIV_I32_S).pure().compile()?;
+
+    assert_eq!(
+        contract.execute(words([-10i32 as u32 as u64, 2, 2]))?.ret,
+        word(-4i32 as u32 as u64)
+    );
+    assert_eq!(
+        contract
+            .execute(words([-10i32 as u32 as u64, 2, -2i32 as u32 as u64]))?
+            .ret,
+        word(4)
+    );
+    Ok(())
+}
+
+#[test]
+fn i64_div_u() -> Result<()> {
+    let mut contract = Contract::from(Test::DIV_I64_U).pure().compile()?;
+
+    assert_eq!(contract.execute(words([7, 3, 2]))?.ret, word(5));
+    assert_eq!(contract.execute(words([u64::MAX, 3, 2]))?.ret, word(1));
+    assert_eq!(contract.execute(words([u64::MAX, 1, 2]))?.ret, word(0));
+    Ok(())
+}
+
+#[test]
+fn i64_div_s() -> Result<()> {
+    let mut contract = Contract::from(Test::DIV_I64_S).pure().compile()?;
+
+    assert_eq!(
+        contract.execute(words([-10i64 as u64, 2, 2]))?.ret,
+        word(-4i64 as u64)
+    );
+    assert_eq!(
+        contract
+            .execute(words([-10i64 as u64, 2, -2i64 as u64]))?
+            .ret,
+        word(4)
+    );
+    assert_eq!(
+        contract.execute(words([i64::MAX as u64, 1, 2]))?.ret,
+        word((i64::MIN / 2) as u64)
+    );
+    Ok(())
+}
+
+#[test]
+fn pow2() -> Result<()> {
+    let mut contract = Contract::from(Test::DIV_POW2).pure().compile()?;
+    let bytecode = contract.runtime_bytecode();
+    assert!(bytecode.windows(3).any(|ops| ops == [PUSH1, 2, SHR]));
+
+    let info = contract.execute([word(7), word(3)])?;
+    assert_eq!(info.ret, word(2));
+
+    // the sum wraps to 2 in 32 bits.
+    let info = contract.execute([word(u32::MAX as u64), word(3)])?;
+    assert_eq!(info.ret, word(0));
+    Ok(())
+}
@@ -0,0 +1,100 @@
+//! codegen error tests
+#![cfg(test)]
+
+use filetests::Test;
+use zingen::Error;
+use zint::Contract;
+
+/// Compile the test and get the error of the code generation.
+fn codegen_error(wasm: impl AsRef<[u8]>) -> Error {
+    let error = Contract::from(wasm)
+        .pure()
+        .compile()
+        .err()
+        .expect("the test should not compile");
+
+    match error.downcast::<zinkc::Error>() {
+        Ok(zinkc::Error::Codegen(error)) => error,
+        error => panic!("unexpected error {error:?}"),
+    }
+}
+
+#[test]
+fn unsupported_operator() {
+    let error = codegen_error(Test::ERROR_INVALID_OPERATOR);
+    assert!(
+        matches!(&error, Error::UnsupportedOperator(op) if op == "sqrt"),
+        "{error}"
+    );
+}
+
+#[test]
+fn opcode_not_available() {
+    let error = codegen_error(Test::ERROR_INVALID_OPCODE);
+    assert!(
+        matches!(
+            &error,
+            Error::OpcodeNotAvailable { opcode, fork: "Shanghai" } if opcode == "TSTORE"
+        ),
+        "{error}"
+    );
+}
+
+#[test]
+fn atomic_operator() {
+    let error = codegen_error(Test::ERROR_INVALID_ATOMIC);
+    assert!(
+        matches!(&error, Error::UnsupportedOperator(op) if op == "i32.atomic.load"),
+        "{error}"
+    );
+}
+
+#[test]
+fn immutable_global() {
+    // the validator rejects it prior to the code generation.
+    let error = codegen_error(Test::ERROR_INVALID_GLOBAL_SET);
+    assert!(
+        matches!(&error, Error::BinaryReader(e) if e.message().contains("immutable")),
+        "{error}"
+    );
+}
+
+#[test]
+fn typed_select_mismatch() {
+    // the operands are checked against the declared result type.
+    let error = codegen_error(Test::ERROR_INVALID_TYPED_SELECT);
+    assert!(
+        matches!(&error, Error::BinaryReader(e) if e.message().contains("type mismatch")),
+        "{error}"
+    );
+}
+
+#[test]
+fn branch_depth() {
+    let error = codegen_error(Test::ERROR_INVALID_BR_DEPTH);
+    assert!(
+        matches!(&error, Error::BinaryReader(e) if e.message().contains("branch depth too large")),
+        "{error}"
+    );
+}
+
+#[test]
+fn width_mismatch() -> anyhow::Result<()> {
+    // the values are not checked by default.
+    Contract::from(Test::ERROR_WIDE_VALUE).pure().compile()?;
+
+    let error = Contract::from(Test::ERROR_WIDE_VALUE)
+        .pure()
+        .strict_width()
+        .compile()
+        .err()
+        .expect("the test should not compile");
+    assert!(
+        matches!(
+            error.downcast_ref::<zinkc::Error>(),
+            Some(zinkc::Error::Codegen(Error::WidthMismatch(op, 256))) if op == "I32Add"
+        ),
+        "{error}"
+    );
+    Ok(())
+}
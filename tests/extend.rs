@@ -0,0 +1,92 @@
+//! Tests for the integer extension instructions.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use opcodes::ShangHai as OpCode;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn i64_extend_i32_s() -> Result<()> {
+    let mut contract = Contract::from(Test::EXTEND_I32_S).pure().compile()?;
+
+    // -1 as i64 in 64 bits.
+    let info = contract.execute([u32::MAX])?;
+    assert_eq!(info.ret, u64::MAX.to_bytes32());
+
+    // the bits above 32 bits are dropped before extending.
+    let info = contract.execute([0x1_0000_0001u64])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn i64_extend_i32_u() -> Result<()> {
+    let mut contract = Contract::from(Test::EXTEND_I32_U).pure().compile()?;
+
+    let info = contract.execute([u32::MAX])?;
+    assert_eq!(info.ret, 4294967295u64.to_bytes32());
+
+    let info = contract.execute([u64::MAX])?;
+    assert_eq!(info.ret, 4294967295u64.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn i32_extend8_s_lt_s() -> Result<()> {
+    let mut contract = Contract::from(Test::EXTEND_I32_8_S_LT).pure().compile()?;
+
+    // the extended value is compared without sign-extending it again.
+    let bytecode = contract.runtime_bytecode();
+    let mut signextends = 0;
+    let mut pc = 0;
+    while let Some(byte) = bytecode.get(pc) {
+        if *byte == u8::from(OpCode::SIGNEXTEND) {
+            signextends += 1;
+        }
+
+        let push = byte.wrapping_sub(OpCode::PUSH1.into());
+        pc += if push < 32 { push as usize + 2 } else { 1 };
+    }
+    assert_eq!(signextends, 1);
+
+    for (value, lt) in [(0x80, 1), (0x7f, 0), (0x1ff, 1), (0x100, 0)] {
+        let info = contract.execute([value])?;
+        assert_eq!(info.ret, lt.to_bytes32(), "value {value:#x}");
+    }
+    Ok(())
+}
+
+#[test]
+fn i32_extend8_s_le_s() -> Result<()> {
+    let mut contract = Contract::from(Test::EXTEND_I32_8_S_LE).pure().compile()?;
+
+    for (value, le) in [(0x80, 1), (0x7f, 0), (0, 1), (0x100, 1), (0x1ff, 1)] {
+        let info = contract.execute([value])?;
+        assert_eq!(info.ret, le.to_bytes32(), "value {value:#x}");
+    }
+    Ok(())
+}
+
+#[test]
+fn i32_extend8_s_ge_s() -> Result<()> {
+    let mut contract = Contract::from(Test::EXTEND_I32_8_S_GE).pure().compile()?;
+
+    for (value, ge) in [(0x80, 0), (0x7f, 1), (0, 1), (0x100, 1), (0x1ff, 0)] {
+        let info = contract.execute([value])?;
+        assert_eq!(info.ret, ge.to_bytes32(), "value {value:#x}");
+    }
+    Ok(())
+}
+
+#[test]
+fn i32_extend8_s_eq() -> Result<()> {
+    let mut contract = Contract::from(Test::EXTEND_I32_8_S_EQ).pure().compile()?;
+
+    // the extended value is wrapped to 32 bits before `i32.eq`.
+    for (value, eq) in [(0x80, 1), (0x180, 1), (0x7f, 0)] {
+        let info = contract.execute([value])?;
+        assert_eq!(info.ret, eq.to_bytes32(), "value {value:#x}");
+    }
+    Ok(())
+}
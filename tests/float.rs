@@ -0,0 +1,156 @@
+//! float to integer conversion tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::{word, Test};
+use zint::{Contract, HaltReason};
+
+/// Execute the contract with the bit pattern, returns the result
+/// or the halt reason if it traps.
+fn convert(contract: &mut Contract, bits: u64) -> Result<Result<[u8; 32], HaltReason>> {
+    let info = contract.execute([word(bits)])?;
+    Ok(match info.halt {
+        Some(reason) => Err(reason),
+        None => Ok(info.ret.try_into().expect("result is not a word")),
+    })
+}
+
+#[test]
+fn i32_trunc_f32_s() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_I32_TRUNC_F32_S)
+        .pure()
+        .compile()?;
+    let mut convert = |f: f32| convert(&mut contract, f.to_bits() as u64);
+
+    assert_eq!(convert(42.9)?, Ok(word(42)));
+    assert_eq!(convert(-42.9)?, Ok(word(-42i32 as u32 as u64)));
+    assert_eq!(convert(-0.9)?, Ok(word(0)));
+    assert_eq!(convert(-2147483648.0)?, Ok(word(0x80000000)));
+    assert!(convert(f32::NAN)?.is_err());
+    assert!(convert(2147483648.0)?.is_err());
+    assert!(convert(f32::NEG_INFINITY)?.is_err());
+    Ok(())
+}
+
+#[test]
+fn i32_trunc_sat_f32_s() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_I32_TRUNC_SAT_F32_S)
+        .pure()
+        .compile()?;
+    let mut convert = |f: f32| convert(&mut contract, f.to_bits() as u64);
+
+    assert_eq!(convert(42.9)?, Ok(word(42)));
+    assert_eq!(convert(-42.9)?, Ok(word(-42i32 as u32 as u64)));
+    assert_eq!(convert(f32::NAN)?, Ok(word(0)));
+    assert_eq!(convert(3e9)?, Ok(word(i32::MAX as u64)));
+    assert_eq!(convert(-3e9)?, Ok(word(i32::MIN as u32 as u64)));
+    assert_eq!(convert(f32::INFINITY)?, Ok(word(i32::MAX as u64)));
+    assert_eq!(
+        convert(f32::NEG_INFINITY)?,
+        Ok(word(i32::MIN as u32 as u64))
+    );
+    Ok(())
+}
+
+#[test]
+fn i64_trunc_f64_u() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_I64_TRUNC_F64_U)
+        .pure()
+        .compile()?;
+    let mut convert = |f: f64| convert(&mut contract, f.to_bits());
+
+    assert_eq!(convert(1e10)?, Ok(word(10_000_000_000)));
+    assert_eq!(
+        convert(18446744073709549568.0)?,
+        Ok(word(18446744073709549568))
+    );
+    assert_eq!(convert(-0.5)?, Ok(word(0)));
+    assert!(convert(-1.0)?.is_err());
+    assert!(convert(f64::NAN)?.is_err());
+    assert!(convert(18446744073709551616.0)?.is_err());
+    Ok(())
+}
+
+#[test]
+fn i64_trunc_sat_f64_u() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_I64_TRUNC_SAT_F64_U)
+        .pure()
+        .compile()?;
+    let mut convert = |f: f64| convert(&mut contract, f.to_bits());
+
+    assert_eq!(convert(123.7)?, Ok(word(123)));
+    assert_eq!(convert(-1.0)?, Ok(word(0)));
+    assert_eq!(convert(f64::NAN)?, Ok(word(0)));
+    assert_eq!(convert(1e20)?, Ok(word(u64::MAX)));
+    assert_eq!(convert(f64::NEG_INFINITY)?, Ok(word(0)));
+    Ok(())
+}
+
+#[test]
+fn i64_trunc_sat_f64_s() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_I64_TRUNC_SAT_F64_S)
+        .pure()
+        .compile()?;
+    let mut convert = |f: f64| convert(&mut contract, f.to_bits());
+
+    assert_eq!(convert(-123.7)?, Ok(word(-123i64 as u64)));
+    assert_eq!(convert(f64::NAN)?, Ok(word(0)));
+    assert_eq!(convert(f64::INFINITY)?, Ok(word(i64::MAX as u64)));
+    assert_eq!(convert(f64::NEG_INFINITY)?, Ok(word(i64::MIN as u64)));
+    assert_eq!(convert(-1e19)?, Ok(word(i64::MIN as u64)));
+    Ok(())
+}
+
+#[test]
+fn f32_const() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_F32_CONST).pure().compile()?;
+
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.ret, word(-7i32 as u32 as u64));
+    Ok(())
+}
+
+#[test]
+fn f32_const_nan() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_F32_CONST_NAN).pure().compile()?;
+
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.ret, word(0x7fa00001));
+    Ok(())
+}
+
+#[test]
+fn f64_const_nan() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_F64_CONST_NAN).pure().compile()?;
+
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.ret, word(0xfff4000000000001));
+    Ok(())
+}
+
+#[test]
+fn local() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_LOCAL).pure().compile()?;
+
+    for bits in [
+        (-0.0f64).to_bits(),
+        1.5f64.to_bits(),
+        f64::NEG_INFINITY.to_bits(),
+        0xfff4000000000001,
+    ] {
+        let info = contract.execute([word(bits)])?;
+        assert_eq!(info.ret, word(bits));
+    }
+    Ok(())
+}
+
+#[test]
+fn local_call() -> Result<()> {
+    let mut contract = Contract::from(Test::FLOAT_CALL).pure().compile()?;
+
+    for bits in [(-0.0f32).to_bits(), 1.5f32.to_bits(), 0x7fa00001] {
+        let info = contract.execute([word(bits as u64)])?;
+        assert_eq!(info.ret, word(bits as u64));
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+//! global tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{keccak256, Bytes32, Contract};
+
+#[test]
+fn immutable() -> Result<()> {
+    let mut contract = Contract::from(Test::GLOBAL_IMMUTABLE).pure().compile()?;
+
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+
+    Ok(())
+}
+
+#[test]
+fn mutable() -> Result<()> {
+    let mut contract = Contract::from(Test::GLOBAL_MUTABLE).pure().compile()?;
+    let mut evm = contract.deploy()?.commit(true);
+
+    let key = keccak256(b"zink.global.0");
+    assert_eq!(evm.storage(contract.address, key)?, 42.to_bytes32());
+
+    let info = evm
+        .calldata(&contract.encode([1])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, 43.to_bytes32());
+
+    let info = evm
+        .calldata(&contract.encode([2])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, 45.to_bytes32());
+    assert_eq!(evm.storage(contract.address, key)?, 45.to_bytes32());
+
+    Ok(())
+}
+
+#[test]
+fn imported() -> Result<()> {
+    let mut contract = Contract::from(Test::GLOBAL_IMPORTED).pure().compile()?;
+    let mut evm = contract.deploy()?.commit(true);
+
+    // the defined global follows the imported one.
+    let key = keccak256(b"zink.global.1");
+    assert_eq!(evm.storage(contract.address, key)?, 42.to_bytes32());
+
+    let info = evm
+        .calldata(&contract.encode([1])?)
+        .call(contract.address)?;
+    assert_eq!(info.ret, 43.to_bytes32());
+    assert_eq!(evm.storage(contract.address, key)?, 43.to_bytes32());
+
+    Ok(())
+}
+
+#[test]
+fn float() -> Result<()> {
+    let mut contract = Contract::from(Test::GLOBAL_FLOAT).pure().compile()?;
+
+    let word = |bits: u64| {
+        let mut word = [0; 32];
+        word[24..].copy_from_slice(&bits.to_be_bytes());
+        word
+    };
+
+    let info = contract.execute([0])?;
+    assert_eq!(info.ret, word((-1.25f64).to_bits()));
+
+    let info = contract.execute([1])?;
+    assert_eq!(info.ret, word(0x7fa00001));
+    Ok(())
+}
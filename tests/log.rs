@@ -85,3 +85,86 @@ fn log4() -> Result<()> {
     assert_eq!(topics[3].to_vec(), b"pong".to_vec().to_bytes32());
     Ok(())
 }
+
+#[test]
+fn transfer() -> Result<()> {
+    use zabi::{decode_log, Event, EventParam, Param, Value};
+
+    let param = |name: &str, ty, indexed| EventParam {
+        name: name.into(),
+        ty,
+        indexed,
+    };
+    let event = Event {
+        name: "Transfer".into(),
+        inputs: vec![
+            param("from", Param::Address, true),
+            param("to", Param::Address, true),
+            param("value", Param::UInt256, false),
+        ],
+        anonymous: false,
+    };
+
+    let mut contract = Contract::from(Test::LOG_TRANSFER).pure().compile()?;
+    let info = contract.execute::<()>([])?;
+    let topics = info.logs[0]
+        .topics()
+        .iter()
+        .map(|topic| topic.0)
+        .collect::<Vec<_>>();
+
+    assert_eq!(topics[0], event.topic());
+    assert_eq!(
+        decode_log(&event, &topics, &info.logs[0].data.data),
+        Some(vec![
+            Value::Address([1; 20]),
+            Value::Address([2; 20]),
+            Value::Uint(42.to_bytes32()),
+        ])
+    );
+    Ok(())
+}
+
+/// `Transfer(address,address,uint256)` emitted by the transfer fixture.
+struct Transfer {
+    from: [u8; 20],
+    to: [u8; 20],
+    value: u64,
+}
+
+impl zint::Event for Transfer {
+    fn topics(&self) -> Vec<[u8; 32]> {
+        vec![
+            zint::keccak256(b"Transfer(address,address,uint256)"),
+            self.from.to_bytes32(),
+            self.to.to_bytes32(),
+        ]
+    }
+
+    fn data(&self) -> Vec<u8> {
+        self.value.to_bytes32().to_vec()
+    }
+}
+
+#[test]
+fn expect_emit() -> Result<()> {
+    let mut contract = Contract::from(Test::LOG_TRANSFER).pure().compile()?;
+    let transfer = |value| Transfer {
+        from: [1; 20],
+        to: [2; 20],
+        value,
+    };
+
+    contract.expect_emit(transfer(42));
+    contract.execute::<()>([])?;
+
+    contract.expect_emit(transfer(41));
+    assert!(contract.execute::<()>([]).is_err());
+
+    // the expectations are consumed by the execution.
+    contract.execute::<()>([])?;
+
+    contract.expect_emit_topics(transfer(41));
+    contract.execute::<()>([])?;
+    Ok(())
+}
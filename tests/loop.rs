@@ -25,3 +25,106 @@ fn as_br_if() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn as_br() -> Result<()> {
+    let mut contract = Contract::from(Test::LOOP_AS_BR).pure().compile()?;
+
+    let info = contract.execute([3])?;
+    assert_eq!(info.ret, 3.to_bytes32());
+
+    let info = contract.execute([42])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn br_if_value() -> Result<()> {
+    let mut contract = Contract::from(Test::LOOP_BR_IF_VALUE).pure().compile()?;
+
+    let info = contract.execute([1])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+
+    let info = contract.execute([5])?;
+    assert_eq!(info.ret, 5.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn sum() -> Result<()> {
+    let mut contract = Contract::from(Test::LOOP_SUM).pure().compile()?;
+    let info = contract.execute([100])?;
+    assert_eq!(info.decode::<u32>()?, 4950);
+
+    // the induction variable is kept in memory
+    let mut baseline = Contract::from(Test::LOOP_SUM)
+        .pure()
+        .no_loop_induction()
+        .compile()?;
+    let expected = baseline.execute([100])?;
+    assert_eq!(info.ret, expected.ret);
+    assert!(info.gas < expected.gas);
+
+    // the loop exits before the first iteration.
+    let info = contract.execute([0])?;
+    assert_eq!(info.decode::<u32>()?, 0);
+    let info = baseline.execute([0])?;
+    assert_eq!(info.decode::<u32>()?, 0);
+    Ok(())
+}
+
+#[test]
+fn sum_le() -> Result<()> {
+    let mut contract = Contract::from(Test::LOOP_SUM_LE).pure().compile()?;
+
+    let info = contract.execute([0])?;
+    assert_eq!(info.decode::<u32>()?, 0);
+
+    let info = contract.execute([100])?;
+    assert_eq!(info.decode::<u32>()?, 5050);
+    Ok(())
+}
+
+#[test]
+fn gas_limit() -> Result<()> {
+    let mut contract = Contract::from(Test::LOOP_SUM).pure().compile()?;
+    contract.with_gas_limit(30_000);
+
+    let info = contract.execute([10])?;
+    assert_eq!(info.decode::<u32>()?, 45);
+
+    let info = contract.execute([1000])?;
+    assert!(info.is_out_of_gas());
+    assert!(info.revert.is_none());
+    assert_eq!(info.gas, 30_000);
+    Ok(())
+}
+
+#[test]
+fn source_map() -> Result<()> {
+    let contract = Contract::from(Test::LOOP_SUM).pure().compile()?;
+    let source_map = contract.source_map();
+
+    // the instructions of the function including the `end`s
+    assert_eq!(source_map.len(), 19);
+    for pair in source_map.windows(2) {
+        assert!(pair[0].0 <= pair[1].0, "pc is not monotonic: {pair:?}");
+        assert!(pair[0].1 < pair[1].1, "offset is not monotonic: {pair:?}");
+    }
+
+    let (pc, _) = source_map.last().expect("empty source map");
+    assert!(*pc < contract.runtime_bytecode().len());
+    Ok(())
+}
+
+#[test]
+fn counter() -> Result<()> {
+    let mut contract = Contract::from(Test::LOOP_COUNTER).pure().compile()?;
+
+    let info = contract.execute([1])?;
+    assert_eq!(info.ret, 8.to_bytes32());
+
+    let info = contract.execute([5])?;
+    assert_eq!(info.ret, 12.to_bytes32());
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! memory tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn invalid_multiple() {
+    let error = Contract::from(Test::MEMORY_INVALID_MULTIPLE)
+        .pure()
+        .compile()
+        .err()
+        .expect("multiple memories should not compile")
+        .to_string();
+
+    assert!(error.contains("Multiple memories"), "{error}");
+}
+
+#[test]
+fn offset() -> Result<()> {
+    let mut contract = Contract::from(Test::MEMORY_OFFSET).pure().compile()?;
+
+    let info = contract.execute([42])?;
+    assert!(info.halt.is_none(), "{:?}", info.halt);
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn endianness() -> Result<()> {
+    let mut contract = Contract::from(Test::MEMORY_ENDIANNESS).pure().compile()?;
+
+    for (index, byte) in [4, 3, 2, 1].into_iter().enumerate() {
+        let info = contract.execute([index as u32])?;
+        assert!(info.halt.is_none(), "{:?}", info.halt);
+        assert_eq!(info.ret, byte.to_bytes32(), "byte {index}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn grow() -> Result<()> {
+    let mut contract = Contract::from(Test::MEMORY_GROW).pure().compile()?;
+
+    let info = contract.execute([2])?;
+    assert!(info.halt.is_none(), "{:?}", info.halt);
+    assert_eq!(info.ret, 1.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn size() -> Result<()> {
+    let mut contract = Contract::from(Test::MEMORY_SIZE).pure().compile()?;
+
+    for (delta, pages) in [(0, 1), (2, 3)] {
+        let info = contract.execute([delta])?;
+        assert!(info.halt.is_none(), "{:?}", info.halt);
+        assert_eq!(info.ret, pages.to_bytes32(), "delta {delta}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn alloc() -> Result<()> {
+    let mut contract = Contract::from(Test::MEMORY_ALLOC).pure().compile()?;
+
+    for (delta, pages) in [(0, 1), (1, 2), (2, 3)] {
+        let info = contract.execute([delta])?;
+        assert!(info.halt.is_none(), "{:?}", info.halt);
+        assert_eq!(info.ret, pages.to_bytes32(), "delta {delta}");
+    }
+
+    Ok(())
+}
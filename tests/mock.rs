@@ -0,0 +1,48 @@
+//! Tests for the mocked and the expected calls.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{keccak256, Bytes32, Contract};
+
+#[test]
+fn mock_call() -> Result<()> {
+    let mut contract = Contract::from(Test::MOCK_ORACLE).pure().compile()?;
+    let mut oracle = [0; 20];
+    oracle[18..].copy_from_slice(&[0x12, 0x34]);
+    let selector = keccak256(b"latestAnswer()")[..4].try_into()?;
+
+    contract.mock_call(oracle, selector, 150.to_bytes32());
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+
+    contract.mock_call(oracle, selector, 50.to_bytes32());
+    let info = contract.execute::<()>([])?;
+    assert_eq!(info.ret, 2.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn expect_call() -> Result<()> {
+    let mut contract = Contract::from(Test::MOCK_FORWARD).pure().compile()?;
+    let mut dependency = [0; 20];
+    dependency[18..].copy_from_slice(&[0x12, 0x34]);
+
+    let mut calldata = keccak256(b"setValue(uint256)")[..4].to_vec();
+    calldata.extend(42.to_bytes32());
+    contract.expect_call(dependency, calldata.clone());
+    let info = contract.execute([42])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+
+    calldata[35] = 43;
+    let error = contract
+        .expect_call(dependency, calldata)
+        .execute([42])
+        .err()
+        .expect("the calldata should not match");
+    assert!(
+        error.to_string().contains("expected call not made"),
+        "{error}"
+    );
+    Ok(())
+}
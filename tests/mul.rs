@@ -0,0 +1,23 @@
+//! integer multiplication tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+/// Opcode `PUSH1`.
+const PUSH1: u8 = 0x60;
+
+/// Opcode `SHL`.
+const SHL: u8 = 0x1b;
+
+#[test]
+fn pow2() -> Result<()> {
+    let mut contract = Contract::from(Test::MUL_POW2).pure().compile()?;
+    let bytecode = contract.runtime_bytecode();
+    assert!(bytecode.windows(3).any(|ops| ops == [PUSH1, 3, SHL]));
+
+    let info = contract.execute([5])?;
+    assert_eq!(info.ret, 40.to_bytes32());
+    Ok(())
+}
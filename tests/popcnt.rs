@@ -0,0 +1,42 @@
+//! popcnt tests for the zink compiler.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+/// Pseudo-random inputs with the edge cases.
+fn inputs() -> Vec<u64> {
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let mut values = vec![0, 1, u32::MAX as u64, u64::MAX];
+    for _ in 0..16 {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        values.push(seed);
+    }
+
+    values
+}
+
+#[test]
+fn i32() -> Result<()> {
+    let mut contract = Contract::from(Test::I32POPCNT_PARAMS).pure().compile()?;
+    for value in inputs().into_iter().map(|v| v as u32) {
+        let info = contract.execute([value])?;
+        assert_eq!(info.ret, value.count_ones().to_bytes32(), "{value:#x}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn i64() -> Result<()> {
+    let mut contract = Contract::from(Test::I64POPCNT_PARAMS).pure().compile()?;
+    for value in inputs() {
+        let info = contract.execute([value])?;
+        assert_eq!(info.ret, value.count_ones().to_bytes32(), "{value:#x}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+//! Tests for the optimization level of the input WASM.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use opcodes::ShangHai as OpCode;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn overflow_checks() -> Result<()> {
+    let mut debug = Contract::from(Test::PROFILE_DEBUG).pure().compile()?;
+    let mut release = Contract::from(Test::PROFILE_RELEASE).pure().compile()?;
+
+    let bytecode = release.runtime_bytecode();
+    assert!(bytecode.len() < debug.runtime_bytecode().len());
+    for op in [OpCode::JUMPI, OpCode::INVALID] {
+        assert!(
+            !bytecode.contains(&op.into()),
+            "release bytecode contains {op:?}"
+        );
+    }
+
+    for contract in [&mut debug, &mut release] {
+        let info = contract.execute([1, 2])?;
+        assert_eq!(info.ret, 3.to_bytes32());
+    }
+    Ok(())
+}
@@ -0,0 +1,26 @@
+//! pure function tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn add() -> Result<()> {
+    let mut contract = Contract::from(Test::PURE_ADD).compile()?;
+
+    let info = contract.execute([b"add(int32)".to_vec(), 41.to_bytes32().to_vec()])?;
+    assert_eq!(info.ret, 42.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn invalid_sload() {
+    let error = Contract::from(Test::PURE_INVALID_SLOAD)
+        .compile()
+        .err()
+        .expect("pure function reading storage should not compile")
+        .to_string();
+
+    assert!(error.contains("get") && error.contains("SLOAD"), "{error}");
+}
@@ -2,7 +2,6 @@ use anyhow::Result;
 use filetests::Test;
 use zint::{Bytes32, Contract};
 
-#[ignore]
 #[test]
 fn fibonacci() -> Result<()> {
     let mut contract = Contract::from(Test::RECURSION_FIBONACCI).pure().compile()?;
@@ -34,3 +33,30 @@ fn fibonacci() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn factorial() -> Result<()> {
+    let mut contract = Contract::from(Test::RECURSION_FACTORIAL).pure().compile()?;
+
+    for (n, expected) in [(0, 1), (1, 1), (2, 2), (3, 6), (5, 120)] {
+        let info = contract.execute([n])?;
+        assert_eq!(info.halt, None);
+        assert_eq!(expected.to_bytes32().to_vec(), info.ret, "factorial({n})");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn mutual() -> Result<()> {
+    let mut contract = Contract::from(Test::RECURSION_MUTUAL).pure().compile()?;
+
+    // a(n) = b(n - 1) + n, b(n) = a(n - 1) + 2n
+    for (n, expected) in [(0, 0), (1, 1), (2, 4), (3, 8), (4, 14), (5, 21)] {
+        let info = contract.execute([n])?;
+        assert_eq!(info.halt, None);
+        assert_eq!(expected.to_bytes32().to_vec(), info.ret, "mutual({n})");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+//! Tests for the reference instructions.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+#[test]
+fn is_null() -> Result<()> {
+    let mut contract = Contract::from(Test::REF_NULL).pure().compile()?;
+
+    for (flag, null) in [(0, true), (1, false)] {
+        let info = contract.execute([flag])?;
+        assert_eq!(info.ret, null.to_bytes32(), "flag {flag}");
+    }
+    Ok(())
+}
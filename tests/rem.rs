@@ -0,0 +1,81 @@
+//! integer remainder tests
+//!
+//! The fixtures of the signed and the unsigned instructions execute
+//! `(x + y) % z`, the sum overflows the width in some of the tests so the
+//! dividend carries high bits.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::{word, words, Test};
+use zint::Contract;
+
+#[test]
+fn i32_rem_u() -> Result<()> {
+    let mut contract = Contract::from(Test::REM_I32_U).pure().compile()?;
+
+    assert_eq!(contract.execute(words([7, 3, 4]))?.ret, word(2));
+    // the sum wraps to 2 in 32 bits.
+    assert_eq!(
+        contract.execute(words([u32::MAX as u64, 3, 4]))?.ret,
+        word(2)
+    );
+    assert_eq!(
+        contract.execute(words([-7i32 as u32 as u64, 0, 3]))?.ret,
+        word(0)
+    );
+    Ok(())
+}
+
+#[test]
+fn i32_rem_s() -> Result<()> {
+    let mut contract = Contract::from(Test::REM_I32_S).pure().compile()?;
+    let neg = |value: i32| value as u32 as u64;
+
+    assert_eq!(contract.execute(words([7, 0, 3]))?.ret, word(1));
+    assert_eq!(contract.execute(words([neg(-7), 0, 3]))?.ret, word(neg(-1)));
+    assert_eq!(contract.execute(words([7, 0, neg(-3)]))?.ret, word(1));
+    assert_eq!(
+        contract.execute(words([neg(-7), 0, neg(-3)]))?.ret,
+        word(neg(-1))
+    );
+    // the sum wraps to -7 in 32 bits.
+    assert_eq!(
+        contract.execute(words([neg(-1), neg(-6), 3]))?.ret,
+        word(neg(-1))
+    );
+    Ok(())
+}
+
+#[test]
+fn i64_rem_u() -> Result<()> {
+    let mut contract = Contract::from(Test::REM_I64_U).pure().compile()?;
+
+    assert_eq!(contract.execute(words([7, 3, 4]))?.ret, word(2));
+    assert_eq!(contract.execute(words([u64::MAX, 3, 4]))?.ret, word(2));
+    assert_eq!(contract.execute(words([-7i64 as u64, 0, 3]))?.ret, word(0));
+    Ok(())
+}
+
+#[test]
+fn i64_rem_s() -> Result<()> {
+    let mut contract = Contract::from(Test::REM_I64_S).pure().compile()?;
+    let neg = |value: i64| value as u64;
+
+    assert_eq!(contract.execute(words([7, 0, 3]))?.ret, word(1));
+    assert_eq!(contract.execute(words([neg(-7), 0, 3]))?.ret, word(neg(-1)));
+    assert_eq!(contract.execute(words([7, 0, neg(-3)]))?.ret, word(1));
+    assert_eq!(
+        contract.execute(words([neg(-7), 0, neg(-3)]))?.ret,
+        word(neg(-1))
+    );
+    assert_eq!(
+        contract.execute(words([neg(-1), neg(-6), 3]))?.ret,
+        word(neg(-1))
+    );
+    // the remainder of the overflowing division is 0 in WASM.
+    assert_eq!(
+        contract.execute(words([neg(i64::MIN), 0, neg(-1)]))?.ret,
+        word(0)
+    );
+    Ok(())
+}
@@ -0,0 +1,144 @@
+//! revert tests for the zink compiler.
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use opcodes::ShangHai as OpCode;
+use zint::{Bytes32, Contract, EVM};
+
+#[test]
+fn require() -> Result<()> {
+    let mut contract = Contract::from(Test::REVERT_REQUIRE).pure().compile()?;
+
+    let info = contract.execute([1])?;
+    assert!(info.revert.is_none());
+
+    let info = contract.execute([0])?;
+    assert_eq!(info.revert, Some("require works fine".into()));
+    Ok(())
+}
+
+#[test]
+fn gas_snapshot() -> Result<()> {
+    let mut contract = Contract::from(Test::REVERT_REQUIRE).pure().compile()?;
+
+    let info = contract.gas_snapshot("revert_require", [0])?;
+    assert_eq!(info.revert, Some("require works fine".into()));
+    Ok(())
+}
+
+#[test]
+fn call_raw() -> Result<()> {
+    let mut contract = Contract::from(Test::REVERT_REQUIRE).pure().compile()?;
+
+    let (success, output) = contract.call_raw(&1.to_bytes32())?;
+    assert!(success);
+    assert!(output.is_empty());
+
+    let message = b"require works fine";
+    let mut padded = message.to_vec();
+    padded.resize(32, 0);
+    let error = [
+        zint::keccak256(b"Error(string)")[..4].to_vec(),
+        0x20.to_bytes32().to_vec(),
+        (message.len() as u8).to_bytes32().to_vec(),
+        padded,
+    ]
+    .concat();
+
+    let (success, output) = contract.call_raw(&0.to_bytes32())?;
+    assert!(!success);
+    assert_eq!(output, error);
+    Ok(())
+}
+
+#[test]
+fn assert() -> Result<()> {
+    let mut contract = Contract::from(Test::REVERT_ASSERT).pure().compile()?;
+
+    let info = contract.execute([1])?;
+    assert!(info.revert.is_none());
+
+    let info = contract.execute([0])?;
+    let data = [
+        zint::keccak256(b"Panic(uint256)")[..4].to_vec(),
+        1.to_bytes32().to_vec(),
+    ]
+    .concat();
+    assert_eq!(info.revert, Some(String::from_utf8_lossy(&data).into()));
+    Ok(())
+}
+
+#[test]
+fn nested() -> Result<()> {
+    let mut contract = Contract::from(Test::REVERT_IN_LOOP).pure().compile()?;
+
+    for limit in [0, 5, 9] {
+        let info = contract.execute([limit])?;
+        assert_eq!(info.revert, Some("limit reached".into()), "limit {limit}");
+    }
+
+    // the revert path is never taken.
+    let info = contract.execute([10])?;
+    assert!(info.revert.is_none());
+    assert_eq!(info.ret, 10.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn trace() -> Result<()> {
+    let mut contract = Contract::from(Test::REVERT_REQUIRE).pure().compile()?;
+
+    let (info, steps) = contract.execute_traced([0])?;
+    assert_eq!(info.revert, Some("require works fine".into()));
+    assert_eq!(steps.first().map(|step| step.pc), Some(0));
+    assert_eq!(
+        steps.last().map(|step| step.opcode),
+        Some(OpCode::REVERT.into())
+    );
+    assert!(steps.windows(2).all(|w| w[0].gas >= w[1].gas));
+    Ok(())
+}
+
+#[test]
+fn trace_label() -> Result<()> {
+    let mut contract = Contract::from(Test::REVERT_REQUIRE).pure().compile()?;
+    let alice = [0x42; 20];
+    contract.label(alice, "alice");
+
+    let (info, steps) = contract.execute_traced([alice])?;
+    assert!(info.revert.is_none());
+    assert!(steps
+        .iter()
+        .any(|step| step.line(&contract.labels).ends_with("top: alice")));
+    Ok(())
+}
+
+#[test]
+fn bubble() -> Result<()> {
+    let inner = Contract::from(Test::REVERT_CUSTOM).pure().compile()?;
+    let outer = Contract::from(Test::REVERT_BUBBLE).pure().compile()?;
+
+    let mut child = [0; 20];
+    child[18..].copy_from_slice(&[0x12, 0x34]);
+    let parent = [0x42; 20];
+
+    let mut evm = EVM::default()
+        .contract_at(child, &inner.runtime_bytecode())
+        .contract_at(parent, &outer.runtime_bytecode());
+
+    let error = [
+        zint::keccak256(b"InsufficientBalance(uint256)")[..4].to_vec(),
+        42.to_bytes32().to_vec(),
+    ]
+    .concat();
+
+    let (success, output) = evm.call_raw(child)?;
+    assert!(!success);
+    assert_eq!(output, error);
+
+    let (success, output) = evm.call_raw(parent)?;
+    assert!(!success);
+    assert_eq!(output, error);
+    Ok(())
+}
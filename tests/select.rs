@@ -14,3 +14,14 @@ fn params() -> Result<()> {
     assert_eq!(info.ret, [2.to_bytes32()].concat());
     Ok(())
 }
+
+#[test]
+fn typed() -> Result<()> {
+    let mut contract = Contract::from(Test::SELECT_TYPED).pure().compile()?;
+    let info = contract.execute([1, 2])?;
+    assert_eq!(info.ret, [1.to_bytes32()].concat());
+
+    let info = contract.execute([2, 1])?;
+    assert_eq!(info.ret, [1.to_bytes32()].concat());
+    Ok(())
+}
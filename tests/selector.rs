@@ -0,0 +1,32 @@
+//! selector tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract, EVM};
+
+#[test]
+fn overridden() -> Result<()> {
+    let mut contract = Contract::from(Test::SELECTOR_OVERRIDDEN).compile()?;
+
+    let info = EVM::interp(&contract.runtime_bytecode(), &[0x12, 0x34, 0x56, 0x78])?;
+    assert_eq!(info.ret, 1.to_bytes32());
+
+    let info = contract.execute([b"two()".to_vec()])?;
+    assert_eq!(info.ret, 2.to_bytes32());
+    Ok(())
+}
+
+#[test]
+fn invalid_collision() {
+    let error = Contract::from(Test::SELECTOR_INVALID_COLLISION)
+        .compile()
+        .err()
+        .expect("colliding selectors should not compile")
+        .to_string();
+
+    assert!(
+        error.contains("5fdf05d7") && error.contains("collides"),
+        "{error}"
+    );
+}
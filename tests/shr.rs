@@ -0,0 +1,62 @@
+//! right shift tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::{word, Test};
+use zint::Contract;
+
+#[test]
+fn i32_shr_s() -> Result<()> {
+    let mut contract = Contract::from(Test::SHR_I32_S).pure().compile()?;
+
+    let info = contract.execute([word(-8i32 as u32 as u64), word(1)])?;
+    assert_eq!(info.ret, word(-4i32 as u32 as u64));
+
+    let info = contract.execute([word(8), word(1)])?;
+    assert_eq!(info.ret, word(4));
+
+    // shift count is taken modulo 32
+    let info = contract.execute([word(-8i32 as u32 as u64), word(33)])?;
+    assert_eq!(info.ret, word(-4i32 as u32 as u64));
+
+    let info = contract.execute([word(i32::MIN as u32 as u64), word(31)])?;
+    assert_eq!(info.ret, word(u32::MAX as u64));
+    Ok(())
+}
+
+#[test]
+fn i32_shr_u() -> Result<()> {
+    let mut contract = Contract::from(Test::SHR_I32_U).pure().compile()?;
+
+    let info = contract.execute([word(-8i32 as u32 as u64), word(1)])?;
+    assert_eq!(info.ret, word(0x7ffffffc));
+
+    let info = contract.execute([word(8), word(33)])?;
+    assert_eq!(info.ret, word(4));
+    Ok(())
+}
+
+#[test]
+fn i32_shr_u_carry() -> Result<()> {
+    let mut contract = Contract::from(Test::SHR_I32_U_CARRY).pure().compile()?;
+
+    // the carry of the addition over 32 bits is not shifted in.
+    let info = contract.execute([word(0xffffffff), word(1)])?;
+    assert_eq!(info.ret, word(0));
+
+    let info = contract.execute([word(0xffffffff), word(3)])?;
+    assert_eq!(info.ret, word(1));
+    Ok(())
+}
+
+#[test]
+fn i64_shr_s() -> Result<()> {
+    let mut contract = Contract::from(Test::SHR_I64_S).pure().compile()?;
+
+    let info = contract.execute([word(-8i64 as u64), word(1)])?;
+    assert_eq!(info.ret, word(-4i64 as u64));
+
+    let info = contract.execute([word(i64::MIN as u64), word(63)])?;
+    assert_eq!(info.ret, word(u64::MAX));
+    Ok(())
+}
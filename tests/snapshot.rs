@@ -0,0 +1,37 @@
+//! gas snapshot tests
+#![cfg(test)]
+
+use anyhow::Result;
+use std::{env, fs};
+use zint::snapshot::{self, GAS_SNAPSHOT};
+
+#[test]
+fn check_file() -> Result<()> {
+    let dir = env::temp_dir().join(format!("zint-gas-snapshot-{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(GAS_SNAPSHOT);
+    fs::write(&path, "revert (gas: 10000)\n")?;
+
+    // within the tolerance of 1%.
+    snapshot::check_file(&path, "revert", 10_100, false)?;
+    snapshot::check_file(&path, "revert", 9_900, false)?;
+
+    // beyond the tolerance, the snapshot is kept.
+    assert!(snapshot::check_file(&path, "revert", 10_101, false).is_err());
+    assert!(snapshot::check_file(&path, "revert", 9_899, false).is_err());
+    assert_eq!(fs::read_to_string(&path)?, "revert (gas: 10000)\n");
+
+    // updated with `ZINT_UPDATE_GAS_SNAPSHOT`.
+    snapshot::check_file(&path, "revert", 10_101, true)?;
+    assert_eq!(fs::read_to_string(&path)?, "revert (gas: 10101)\n");
+
+    // the missing snapshot is recorded.
+    snapshot::check_file(&path, "require", 21_205, false)?;
+    assert_eq!(
+        fs::read_to_string(&path)?,
+        "require (gas: 21205)\nrevert (gas: 10101)\n"
+    );
+
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
@@ -0,0 +1,27 @@
+//! Function terminator tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use opcodes::ShangHai as OpCode;
+use zint::{Contract, U256};
+
+#[test]
+fn fallthrough() -> Result<()> {
+    let mut contract = Contract::from(Test::STOP_FALLTHROUGH).pure().compile()?;
+
+    let value = 42;
+    let info = contract.execute([value])?;
+    assert!(info.ret.is_empty());
+    assert_eq!(info.halt, None);
+    assert_eq!(info.revert, None);
+    assert_eq!(info.storage.get(&U256::ZERO), Some(&U256::from(value)));
+
+    // The main function halts before the code of the internal function.
+    let (_, steps) = contract.execute_traced([value])?;
+    assert_eq!(
+        steps.last().map(|step| step.opcode),
+        Some(OpCode::STOP.into())
+    );
+    Ok(())
+}
@@ -3,7 +3,7 @@
 
 use anyhow::Result;
 use filetests::Test;
-use zint::{keccak256, Bytes32, Contract, EVM, U256};
+use zint::{keccak256, Bytes32, Contract, StorageOp, CONTRACT, EVM, U256};
 
 #[test]
 fn store() -> Result<()> {
@@ -40,6 +40,24 @@ fn basic() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn trace_storage() -> Result<()> {
+    let mut contract = Contract::from(Test::STORAGE_BASIC).pure().compile()?;
+    contract.trace_storage();
+
+    let info = contract.execute([42])?;
+    let op = |store, old: u64, new: u64| StorageOp {
+        address: CONTRACT,
+        store,
+        slot: U256::ZERO,
+        old: U256::from(old),
+        new: U256::from(new),
+    };
+    assert_eq!(info.storage_ops, [op(true, 0, 42), op(false, 42, 42)]);
+
+    Ok(())
+}
+
 #[test]
 fn mapping() -> Result<()> {
     use opcodes::ShangHai;
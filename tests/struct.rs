@@ -0,0 +1,38 @@
+//! struct argument tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Bytes32, Contract};
+
+/// Encode the signature and the words as the inputs.
+fn inputs(signature: &str, words: &[u64]) -> Vec<Vec<u8>> {
+    [signature.as_bytes().to_vec()]
+        .into_iter()
+        .chain(words.iter().map(|word| word.to_bytes32().to_vec()))
+        .collect()
+}
+
+#[test]
+fn field() -> Result<()> {
+    let mut contract = Contract::from(Test::STRUCT_FIELD).compile()?;
+
+    // the fields are laid out as `#[repr(C)]` in rust, the second
+    // field is at the offset 8 after the padding of the first one.
+    let info = contract.execute(inputs("second((uint32,uint64))", &[1, 2]))?;
+    assert_eq!(info.ret, 2.to_bytes32());
+
+    // the nested struct is aligned inside of the outer one, the second
+    // field of the second field is at the offset 16, and the head of the
+    // following argument is after the struct.
+    let info = contract.execute(inputs(
+        "nested((uint64,(uint32,uint64)),uint64)",
+        &[1, 2, 3, 4],
+    ))?;
+    assert_eq!(info.ret, 7.to_bytes32());
+
+    // the structs of other types are copied as the words of the abi.
+    let info = contract.execute(inputs("words((uint256,uint256))", &[1, 2]))?;
+    assert_eq!(info.ret, 2.to_bytes32());
+    Ok(())
+}
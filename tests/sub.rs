@@ -1,14 +1,33 @@
-//! Addition tests for the zink compiler.
+//! Subtraction tests for the zink compiler.
 #![cfg(test)]
 
 use anyhow::Result;
 use filetests::{impl_tests, Test};
 use zint::{Bytes32, Contract};
 
+/// Opcode `SWAP1`.
+const SWAP1: u8 = 0x90;
+
+/// Opcode `SUB`.
+const SUB: u8 = 0x03;
+
+/// If the operands of `SUB` are swapped at runtime.
+fn swapped(bytecode: &[u8]) -> bool {
+    bytecode.windows(2).any(|ops| ops == [SWAP1, SUB])
+}
+
 fn params(module: &str) -> Result<()> {
     let mut contract = Contract::from(Test::load(module, "params")?.wasm)
         .pure()
         .compile()?;
+    assert!(!swapped(&contract.runtime_bytecode()));
+
+    // the entries of the reordered operands follow their code, the
+    // second `local.get` comes first.
+    let source_map = contract.source_map();
+    assert!(source_map.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+    assert!(source_map[0].1 > source_map[1].1);
+
     let info = contract.execute([2, 1])?;
 
     assert_eq!(info.ret, [1.to_bytes32()].concat());
@@ -25,7 +44,17 @@ fn locals(module: &str) -> Result<()> {
     Ok(())
 }
 
+fn nested(module: &str) -> Result<()> {
+    let mut contract = Contract::from(Test::load(module, "nested")?.wasm)
+        .pure()
+        .compile()?;
+    let info = contract.execute([10, 3, 2])?;
+
+    assert_eq!(info.ret, [5.to_bytes32()].concat());
+    Ok(())
+}
+
 impl_tests! {
-    tests: [params, locals],
+    tests: [params, locals, nested],
     modules: ["i32sub", "i64sub"]
 }
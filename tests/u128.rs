@@ -0,0 +1,40 @@
+//! u128 tests
+#![cfg(test)]
+
+use anyhow::Result;
+use filetests::Test;
+use zint::{Contract, U256};
+
+/// Big-endian word of the u128.
+fn word(value: u128) -> [u8; 32] {
+    U256::from(value).to_be_bytes()
+}
+
+#[test]
+fn add() -> Result<()> {
+    let mut contract = Contract::from(Test::U128_ADD).pure().compile()?;
+
+    let (a, b) = ((1 << 100) + 5, (1 << 127) + (1 << 100));
+    let info = contract.execute([word(a), word(b)])?;
+    assert_eq!(info.ret, word(a.wrapping_add(b)));
+
+    let info = contract.execute([word(u128::MAX), word(1)])?;
+    assert_eq!(info.ret, word(0));
+
+    // a single ADD wrapped to 128 bits instead of the 64-bit limbs.
+    assert!(contract.artifact.runtime_bytecode.len() < 128);
+    Ok(())
+}
+
+#[test]
+fn mul() -> Result<()> {
+    let mut contract = Contract::from(Test::U128_MUL).pure().compile()?;
+
+    let (a, b) = ((1 << 64) + 1, (1 << 64) + 3);
+    let info = contract.execute([word(a), word(b)])?;
+    assert_eq!(info.ret, word(a.wrapping_mul(b)));
+
+    let info = contract.execute([word(1 << 70), word(1 << 50)])?;
+    assert_eq!(info.ret, word(1 << 120));
+    Ok(())
+}
@@ -0,0 +1,42 @@
+//! Macro for the constructor function.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_quote, ItemFn};
+
+/// Export name of the constructor function.
+const CONSTRUCTOR: &str = "constructor";
+
+/// Mark the function as the constructor.
+///
+/// The function is exported as `constructor` for WASM only, a plain
+/// rust function with the same body is generated for native targets
+/// for unit tests.
+pub fn expand(mut item: ItemFn) -> TokenStream {
+    if matches!(item.sig.output, syn::ReturnType::Type(..)) {
+        let message = format!("constructor {} could not return values", item.sig.ident);
+        return syn::Error::new_spanned(&item.sig.output, message)
+            .to_compile_error()
+            .into();
+    }
+
+    let mut native = item.clone();
+    native
+        .attrs
+        .push(parse_quote! { #[cfg(not(target_arch = "wasm32"))] });
+
+    item.attrs
+        .push(parse_quote! { #[cfg(target_arch = "wasm32")] });
+    item.sig.abi = Some(parse_quote! { extern "C" });
+    item.attrs
+        .push(parse_quote! { #[export_name = #CONSTRUCTOR] });
+    item.attrs
+        .push(parse_quote! { #[allow(improper_ctypes_definitions)] });
+
+    quote! {
+        #item
+
+        #native
+    }
+    .into()
+}
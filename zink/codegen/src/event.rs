@@ -1,19 +1,90 @@
 //! Event interface generation
 
 use proc_macro::{Span, TokenStream};
-use quote::quote;
-use syn::{DeriveInput, LitByteStr};
+use proc_macro2::Ident;
+use quote::{quote, ToTokens};
+use syn::{Data, DeriveInput, Fields, LitByteStr};
+use zabi::Param;
 
 /// Expand the event interface
 pub fn parse(item: DeriveInput) -> TokenStream {
     let name = LitByteStr::new(item.ident.to_string().as_bytes(), Span::call_site().into());
     let ident = item.ident;
 
-    let expanded = quote! {
+    let mut expanded = quote! {
         impl zink::Event for #ident {
             const NAME: &'static [u8] = #name;
         }
     };
 
+    if let Data::Struct(data) = item.data {
+        if let Fields::Named(fields) = data.fields {
+            expanded.extend(emit(&ident, fields.named.into_iter().collect()));
+        }
+    }
+
     expanded.into()
 }
+
+/// Generate the `emit` function of the event with fields, the fields
+/// marked with `#[indexed]` are the topics and the others are the data
+/// words, both in the declared order.
+///
+/// The signature of the event follows the declared order of the fields
+/// no matter if they are indexed.
+fn emit(ident: &Ident, fields: Vec<syn::Field>) -> proc_macro2::TokenStream {
+    let params = fields
+        .iter()
+        .map(|field| {
+            let ty = field.ty.to_token_stream().to_string().replace(' ', "");
+            Param::from(ty.as_str()).to_string()
+        })
+        .collect::<Vec<_>>();
+    let signature = format!("{ident}({})", params.join(","));
+
+    let (topics, words): (Vec<_>, Vec<_>) = fields.iter().partition(|field| indexed(field));
+    if topics.len() > 3 {
+        panic!("Event {ident} has more than 3 indexed fields");
+    }
+
+    if words.len() > 4 {
+        panic!("Event {ident} has more than 4 fields not indexed");
+    }
+
+    let fields = topics.iter().chain(&words).map(|field| &field.ident);
+    let host = Ident::new(
+        &format!("emit{}_{}", topics.len(), words.len()),
+        proc_macro2::Span::call_site(),
+    );
+    let doc = format!(" Emit `{signature}` with the fields.");
+
+    quote! {
+        impl #ident {
+            #[doc = #doc]
+            pub fn emit(self) {
+                #(zink::Asm::push(self.#fields);)*
+                unsafe {
+                    zink::ffi::asm::#host(#signature);
+                }
+            }
+        }
+    }
+}
+
+/// If the field is marked with `#[indexed]`, which takes no arguments.
+fn indexed(field: &syn::Field) -> bool {
+    let Some(attr) = field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("indexed"))
+    else {
+        return false;
+    };
+
+    if attr.meta.require_path_only().is_err() {
+        let name = field.ident.to_token_stream();
+        panic!("#[indexed] of field {name} takes no arguments");
+    }
+
+    true
+}
@@ -0,0 +1,38 @@
+//! Macro for the immutables.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use std::{cell::RefCell, collections::HashSet};
+use syn::{Ident, ItemStruct};
+
+thread_local! {
+   static IMMUTABLE_REGISTRY: RefCell<HashSet<String>> = RefCell::new(HashSet::new());
+}
+
+/// Expand the immutable with the type of its value.
+pub fn expand(value: Ident, target: ItemStruct) -> TokenStream {
+    let name = &target.ident;
+    let slot = immutable_slot(name.to_string());
+
+    quote! {
+        #target
+
+        impl zink::Immutable for #name {
+            const IMMUTABLE_SLOT: u32 = #slot;
+
+            type Value = #value;
+        }
+    }
+    .into()
+}
+
+fn immutable_slot(name: String) -> u32 {
+    IMMUTABLE_REGISTRY.with_borrow_mut(|r| {
+        let slot = r.len();
+        if !r.insert(name.clone()) {
+            panic!("Immutable {name} has already been declared");
+        }
+
+        slot
+    }) as u32
+}
@@ -4,9 +4,12 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use syn::{parse_macro_input, Attribute, DeriveInput, ItemFn, ItemStruct, LitStr};
+use syn::{parse_macro_input, Attribute, DeriveInput, Expr, Ident, ItemFn, ItemStruct, LitStr};
+use zabi::StateMutability;
 
+mod constructor;
 mod event;
+mod immutable;
 mod revert;
 mod selector;
 mod storage;
@@ -21,6 +24,46 @@ pub fn revert(input: TokenStream) -> TokenStream {
     revert::parse(input)
 }
 
+/// Revert with the input message in the ABI encoding of
+/// `Error(string)` if the condition is false
+///
+/// ```ignore
+/// zink::require!(value.lt(limit), "value exceeds the limit");
+/// ```
+#[proc_macro]
+pub fn require(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as revert::RequireInput);
+    revert::require(input)
+}
+
+/// Revert with the panic code in the ABI encoding of `Panic(uint256)`,
+/// which is decodable by the standard tooling of solidity
+///
+/// The codes of solidity are `0x01` for assertions, `0x11` for
+/// arithmetic overflows, `0x12` for division by zero and `0x32` for
+/// out-of-bounds accesses.
+///
+/// ```ignore
+/// zink::revert_with_code!(0x11);
+/// ```
+#[proc_macro]
+pub fn revert_with_code(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Expr);
+    revert::panic(input)
+}
+
+/// Revert with the panic code `0x01` in the ABI encoding of
+/// `Panic(uint256)` if the condition is false
+///
+/// ```ignore
+/// zink::assert!(!owner.eq(Address::empty()));
+/// ```
+#[proc_macro]
+pub fn assert(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Expr);
+    revert::assert(input)
+}
+
 /// Event logging interface
 ///
 /// ```ignore
@@ -45,7 +88,23 @@ pub fn revert(input: TokenStream) -> TokenStream {
 ///     const NAME: &'static [u8] = b"Ping";
 /// }
 /// ```
-#[proc_macro_derive(Event)]
+///
+/// Events with named fields could be emitted with the values of the
+/// fields, the fields marked with `#[indexed]` are the topics.
+///
+/// ```ignore
+/// #[derive(Event)]
+/// struct Transfer {
+///     #[indexed]
+///     from: Address,
+///     #[indexed]
+///     to: Address,
+///     value: U256,
+/// }
+///
+/// Transfer { from, to, value }.emit();
+/// ```
+#[proc_macro_derive(Event, attributes(indexed))]
 pub fn event(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     event::parse(input)
@@ -61,6 +120,10 @@ pub fn event(input: TokenStream) -> TokenStream {
 /// /// storage mapping
 /// #[zink::storage(i32, i32)]
 /// pub struct Mapping;
+///
+/// /// nested storage mapping, `mapping(address => mapping(address => uint256))`
+/// #[zink::storage(Address, Mapping<Address, U256>)]
+/// pub struct Allowance;
 /// ```
 #[proc_macro_attribute]
 pub fn storage(attr: TokenStream, input: TokenStream) -> TokenStream {
@@ -69,11 +132,130 @@ pub fn storage(attr: TokenStream, input: TokenStream) -> TokenStream {
     storage::Storage::parse(ty, input)
 }
 
+/// Declare an immutable which is set by the constructor and baked
+/// into the runtime bytecode.
+///
+/// ```ignore
+/// #[zink::immutable(Address)]
+/// pub struct Owner;
+/// ```
+#[proc_macro_attribute]
+pub fn immutable(attr: TokenStream, input: TokenStream) -> TokenStream {
+    let value = parse_macro_input!(attr as Ident);
+    let input = parse_macro_input!(input as ItemStruct);
+    immutable::expand(value, input)
+}
+
 /// Mark the function as an external entry point.
+///
+/// The selector of the function could be overridden to match an
+/// existing interface, and the output could be named in the ABI.
+///
+/// ```ignore
+/// #[zink::external(selector = "0x12345678", output = "sum")]
+/// pub fn add(x: u64, y: u64) -> u64 {
+///     x + y
+/// }
+/// ```
+///
+/// Functions could be overloaded by naming them in the ABI, they are
+/// dispatched by the selectors of their own signatures.
+///
+/// ```ignore
+/// #[zink::external(name = "f")]
+/// pub fn f_address(account: Address) -> Address {
+///     account
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn external(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut selector = None;
+    let mut output = None;
+    let mut name = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("selector") {
+            selector = Some(selector::parse(&meta.value()?.parse()?)?);
+            Ok(())
+        } else if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            Ok(())
+        } else if meta.path.is_ident("output") {
+            output = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("unsupported external property"))
+        }
+    });
+
+    parse_macro_input!(args with parser);
+    let input = parse_macro_input!(input as ItemFn);
+    selector::external(input, selector, name, output)
+}
+
+/// Mark the external function as pure, which reads neither the
+/// state nor the environment.
+///
+/// ```ignore
+/// #[zink::external]
+/// #[zink::pure]
+/// pub fn add(x: u64, y: u64) -> u64 {
+///     x + y
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn pure(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemFn);
+    selector::mutability(input, StateMutability::Pure)
+}
+
+/// Mark the external function as view, which reads but does not
+/// modify the state.
+///
+/// ```ignore
+/// #[zink::external]
+/// #[zink::view]
+/// pub fn balance() -> U256 {
+///     Balance::get()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn view(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemFn);
+    selector::mutability(input, StateMutability::View)
+}
+
+/// Mark the external function as payable, which accepts ether.
+///
+/// ```ignore
+/// #[zink::external]
+/// #[zink::payable]
+/// pub fn deposit() {
+///     Deposits::set(msg::sender(), msg::value());
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn payable(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemFn);
+    selector::mutability(input, StateMutability::Payable)
+}
+
+/// Mark the function as the constructor of the contract, which runs
+/// once while deploying the contract with the arguments appended to
+/// the init code.
+///
+/// The contract is not deployed if the constructor reverts.
+///
+/// ```ignore
+/// #[zink::constructor]
+/// pub fn init(supply: u32) {
+///     zink::require!(supply > 0, "zero supply");
+///     Supply::set(supply);
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn external(_args: TokenStream, input: TokenStream) -> TokenStream {
+pub fn constructor(_args: TokenStream, input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ItemFn);
-    selector::external(input)
+    constructor::expand(input)
 }
 
 /// Bounds for Arithmetic Primitives
@@ -3,7 +3,10 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Literal, Span};
 use quote::{quote, ToTokens};
-use syn::{Ident, LitStr};
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, Ident, LitStr, Token,
+};
 
 /// Revert with message
 pub fn parse(input: LitStr) -> TokenStream {
@@ -34,3 +37,51 @@ pub fn parse(input: LitStr) -> TokenStream {
     }
     .into()
 }
+
+/// Input of `require!`, `require!(cond, "message")`
+pub struct RequireInput {
+    /// The condition to check.
+    pub cond: Expr,
+    /// The revert message.
+    pub message: LitStr,
+}
+
+impl Parse for RequireInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let cond = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let message = input.parse()?;
+
+        Ok(Self { cond, message })
+    }
+}
+
+/// Revert with `Error(string)` if the condition is false
+pub fn require(input: RequireInput) -> TokenStream {
+    let RequireInput { cond, message } = input;
+
+    quote! {
+        if !(#cond) {
+            unsafe { zink::ffi::asm::revert_error(#message) }
+        }
+    }
+    .into()
+}
+
+/// Revert with the panic code in the ABI encoding of `Panic(uint256)`
+pub fn panic(code: Expr) -> TokenStream {
+    quote! {
+        unsafe { zink::ffi::asm::revert_panic(#code) }
+    }
+    .into()
+}
+
+/// Revert with `Panic(0x01)` if the condition is false
+pub fn assert(cond: Expr) -> TokenStream {
+    quote! {
+        if !(#cond) {
+            unsafe { zink::ffi::asm::revert_panic(0x01) }
+        }
+    }
+    .into()
+}
@@ -3,11 +3,27 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
-use syn::{parse_quote, ItemFn};
-use zabi::Abi;
+use syn::{parse_quote, Attribute, ItemFn, LitStr};
+use zabi::{Abi, StateMutability};
 
 /// Mark the function as external.
-pub fn external(mut item: ItemFn) -> TokenStream {
+///
+/// The function is exported for WASM only, a plain rust function with
+/// the same body is generated for native targets for unit tests.
+pub fn external(
+    mut item: ItemFn,
+    selector: Option<[u8; 4]>,
+    name: Option<String>,
+    output: Option<String>,
+) -> TokenStream {
+    let mut native = item.clone();
+    native.attrs.retain(|attr| state_mutability(attr).is_none());
+    native
+        .attrs
+        .push(parse_quote! { #[cfg(not(target_arch = "wasm32"))] });
+
+    item.attrs
+        .push(parse_quote! { #[cfg(target_arch = "wasm32")] });
     item.sig.abi = Some(parse_quote! { extern "C" });
     item.attrs.push(parse_quote! { #[no_mangle] });
     item.attrs
@@ -16,7 +32,27 @@ pub fn external(mut item: ItemFn) -> TokenStream {
     let selector: ItemFn = {
         let func = item.sig.ident.clone().to_string();
         let ident = Ident::new(&(func.clone() + "_selector"), Span::call_site());
-        let abi = Abi::from(&item.sig).to_hex().expect("ABI is not supported");
+        let mut abi = Abi::from(&item.sig);
+        abi.selector = selector;
+        if let Some(name) = name {
+            abi.symbol = Some(std::mem::replace(&mut abi.name, name));
+        }
+
+        if let Some(name) = output {
+            let Some(output) = abi.outputs.first_mut() else {
+                panic!("{func} has no output to name");
+            };
+
+            output.name = name;
+        }
+
+        if let Some(state_mutability) = item.attrs.iter().find_map(state_mutability) {
+            abi.state_mutability = state_mutability;
+        }
+
+        let abi = abi
+            .to_hex()
+            .unwrap_or_else(|e| panic!("ABI of {func} is not supported: {e}"));
         let abi_len = abi.len() as u32;
         let doc = " EVM selector for the function `".to_string() + &func + "`";
 
@@ -35,7 +71,54 @@ pub fn external(mut item: ItemFn) -> TokenStream {
     quote! {
         #item
 
+        #native
+
         #selector
     }
     .into()
 }
+
+/// Parse the selector override in hex, e.g. `"0x12345678"`.
+pub fn parse(selector: &LitStr) -> syn::Result<[u8; 4]> {
+    hex::decode(selector.value().trim_start_matches("0x"))
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| syn::Error::new(selector.span(), "selector should be 4 bytes in hex"))
+}
+
+/// Mark the state mutability of the external function.
+///
+/// The state mutability is recorded in the ABI by [`external`], this
+/// attribute only makes sure it is placed after `#[zink::external]`.
+pub fn mutability(mut item: ItemFn, mutability: StateMutability) -> TokenStream {
+    let attr = Ident::new(mutability.as_ref(), Span::call_site());
+    if let Some(index) = item.attrs.iter().position(is_external) {
+        item.attrs
+            .insert(index + 1, parse_quote! { #[zink::#attr] });
+    } else if item.sig.abi.is_none() {
+        panic!("#[zink::{attr}] only works with #[zink::external] functions");
+    }
+
+    quote! { #item }.into()
+}
+
+/// If the attribute is `#[zink::external]`.
+fn is_external(attr: &Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|s| s.ident == "external")
+}
+
+/// Get the state mutability if the attribute is one of `#[zink::pure]`,
+/// `#[zink::view]` or `#[zink::payable]`.
+fn state_mutability(attr: &Attribute) -> Option<StateMutability> {
+    let ident = &attr.path().segments.last()?.ident;
+    [
+        StateMutability::Pure,
+        StateMutability::View,
+        StateMutability::Payable,
+    ]
+    .into_iter()
+    .find(|mutability| ident == mutability.as_ref())
+}
@@ -60,6 +60,7 @@ impl Storage {
                 type Value = #value;
             }
         };
+        expanded.extend(self.layout(slot, width(&value)));
 
         if let Some(getter) = self.getter() {
             // TODO: generate docs from the storage doc
@@ -101,6 +102,7 @@ impl Storage {
                 }
             }
         };
+        expanded.extend(self.layout(slot, 32));
 
         if let Some(getter) = self.getter() {
             // TODO: generate docs from the storage doc
@@ -140,12 +142,13 @@ impl Storage {
                     seed[..32].copy_from_slice(&key1.bytes32());
                     seed[32..].copy_from_slice(&Self::STORAGE_SLOT.bytes32());
                     let skey1 = zink::keccak256(&seed);
-                    seed[..32].copy_from_slice(&skey1);
-                    seed[32..].copy_from_slice(&key2.bytes32());
+                    seed[..32].copy_from_slice(&key2.bytes32());
+                    seed[32..].copy_from_slice(&skey1);
                     zink::keccak256(&seed)
                 }
             }
         };
+        expanded.extend(self.layout(slot, 32));
 
         if let Some(getter) = self.getter() {
             // TODO: generate docs from the storage doc
@@ -162,6 +165,29 @@ impl Storage {
         expanded.into()
     }
 
+    /// Emit the slot of the storage to the compiler, the values are
+    /// not packed so the offset is always `0`.
+    fn layout(&self, slot: i32, width: u8) -> proc_macro2::TokenStream {
+        let name = self.target.ident.to_string();
+        let ident = Ident::new(
+            &format!("{}_storage_layout", AsSnakeCase(&name)),
+            Span::call_site(),
+        );
+        let info = format!("{name}:{slot}:0:{width}");
+        let len = info.len() as u32;
+
+        quote! {
+            #[no_mangle]
+            #[cfg(target_arch = "wasm32")]
+            #[doc(hidden)]
+            pub extern "C" fn #ident() {
+                unsafe {
+                    zink::ffi::emit_storage(#info.as_ptr() as u32, #len);
+                }
+            }
+        }
+    }
+
     /// Get the getter of this storage
     fn getter(&mut self) -> Option<Ident> {
         let mut getter = if matches!(self.target.vis, Visibility::Public(_)) {
@@ -229,7 +255,17 @@ pub enum StorageType {
 
 impl From<TokenStream> for StorageType {
     fn from(input: TokenStream) -> Self {
-        let tokens = input.to_string();
+        let mut tokens = input.to_string().replace(' ', "");
+
+        // `mapping(k1 => mapping(k2 => v))` could be declared as
+        // `k1, Mapping<k2, v>` as well.
+        if let Some((key1, inner)) = tokens
+            .strip_suffix('>')
+            .and_then(|tokens| tokens.split_once("Mapping<"))
+        {
+            tokens = format!("{key1}{inner}");
+        }
+
         let types: Vec<_> = tokens.split(',').collect();
         match types.len() {
             1 => StorageType::Value(Ident::new(types[0].trim(), Span::call_site())),
@@ -247,6 +283,23 @@ impl From<TokenStream> for StorageType {
     }
 }
 
+/// Width of the value type in its slot, types not known are taken as
+/// full words.
+fn width(value: &Ident) -> u8 {
+    match value.to_string().as_str() {
+        "bool" | "i8" | "u8" => 1,
+        "i16" | "u16" => 2,
+        "i32" | "u32" => 4,
+        "i64" | "u64" => 8,
+        "i128" | "u128" | "U128" => 16,
+        "Address" => 20,
+        ty => ty
+            .strip_prefix("Bytes")
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(32),
+    }
+}
+
 fn storage_slot(name: String) -> i32 {
     STORAGE_REGISTRY.with_borrow_mut(|r| {
         let key = r.len();
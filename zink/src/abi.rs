@@ -0,0 +1,115 @@
+//! ABI codec in memory.
+//!
+//! Values are laid out in words of 32 bytes per the solidity ABI, or
+//! in their own bytes with the packed encoding, only the types with a
+//! static encoding are supported.
+
+use crate::{
+    ffi,
+    primitives::{Address, U256},
+    Asm,
+};
+
+/// Types encoded in a single word.
+pub trait Value: Asm {
+    /// Bytes of the value in the packed encoding.
+    const PACKED_BYTES: u32;
+
+    /// Load the value from the word at `offset` in memory.
+    fn mload(offset: u32) -> Self;
+
+    /// Store the value to the word at `offset` in memory.
+    #[inline(always)]
+    fn mstore(self, offset: u32) {
+        self.push();
+        offset.push();
+        unsafe { ffi::evm::mstore() }
+    }
+
+    /// Store the value in its packed bytes to `offset` in memory, the
+    /// rest of the word is zeroed, returns the packed bytes.
+    #[inline(always)]
+    fn mstore_packed(self, offset: u32) -> u32 {
+        self.push();
+        (256 - Self::PACKED_BYTES * 8).push();
+        unsafe { ffi::evm::shl() }
+        offset.push();
+        unsafe { ffi::evm::mstore() }
+        Self::PACKED_BYTES
+    }
+}
+
+macro_rules! impl_value {
+    ($($ty:ident: $bytes:expr),+) => {
+        paste::paste! {
+            $(
+                impl Value for $ty {
+                    const PACKED_BYTES: u32 = $bytes;
+
+                    #[inline(always)]
+                    fn mload(offset: u32) -> Self {
+                        offset.push();
+                        unsafe { ffi::asm::[<mload_ $ty:lower>]() }
+                    }
+                }
+            )+
+        }
+    };
+}
+
+impl_value!(u8: 1, u16: 2, u32: 4, u64: 8, Address: 20, U256: 32);
+
+/// Offset of the free memory, after the memory used by the contract.
+#[inline(always)]
+pub fn free_memory() -> u32 {
+    unsafe { ffi::free_memory() }
+}
+
+/// Keccak hash of `len` bytes of memory from `offset`.
+#[inline(always)]
+pub fn keccak256(offset: u32, len: u32) -> U256 {
+    unsafe { ffi::keccak256_memory(len, offset) }
+}
+
+/// ABI-encode the values into the free memory, returns the offset
+/// and the length of the encoding.
+#[macro_export]
+macro_rules! abi_encode {
+    ($($value:expr),+ $(,)?) => {{
+        let offset = $crate::abi::free_memory();
+        let mut len = 0u32;
+        $(
+            $crate::abi::Value::mstore($value, offset + len);
+            len += 32;
+        )+
+        (offset, len)
+    }};
+}
+
+/// Encode the values tightly into the free memory as `abi.encodePacked`
+/// of solidity, returns the offset and the length of the encoding.
+///
+/// Each value takes the bytes of its type only, e.g. 20 bytes for an
+/// address and 4 bytes for a `u32`, so different values could share
+/// the same encoding, `(0x01, 0x0203)` and `(0x0102, 0x03)` of `u16`s
+/// for example. Hashing such encodings, e.g. for signatures, should
+/// only take the values of the fixed types in the fixed order.
+#[macro_export]
+macro_rules! encode_packed {
+    ($($value:expr),+ $(,)?) => {{
+        let offset = $crate::abi::free_memory();
+        let mut len = 0u32;
+        $(
+            len += $crate::abi::Value::mstore_packed($value, offset + len);
+        )+
+        (offset, len)
+    }};
+}
+
+/// Decode the value of the type from the word at the offset in memory.
+#[macro_export]
+macro_rules! abi_decode {
+    ($ty:ty, $offset:expr) => {
+        <$ty as $crate::abi::Value>::mload($offset)
+    };
+}
@@ -0,0 +1,9 @@
+//! Properties of the executing contract, mirroring `this` of solidity.
+
+use crate::{ffi, primitives::Address};
+
+/// Address of the executing contract, `address(this)`.
+#[inline(always)]
+pub fn address() -> Address {
+    unsafe { ffi::evm::address() }
+}
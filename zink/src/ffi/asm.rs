@@ -1,6 +1,7 @@
 //! Assembly FFI.
 
-use crate::primitives::{Address, U256};
+use crate::primitives::*;
+use paste::paste;
 
 #[link(wasm_import_module = "asm")]
 #[allow(improper_ctypes)]
@@ -32,6 +33,9 @@ extern "C" {
     /// Push address to stack
     pub fn push_address(address: Address);
 
+    /// Push u128 to stack
+    pub fn push_u128(u128: U128);
+
     /// Push u256 to stack
     pub fn push_u256(u256: U256);
 
@@ -47,6 +51,12 @@ extern "C" {
     /// Revert with message in 128 bytes
     pub fn revert4(message: &'static str);
 
+    /// Revert with message in the ABI encoding of `Error(string)`
+    pub fn revert_error(message: &'static str);
+
+    /// Revert with the panic code in the ABI encoding of `Panic(uint256)`
+    pub fn revert_panic(code: u32);
+
     /// Load a 8-bit signed integer from the storage.
     pub fn sload_i8() -> i8;
 
@@ -74,6 +84,94 @@ extern "C" {
     /// Load address from storage
     pub fn sload_address() -> Address;
 
+    /// Load u128 from storage
+    pub fn sload_u128() -> U128;
+
     /// Load address from storage
     pub fn sload_u256() -> U256;
+
+    /// Load a 8-bit unsigned integer from the memory.
+    pub fn mload_u8() -> u8;
+
+    /// Load a 16-bit unsigned integer from the memory.
+    pub fn mload_u16() -> u16;
+
+    /// Load a 32-bit unsigned integer from the memory.
+    pub fn mload_u32() -> u32;
+
+    /// Load a 64-bit unsigned integer from the memory.
+    pub fn mload_u64() -> u64;
+
+    /// Load address from the memory.
+    pub fn mload_address() -> Address;
+
+    /// Load u256 from the memory.
+    pub fn mload_u256() -> U256;
+
+    /// Load a 32-bit signed integer baked into the code.
+    pub fn immutable_i32(slot: u32) -> i32;
+
+    /// Load a 32-bit unsigned integer baked into the code.
+    pub fn immutable_u32(slot: u32) -> u32;
+
+    /// Load a 64-bit signed integer baked into the code.
+    pub fn immutable_i64(slot: u32) -> i64;
+
+    /// Load a 64-bit unsigned integer baked into the code.
+    pub fn immutable_u64(slot: u32) -> u64;
+
+    /// Load address baked into the code.
+    pub fn immutable_address(slot: u32) -> Address;
+
+    /// Load u256 baked into the code.
+    pub fn immutable_u256(slot: u32) -> U256;
 }
+
+/// Declare the push and sload functions of `bytesN`.
+macro_rules! bytes_ffi {
+    ($($size:expr),+) => {
+        paste! {
+            #[link(wasm_import_module = "asm")]
+            #[allow(improper_ctypes)]
+            extern "C" {
+                $(
+                    #[doc = concat!(" Push `bytes", $size, "` to stack")]
+                    pub fn [<push_bytes $size>](bytes: [<Bytes $size>]);
+
+                    #[doc = concat!(" Load `bytes", $size, "` from storage")]
+                    pub fn [<sload_bytes $size>]() -> [<Bytes $size>];
+                )+
+            }
+        }
+    };
+}
+
+bytes_ffi!(
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32
+);
+
+/// Declare the emit functions of events with the counts of the indexed
+/// topics and the data words.
+macro_rules! emit_ffi {
+    (topics: [$($topics:literal),+], words: $words:tt) => {
+        $(emit_ffi!(@topics $topics, $words);)+
+    };
+    (@topics $topics:literal, [$($words:literal),+]) => {
+        paste! {
+            #[link(wasm_import_module = "asm")]
+            #[allow(improper_ctypes)]
+            extern "C" {
+                $(
+                    #[doc = concat!(
+                        " Emit event with ", $topics, " indexed topics and ",
+                        $words, " data words on the stack"
+                    )]
+                    pub fn [<emit $topics _ $words>](signature: &'static str);
+                )+
+            }
+        }
+    };
+}
+
+emit_ffi!(topics: [0, 1, 2, 3], words: [0, 1, 2, 3, 4]);
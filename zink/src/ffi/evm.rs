@@ -1,6 +1,6 @@
 //! EVM FFI.
 
-use crate::primitives::Address;
+use crate::primitives::{Address, U256};
 
 #[link(wasm_import_module = "evm")]
 #[allow(improper_ctypes)]
@@ -122,9 +122,39 @@ extern "C" {
     /// Compute Keccak-256 hash
     pub fn keccak256();
 
+    /// Shift the value left by the bits on the top of the stack
+    pub fn shl();
+
+    /// Get the address of the current contract
+    pub fn address() -> Address;
+
     /// Get the current message sender
     pub fn caller() -> Address;
 
+    /// Get the value of the current message
+    pub fn callvalue() -> U256;
+
+    /// Get the size of the calldata
+    pub fn calldatasize() -> u32;
+
+    /// Get the balance of the account
+    pub fn balance(address: Address) -> U256;
+
+    /// Get the balance of the current contract
+    pub fn selfbalance() -> U256;
+
+    /// Get the origin of the transaction
+    pub fn origin() -> Address;
+
+    /// Get the gas price of the transaction
+    pub fn gasprice() -> U256;
+
+    /// Get the timestamp of the current block
+    pub fn timestamp() -> u64;
+
+    /// Get the number of the current block
+    pub fn number() -> u64;
+
     /// Append log record with no topics
     pub fn log0(name: &'static [u8]);
 
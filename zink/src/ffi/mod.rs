@@ -1,6 +1,6 @@
 //! Zink FFI.
 
-use crate::primitives::{Address, U256};
+use crate::primitives::{Address, Bytes4, U128, U256};
 
 pub mod asm;
 pub mod evm;
@@ -11,9 +11,15 @@ extern "C" {
     /// Emit ABI to host state.
     pub fn emit_abi(ptr: u32, len: u32);
 
+    /// Emit the slot of a storage to host state.
+    pub fn emit_storage(ptr: u32, len: u32);
+
     /// Equal operation for addresses
     pub fn address_eq(this: Address, other: Address) -> bool;
 
+    /// Check if the address is the zero address
+    pub fn address_is_zero(this: Address) -> bool;
+
     /// Equal operation for addresses
     pub fn u256_add(this: U256, other: U256) -> U256;
 
@@ -26,6 +32,42 @@ extern "C" {
     /// Equal operation for addresses
     pub fn u256_max() -> U256;
 
+    /// Add operation for u128, wrapped to 128 bits
+    pub fn u128_add(this: U128, other: U128) -> U128;
+
+    /// Sub operation for u128, wrapped to 128 bits
+    pub fn u128_sub(this: U128, other: U128) -> U128;
+
+    /// Mul operation for u128, wrapped to 128 bits
+    pub fn u128_mul(this: U128, other: U128) -> U128;
+
+    /// Less than operation for u128
+    pub fn u128_lt(this: U128, other: U128) -> bool;
+
+    /// Max of u128
+    pub fn u128_max() -> U128;
+
+    /// Convert u64 to u128
+    pub fn u128_from_u64(value: u64) -> U128;
+
+    /// Copy calldata into memory, returns the memory offset
+    pub fn calldata(offset: u32, len: u32) -> u32;
+
+    /// Selector of the calldata
+    pub fn selector() -> Bytes4;
+
+    /// Offset of the free memory
+    pub fn free_memory() -> u32;
+
+    /// Revert with the return data of the last call
+    pub fn bubble_revert();
+
+    /// Store a value in the storage if it differs from the present one
+    pub fn sstore_if_changed();
+
+    /// Keccak hash of the memory
+    pub fn keccak256_memory(len: u32, offset: u32) -> U256;
+
     /// Set up a label for reserving 32 bytes in memory
     pub fn label_reserve_mem_32();
 
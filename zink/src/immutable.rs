@@ -0,0 +1,42 @@
+//! Immutables set by the constructor.
+
+use crate::{
+    ffi,
+    primitives::{Address, U256},
+};
+use paste::paste;
+
+/// Immutable which is set by the constructor and baked into the
+/// runtime bytecode, reading it is cheaper than reading the storage.
+pub trait Immutable {
+    const IMMUTABLE_SLOT: u32;
+
+    type Value: ImmutableValue;
+
+    /// Get the value baked into the code.
+    #[inline(always)]
+    fn get() -> Self::Value {
+        Self::Value::immutable(Self::IMMUTABLE_SLOT)
+    }
+}
+
+/// Interface for the value of immutables
+pub trait ImmutableValue {
+    /// Load the immutable of the slot from the code
+    fn immutable(slot: u32) -> Self;
+}
+
+macro_rules! impl_immutable_value {
+    ($($ty:ident),+) => {
+        $(
+            impl ImmutableValue for $ty {
+                #[inline(always)]
+                fn immutable(slot: u32) -> Self {
+                    unsafe { paste! { ffi::asm::[<immutable_ $ty:lower>](slot) } }
+                }
+            }
+        )+
+    };
+}
+
+impl_immutable_value!(i32, u32, i64, u64, Address, U256);
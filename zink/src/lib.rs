@@ -5,15 +5,45 @@
 #[cfg(not(target_family = "wasm"))]
 extern crate alloc;
 
+pub mod abi;
 mod asm;
+pub mod env;
 mod event;
 pub mod ffi;
+mod immutable;
+pub mod msg;
 pub mod primitives;
 pub mod storage;
+pub mod tx;
 
-pub use self::{asm::Asm, event::Event};
+pub use self::{
+    asm::Asm,
+    event::Event,
+    immutable::{Immutable, ImmutableValue},
+};
 pub use storage::{DoubleKeyMapping, Mapping, Storage};
-pub use zink_codegen::{external, revert, storage, Event};
+pub use zink_codegen::{
+    assert, constructor, external, immutable, payable, pure, require, revert, revert_with_code,
+    storage, view, Event,
+};
+
+/// Copy `len` bytes of calldata from `offset` into memory, returns
+/// the memory offset of the copy.
+///
+/// Calldata out of bounds is zero-filled.
+#[inline(always)]
+pub fn calldata(offset: u32, len: u32) -> u32 {
+    unsafe { ffi::calldata(offset, len) }
+}
+
+/// Revert with the return data of the last external call.
+///
+/// This bubbles the revert data of a failed sub-call up unchanged,
+/// preserving its custom error for the caller.
+#[inline(always)]
+pub fn bubble_revert() {
+    unsafe { ffi::bubble_revert() }
+}
 
 /// Generate a keccak hash of the input (sha3)
 #[cfg(not(target_family = "wasm"))]
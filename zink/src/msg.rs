@@ -0,0 +1,39 @@
+//! Properties of the current message, mirroring `msg` of solidity.
+
+use crate::{
+    ffi,
+    primitives::{Address, Bytes4, U256},
+};
+
+/// Sender of the message, `msg.sender`.
+#[inline(always)]
+pub fn sender() -> Address {
+    unsafe { ffi::evm::caller() }
+}
+
+/// Wei sent with the message, `msg.value`.
+#[inline(always)]
+pub fn value() -> U256 {
+    unsafe { ffi::evm::callvalue() }
+}
+
+/// Copy the complete calldata into memory, `msg.data`, returns the
+/// memory offset of the copy.
+///
+/// The length of the copy is [`size`].
+#[inline(always)]
+pub fn data() -> u32 {
+    crate::calldata(0, size())
+}
+
+/// Size of the calldata in bytes.
+#[inline(always)]
+pub fn size() -> u32 {
+    unsafe { ffi::evm::calldatasize() }
+}
+
+/// First four bytes of the calldata, `msg.sig`.
+#[inline(always)]
+pub fn sig() -> Bytes4 {
+    unsafe { ffi::selector() }
+}
@@ -1,4 +1,4 @@
-use crate::{ffi, storage::StorageValue, Asm};
+use crate::{ffi, primitives::U256, storage::StorageValue, Asm};
 
 /// Account address
 #[repr(C)]
@@ -9,6 +9,9 @@ pub struct Address(
 );
 
 impl Address {
+    /// The zero address
+    pub const ZERO: Self = Self::empty();
+
     /// Returns empty address
     #[cfg(not(target_family = "wasm"))]
     pub const fn empty() -> Self {
@@ -27,6 +30,12 @@ impl Address {
         unsafe { ffi::evm::caller() }
     }
 
+    /// Returns the balance of the account
+    #[inline(always)]
+    pub fn balance(self) -> U256 {
+        unsafe { ffi::evm::balance(self) }
+    }
+
     /// if self equal to another
     ///
     /// NOTE: not using core::cmp because it uses registers in wasm
@@ -35,6 +44,20 @@ impl Address {
     pub fn eq(self, other: Self) -> bool {
         unsafe { ffi::address_eq(self, other) }
     }
+
+    /// if self is the zero address
+    #[inline(always)]
+    pub fn is_zero(self) -> bool {
+        unsafe { ffi::address_is_zero(self) }
+    }
+
+    /// Revert with `Error(string)` if self is the zero address
+    #[inline(always)]
+    pub fn require_not_zero(self) {
+        if self.is_zero() {
+            unsafe { ffi::asm::revert_error("zero address") }
+        }
+    }
 }
 
 impl Asm for Address {
@@ -0,0 +1,66 @@
+//! Fixed-size byte arrays.
+//!
+//! Unlike integers, `bytesN` are left-aligned in their words.
+//!
+//! `Bytes20` and `Bytes32` replace the former aliases of [`Address`]
+//! and [`U256`], which are kept for the right-aligned values.
+//!
+//! [`Address`]: crate::primitives::Address
+//! [`U256`]: crate::primitives::U256
+
+use crate::{ffi, storage::StorageValue, Asm};
+use paste::paste;
+
+macro_rules! impl_bytes {
+    ($($size:expr),+) => {
+        paste! {
+            $(
+                #[doc = concat!(" Fixed-size byte array `bytes", $size, "`")]
+                #[repr(C)]
+                #[derive(Clone, Copy)]
+                pub struct [<Bytes $size>](
+                    #[cfg(target_family = "wasm")] i32,
+                    #[cfg(not(target_family = "wasm"))] pub [u8; $size],
+                );
+
+                impl [<Bytes $size>] {
+                    /// Returns empty bytes
+                    #[cfg(not(target_family = "wasm"))]
+                    pub const fn empty() -> Self {
+                        Self([0; $size])
+                    }
+
+                    /// Returns empty bytes
+                    #[cfg(target_family = "wasm")]
+                    pub const fn empty() -> Self {
+                        Self(0)
+                    }
+                }
+
+                impl Asm for [<Bytes $size>] {
+                    fn push(self) {
+                        unsafe { ffi::asm::[<push_bytes $size>](self) }
+                    }
+
+                    #[cfg(not(target_family = "wasm"))]
+                    fn bytes32(&self) -> [u8; 32] {
+                        let mut output = [0; 32];
+                        output[..$size].copy_from_slice(&self.0);
+                        output
+                    }
+                }
+
+                impl StorageValue for [<Bytes $size>] {
+                    fn sload() -> Self {
+                        unsafe { ffi::asm::[<sload_bytes $size>]() }
+                    }
+                }
+            )+
+        }
+    };
+}
+
+impl_bytes!(
+    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26,
+    27, 28, 29, 30, 31, 32
+);
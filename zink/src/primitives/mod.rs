@@ -1,11 +1,13 @@
 //! Zink primitive types
 
 mod address;
+mod bytes;
+mod u128;
 mod u256;
 
 pub use address::Address;
+pub use bytes::*;
+pub use u128::U128;
 pub use u256::U256;
 
-pub type Bytes20 = Address;
-pub type Bytes32 = U256;
 pub type String32 = U256;
@@ -0,0 +1,78 @@
+#![allow(clippy::should_implement_trait)]
+use crate::{ffi, storage::StorageValue, Asm};
+
+/// 128-bit unsigned integer
+///
+/// The arithmetic operations are single EVM opcodes wrapped to 128
+/// bits instead of the 64-bit limbs of `u128` in WASM.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct U128(
+    #[cfg(target_family = "wasm")] i32,
+    #[cfg(not(target_family = "wasm"))] [u8; 16],
+);
+
+impl U128 {
+    /// Returns zero
+    #[cfg(not(target_family = "wasm"))]
+    pub const fn empty() -> Self {
+        U128([0; 16])
+    }
+
+    /// u128 from u64
+    #[inline(always)]
+    pub fn from_u64(value: u64) -> Self {
+        unsafe { ffi::u128_from_u64(value) }
+    }
+
+    /// u128 add
+    #[inline(always)]
+    pub fn add(self, other: Self) -> Self {
+        unsafe { ffi::u128_add(self, other) }
+    }
+
+    /// u128 mul
+    #[inline(always)]
+    pub fn mul(self, other: Self) -> Self {
+        unsafe { ffi::u128_mul(self, other) }
+    }
+
+    /// u128 less than
+    #[inline(always)]
+    pub fn lt(self, other: Self) -> bool {
+        unsafe { ffi::u128_lt(other, self) }
+    }
+
+    /// u128 sub
+    #[inline(always)]
+    pub fn sub(self, other: Self) -> Self {
+        unsafe { ffi::u128_sub(other, self) }
+    }
+
+    /// max of u128
+    #[inline(always)]
+    pub fn max() -> Self {
+        unsafe { ffi::u128_max() }
+    }
+}
+
+impl Asm for U128 {
+    #[inline(always)]
+    fn push(self) {
+        unsafe { ffi::asm::push_u128(self) }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn bytes32(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        bytes[16..].copy_from_slice(&self.0);
+        bytes
+    }
+}
+
+impl StorageValue for U128 {
+    #[inline(always)]
+    fn sload() -> Self {
+        unsafe { ffi::asm::sload_u128() }
+    }
+}
@@ -3,6 +3,9 @@
 use crate::{ffi, storage::StorageValue, Asm};
 
 /// Storage mapping interface
+///
+/// The storage layout is the one of `mapping(k1 => mapping(k2 => v))`
+/// in solidity, the slot of the value is `keccak(k2 . keccak(k1 . slot))`.
 pub trait DoubleKeyMapping {
     const STORAGE_SLOT: i32;
 
@@ -52,13 +55,13 @@ fn load_double_key(key1: impl Asm, key2: impl Asm, index: i32) {
         ffi::evm::push0();
         ffi::evm::keccak256();
 
-        // stores the hash
-        ffi::evm::push0();
+        // stores the hash as the slot of the inner mapping
+        ffi::asm::push_u8(0x20);
         ffi::evm::mstore();
 
-        // write index to memory
+        // write key2 to memory
         key2.push();
-        ffi::asm::push_u8(0x20);
+        ffi::evm::push0();
         ffi::evm::mstore();
 
         // hash key
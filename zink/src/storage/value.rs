@@ -23,4 +23,14 @@ pub trait Storage {
             ffi::evm::sstore();
         }
     }
+
+    /// Set value to storage, the write is skipped if the value is
+    /// unchanged at the cost of an extra `SLOAD`.
+    fn set_if_changed(value: Self::Value) {
+        value.push();
+        Asm::push(Self::STORAGE_SLOT);
+        unsafe {
+            ffi::sstore_if_changed();
+        }
+    }
 }
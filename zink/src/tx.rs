@@ -0,0 +1,18 @@
+//! Properties of the current transaction, mirroring `tx` of solidity.
+
+use crate::{
+    ffi,
+    primitives::{Address, U256},
+};
+
+/// Sender of the transaction, `tx.origin`.
+#[inline(always)]
+pub fn origin() -> Address {
+    unsafe { ffi::evm::origin() }
+}
+
+/// Gas price of the transaction, `tx.gasprice`.
+#[inline(always)]
+pub fn gasprice() -> U256 {
+    unsafe { ffi::evm::gasprice() }
+}
@@ -0,0 +1,146 @@
+//! Solidity ABI files for cross-testing.
+
+use crate::{evm::CONTRACT, Bytes32, Contract, Info};
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::{fs, path::Path};
+use zabi::{Abi, Arg, Param};
+
+/// Entry of a solidity JSON ABI.
+///
+/// Only the fields required for encoding the calls are parsed,
+/// events and errors share the same layout.
+#[derive(Deserialize)]
+struct Entry {
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    inputs: Vec<Input>,
+    #[serde(default)]
+    outputs: Vec<Input>,
+}
+
+/// Input or output of a solidity JSON ABI entry.
+#[derive(Deserialize)]
+struct Input {
+    #[serde(default)]
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+impl From<Input> for Arg {
+    fn from(input: Input) -> Self {
+        Arg {
+            name: input.name,
+            ty: Param::from(input.ty.as_str()),
+        }
+    }
+}
+
+/// Solidity JSON ABI, either emitted by `solc` or wrapped in a
+/// `forge` artifact.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum File {
+    Abi(Vec<Entry>),
+    Artifact { abi: Vec<Entry> },
+}
+
+/// Load the functions from the solidity JSON ABI file.
+pub fn load(path: impl AsRef<Path>) -> Result<Vec<Abi>> {
+    let path = path.as_ref();
+    let entries = match serde_json::from_slice(&fs::read(path)?)
+        .map_err(|e| anyhow!("invalid ABI file {}: {e}", path.display()))?
+    {
+        File::Abi(entries) | File::Artifact { abi: entries } => entries,
+    };
+
+    Ok(entries
+        .into_iter()
+        .filter(|entry| entry.ty == "function")
+        .map(|entry| {
+            let mut abi = Abi::default();
+            abi.name = entry.name;
+            abi.inputs = entry.inputs.into_iter().map(Into::into).collect();
+            abi.outputs = entry.outputs.into_iter().map(Into::into).collect();
+            abi
+        })
+        .collect())
+}
+
+/// Call of a function resolved from the attached ABI.
+pub struct Call<'c> {
+    contract: &'c mut Contract,
+    abi: Abi,
+    args: Vec<[u8; 32]>,
+}
+
+impl<'c> Call<'c> {
+    /// Resolve the function by its name or its signature.
+    pub(crate) fn new(contract: &'c mut Contract, name: &str) -> Result<Self> {
+        let mut matches = contract
+            .abi
+            .iter()
+            .filter(|abi| abi.name == name || abi.signature() == name);
+
+        let abi = match (matches.next(), matches.next()) {
+            (Some(abi), None) => abi.clone(),
+            (Some(_), Some(_)) => {
+                return Err(anyhow!(
+                    "function {name} is overloaded, call it with the signature"
+                ))
+            }
+            (None, _) => return Err(anyhow!("function {name} not found in the ABI")),
+        };
+
+        Ok(Self {
+            contract,
+            abi,
+            args: Vec::new(),
+        })
+    }
+
+    /// Append an argument to the call.
+    pub fn arg(mut self, arg: impl Bytes32) -> Self {
+        self.args.push(arg.to_bytes32());
+        self
+    }
+
+    /// Encode the calldata of the call.
+    pub fn calldata(&self) -> Result<Vec<u8>> {
+        if self.args.len() != self.abi.inputs.len() {
+            return Err(anyhow!(
+                "{} expects {} arguments, got {}",
+                self.abi.signature(),
+                self.abi.inputs.len(),
+                self.args.len()
+            ));
+        }
+
+        let mut calldata = self.abi.selector().to_vec();
+        for arg in &self.args {
+            calldata.extend_from_slice(arg);
+        }
+
+        tracing::debug!("calldata: {}", hex::encode(&calldata));
+        Ok(calldata)
+    }
+
+    /// Execute the call.
+    pub fn execute(self) -> Result<Info> {
+        let calldata = self.calldata()?;
+        let info = self
+            .contract
+            .evm()?
+            .caller(self.contract.take_caller())
+            .value(self.contract.take_value())
+            .contract(&self.contract.artifact.runtime_bytecode)
+            .calldata(&calldata)
+            .call(CONTRACT)?;
+
+        self.contract.check_expectations(info)
+    }
+}
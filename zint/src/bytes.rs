@@ -105,6 +105,20 @@ impl Bytes32 for &str {
     }
 }
 
+/// Solidity fixed-size byte array `bytesN`.
+///
+/// Left-aligned in its word, unlike the integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FixedBytes<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> Bytes32 for FixedBytes<N> {
+    fn to_bytes32(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[..N].copy_from_slice(&self.0);
+        bytes
+    }
+}
+
 impl Bytes32 for bool {
     fn to_bytes32(&self) -> [u8; 32] {
         let mut output = [0; 32];
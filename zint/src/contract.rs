@@ -1,23 +1,72 @@
 //! Contract Instance
 
-use crate::{lookup, Bytes32, Info, EVM};
+use crate::{
+    abi, evm::CONTRACT, lookup, BlockEnv, Bytes32, Call, Decode, Emit, Event, ExternalCall, Info,
+    Labels, Mocks, EVM, U256,
+};
 use anyhow::{anyhow, Result};
-use std::fs;
-use zinkc::{Artifact, Compiler, Config, Constructor, InitStorage};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+};
+use zabi::Abi;
+use zinkc::{Artifact, Compiler, Config, Constructor, Immutables, InitStorage, SlotInfo};
 
 /// Contract instance for testing.
 #[derive(Default)]
 pub struct Contract {
     /// If enable dispatcher.
     pub dispatcher: bool,
+    /// If skip the `SSTORE`s writing the present values.
+    pub sstore_if_changed: bool,
+    /// If keep the induction variables of the loops in memory.
+    pub no_loop_induction: bool,
+    /// If fail on the values wider than their types.
+    pub strict_width: bool,
+    /// If verify the emitted bytecode.
+    pub verify: bool,
+    /// Threshold of the constant pool, disabled if unset.
+    pub const_pool: Option<usize>,
     /// The artifact of the contract.
     pub artifact: Artifact,
     /// The source WASM of the contract.
     pub wasm: Vec<u8>,
     /// Bytecode constructor
     pub constructor: Constructor,
-    /// Address in evm
+    /// Address of the contract in the executions, [`CONTRACT`] if unset
     pub address: [u8; 20],
+    /// Block environment of the executions
+    pub block: BlockEnv,
+    /// Functions of the attached solidity ABI
+    pub abi: Vec<Abi>,
+    /// Events expected to be emitted by the next execution
+    pub emits: Vec<Emit>,
+    /// Calls expected to be made by the next execution
+    pub calls: Vec<ExternalCall>,
+    /// Caller of the executions
+    pub caller: [u8; 20],
+    /// Caller of the next execution only
+    pub next_caller: Option<[u8; 20]>,
+    /// Caller of the executions until the prank stops
+    pub prank: Option<[u8; 20]>,
+    /// Value sent with the next execution only
+    pub next_value: U256,
+    /// Labels of the addresses in the traces and the error messages
+    pub labels: Labels,
+    /// Balances of the accounts in the executions
+    pub balances: BTreeMap<[u8; 20], U256>,
+    /// Balances of the accounts touched by the last execution after it
+    pub last_balances: HashMap<[u8; 20], U256>,
+    /// Mocked calls of the accounts in the executions
+    pub mocks: BTreeMap<[u8; 20], Mocks>,
+    /// Gas limit of the executions
+    pub gas_limit: Option<u64>,
+    /// If record the storage accesses of the executions
+    pub trace_storage: bool,
+    /// The node url and the block number of the forked state
+    #[cfg(feature = "fork")]
+    pub fork: Option<(String, u64)>,
 }
 
 impl<T> From<T> for Contract
@@ -47,6 +96,39 @@ impl Contract {
         Ok(bytecode)
     }
 
+    /// Get the init bytecode of the contract.
+    ///
+    /// This is the constructor without the runtime bytecode appended.
+    pub fn init_bytecode(&self) -> Result<Vec<u8>> {
+        let init_code = self
+            .constructor
+            .init_code(self.artifact.runtime_bytecode.len())?;
+
+        Ok(init_code.to_vec())
+    }
+
+    /// Get the runtime bytecode of the contract.
+    pub fn runtime_bytecode(&self) -> Vec<u8> {
+        self.artifact.runtime_bytecode.clone()
+    }
+
+    /// Get the program counters of the runtime bytecode with the offsets
+    /// of their originating WASM instructions, for mapping a program
+    /// counter back to the WASM source, e.g. the one of a revert.
+    ///
+    /// This has to be called after the contract is compiled.
+    pub fn source_map(&self) -> Vec<(usize, usize)> {
+        self.artifact.source_map.clone()
+    }
+
+    /// Get the storage slots of the `#[zink::storage]` declarations,
+    /// ordered by slot.
+    ///
+    /// This has to be called after the contract is compiled.
+    pub fn storage_layout(&self) -> Vec<SlotInfo> {
+        self.artifact.layout.clone()
+    }
+
     /// Preset the storage of the contract, similar with the concept `constructor`
     /// in solidity, but just in time.
     pub fn construct(&mut self, storage: InitStorage) -> Result<&mut Self> {
@@ -54,21 +136,297 @@ impl Contract {
         Ok(self)
     }
 
+    /// Set the immutables which are baked into the runtime bytecode by
+    /// the constructor.
+    ///
+    /// This has to be called after the contract is compiled.
+    pub fn immutables(&mut self, immutables: Immutables) -> Result<&mut Self> {
+        self.constructor
+            .immutables(&self.artifact.runtime_bytecode, immutables)?;
+        Ok(self)
+    }
+
     /// Compile WASM to EVM bytecode.
     pub fn compile(mut self) -> Result<Self> {
-        let config = Config::default().dispatcher(self.dispatcher);
+        let config = Config::default()
+            .dispatcher(self.dispatcher)
+            .sstore_if_changed(self.sstore_if_changed)
+            .no_loop_induction(self.no_loop_induction)
+            .strict_width(self.strict_width)
+            .verify(self.verify)
+            .const_pool(self.const_pool);
         let compiler = Compiler::new(config);
         self.artifact = compiler.compile(&self.wasm)?;
+        self.constructor.body(&self.artifact.constructor_bytecode);
 
         // tracing::debug!("abi: {:#}", self.json_abi()?);
         tracing::debug!("bytecode: {}", hex::encode(&self.artifact.runtime_bytecode));
         Ok(self)
     }
 
+    /// Attach the solidity JSON ABI, either emitted by `solc` or
+    /// wrapped in a `forge` artifact, for resolving calls by name.
+    pub fn with_abi(mut self, path: impl AsRef<Path>) -> Result<Self> {
+        self.abi = abi::load(path)?;
+        Ok(self)
+    }
+
+    /// Call the function of the attached ABI by its name or its
+    /// signature, the arguments are appended with [`Call::arg`].
+    pub fn call(&mut self, name: &str) -> Result<Call<'_>> {
+        Call::new(self, name)
+    }
+
+    /// Expect the event to be emitted by the next execution, the
+    /// execution fails if no log matches its topics and data.
+    pub fn expect_emit(&mut self, event: impl Event) -> &mut Self {
+        self.emits.push(Emit::new(event));
+        self
+    }
+
+    /// Expect the event to be emitted by the next execution, only
+    /// the topics are matched.
+    pub fn expect_emit_topics(&mut self, event: impl Event) -> &mut Self {
+        self.emits.push(Emit::topics(event));
+        self
+    }
+
+    /// Expect the next execution to call the account with the calldata,
+    /// the execution fails if no such call is made.
+    pub fn expect_call(&mut self, address: [u8; 20], calldata: impl Into<Vec<u8>>) -> &mut Self {
+        self.calls.push(ExternalCall {
+            to: address,
+            input: calldata.into(),
+        });
+        self
+    }
+
+    /// Check the logs and the calls of the execution against the
+    /// expected events and calls, the expectations are consumed.
+    ///
+    /// The labeled addresses in the errors and the revert message are
+    /// replaced with their labels, the balances after the execution are
+    /// kept for [`Contract::balance_of`].
+    pub(crate) fn check_expectations(&mut self, mut info: Info) -> Result<Info> {
+        self.last_balances = info.balances.clone();
+        Emit::check(&std::mem::take(&mut self.emits), &info)
+            .and_then(|_| ExternalCall::check(&std::mem::take(&mut self.calls), &info))
+            .map_err(|e| anyhow!(self.labels.replace(&e.to_string())))?;
+        info.revert = info.revert.map(|message| self.labels.replace(&message));
+        Ok(info)
+    }
+
+    /// Label the address, the label is printed instead of the hex of
+    /// the address in the traces and the error messages.
+    pub fn label(&mut self, address: [u8; 20], name: impl Into<String>) -> &mut Self {
+        self.labels.insert(address, name);
+        self
+    }
+
+    /// Set the caller of the next execution only, the following
+    /// executions fall back to the caller set by [`Contract::set_caller`].
+    pub fn with_caller(&mut self, caller: [u8; 20]) -> &mut Self {
+        self.next_caller = Some(caller);
+        self
+    }
+
+    /// Set the address of the contract for the following executions.
+    pub fn set_address(&mut self, address: [u8; 20]) -> &mut Self {
+        self.address = address;
+        self
+    }
+
+    /// Set the caller for the following executions.
+    pub fn set_caller(&mut self, caller: [u8; 20]) -> &mut Self {
+        self.caller = caller;
+        self
+    }
+
+    /// Set the caller of the following executions until
+    /// [`Contract::stop_prank`], the pranks could not be nested.
+    ///
+    /// The one-off caller set by [`Contract::with_caller`] still
+    /// applies to the next execution.
+    pub fn start_prank(&mut self, caller: [u8; 20]) -> Result<&mut Self> {
+        if let Some(prank) = self.prank {
+            return Err(anyhow!(
+                "prank of 0x{} has not been stopped",
+                hex::encode(prank)
+            ));
+        }
+
+        self.prank = Some(caller);
+        Ok(self)
+    }
+
+    /// Stop the prank, the following executions fall back to the
+    /// caller set by [`Contract::set_caller`].
+    pub fn stop_prank(&mut self) -> &mut Self {
+        self.prank = None;
+        self
+    }
+
+    /// Get the caller of the next execution, the one-off caller is
+    /// consumed.
+    pub(crate) fn take_caller(&mut self) -> [u8; 20] {
+        self.next_caller
+            .take()
+            .or(self.prank)
+            .unwrap_or(self.caller)
+    }
+
+    /// Send value with the next execution only.
+    pub fn with_value(&mut self, value: impl Into<U256>) -> &mut Self {
+        self.next_value = value.into();
+        self
+    }
+
+    /// Get the value of the next execution, the value is consumed.
+    pub(crate) fn take_value(&mut self) -> U256 {
+        std::mem::take(&mut self.next_value)
+    }
+
+    /// Set the balance of the account for the following executions.
+    pub fn deal(&mut self, address: [u8; 20], amount: impl Into<U256>) -> &mut Self {
+        self.balances.insert(address, amount.into());
+        self
+    }
+
+    /// Get the balance of the account after the last execution, the
+    /// accounts not touched by it keep the balances set by
+    /// [`Contract::deal`].
+    pub fn balance_of(&self, address: [u8; 20]) -> U256 {
+        self.last_balances
+            .get(&address)
+            .or_else(|| self.balances.get(&address))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Mock the calls of `selector` to the account for the following
+    /// executions, which return `data` without the callee deployed, the
+    /// calls of the other selectors to the account revert.
+    pub fn mock_call(
+        &mut self,
+        address: [u8; 20],
+        selector: [u8; 4],
+        data: impl Into<Vec<u8>>,
+    ) -> &mut Self {
+        self.mocks
+            .entry(address)
+            .or_default()
+            .insert(selector, data.into());
+        self
+    }
+
+    /// Set the timestamp of the block for the following executions.
+    pub fn warp(&mut self, timestamp: u64) -> &mut Self {
+        self.block.timestamp = U256::from(timestamp);
+        self
+    }
+
+    /// Set the number of the block for the following executions.
+    pub fn set_block_number(&mut self, number: u64) -> &mut Self {
+        self.block.number = U256::from(number);
+        self
+    }
+
+    /// Roll the block to `number` for the following executions, the
+    /// 256 most recent blocks before it have their hashes for
+    /// `BLOCKHASH`, see [`crate::block_hash`].
+    pub fn roll(&mut self, number: u64) -> &mut Self {
+        self.set_block_number(number)
+    }
+
+    /// Fork the state of the block from the node at `url` for the
+    /// following executions, the state of the existing contracts is
+    /// loaded from the node while being accessed.
+    ///
+    /// The executions have to run in a multi-threaded tokio runtime.
+    #[cfg(feature = "fork")]
+    pub fn fork(mut self, url: impl Into<String>, block: u64) -> Self {
+        self.fork = Some((url.into(), block));
+        self
+    }
+
+    /// Cap the gas of the executions, the executions exceeding it halt
+    /// with [`crate::HaltReason::OutOfGas`], see [`Info::is_out_of_gas`].
+    ///
+    /// The limit covers the intrinsic gas of the transactions as well.
+    pub fn with_gas_limit(&mut self, gas_limit: u64) -> &mut Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Record the `SLOAD`s and the `SSTORE`s of the following executions
+    /// in [`Info::storage_ops`], with the values of the slots before
+    /// and after them.
+    pub fn trace_storage(&mut self) -> &mut Self {
+        self.trace_storage = true;
+        self
+    }
+
+    /// Create the EVM for the executions.
+    pub(crate) fn evm<'e>(&self) -> Result<EVM<'e>> {
+        #[cfg(feature = "fork")]
+        let evm = match &self.fork {
+            Some((url, block)) => EVM::fork(url, *block)?,
+            None => EVM::default(),
+        };
+        #[cfg(not(feature = "fork"))]
+        let evm = EVM::default();
+
+        let evm = match self.gas_limit {
+            Some(gas_limit) => evm.gas_limit(gas_limit),
+            None => evm,
+        }
+        .trace_storage(self.trace_storage);
+        let evm = self
+            .balances
+            .iter()
+            .fold(evm.block(self.block.clone()), |evm, (address, balance)| {
+                evm.balance(*address, *balance)
+            });
+
+        Ok(self.mocks.iter().fold(evm, |evm, (address, mocks)| {
+            evm.contract_at(*address, &mocks.bytecode())
+        }))
+    }
+
+    /// Address of the contract in the executions.
+    fn target(&self) -> [u8; 20] {
+        if self.address == [0; 20] {
+            CONTRACT
+        } else {
+            self.address
+        }
+    }
+
     /// Deploy self to evm
     pub fn deploy<'e>(&mut self) -> Result<EVM<'e>> {
-        let mut evm = EVM::default();
-        let info = evm.deploy(&self.bytecode()?)?;
+        self.deploy_with::<()>([])
+    }
+
+    /// Deploy self to evm with the arguments of the constructor, fails
+    /// with the revert message if the constructor reverts.
+    pub fn deploy_with<'e, Param>(&mut self, args: impl AsRef<[Param]>) -> Result<EVM<'e>>
+    where
+        Param: Bytes32,
+    {
+        let mut bytecode = self.bytecode()?;
+        for arg in args.as_ref() {
+            bytecode.extend_from_slice(&arg.to_bytes32());
+        }
+
+        let mut evm = self.evm()?;
+        let info = evm.deploy(&bytecode)?;
+        if let Some(message) = &info.revert {
+            return Err(anyhow!("deployment reverted: {message}"));
+        }
+
+        if let Some(reason) = &info.halt {
+            return Err(anyhow!("deployment halted: {reason:?}"));
+        }
 
         self.address.copy_from_slice(&info.address);
         Ok(evm)
@@ -112,7 +470,84 @@ impl Contract {
     where
         Param: Bytes32,
     {
-        EVM::interp(&self.artifact.runtime_bytecode, &self.encode(inputs)?)
+        let info = self
+            .evm()?
+            .caller(self.take_caller())
+            .value(self.take_value())
+            .contract_at(self.target(), &self.artifact.runtime_bytecode)
+            .calldata(&self.encode(inputs)?)
+            .call(self.target())?;
+
+        self.check_expectations(info)
+    }
+
+    /// Execute the contract and decode the return data into `T`.
+    pub fn read_return_as<T, Param>(&mut self, inputs: impl AsRef<[Param]>) -> Result<T>
+    where
+        T: Decode,
+        Param: Bytes32,
+    {
+        self.execute(inputs)?.decode()
+    }
+
+    /// Execute the contract and check the gas used against the
+    /// snapshot of `name`, see [`crate::snapshot`].
+    pub fn gas_snapshot<Param>(&mut self, name: &str, inputs: impl AsRef<[Param]>) -> Result<Info>
+    where
+        Param: Bytes32,
+    {
+        let info = self.execute(inputs)?;
+        crate::snapshot::check(name, info.gas)?;
+        Ok(info)
+    }
+
+    /// Execute the contract and fail if the gas used meets or exceeds
+    /// the budget, which covers the intrinsic gas of the transaction.
+    pub fn assert_gas_lt<Param>(&mut self, budget: u64, inputs: impl AsRef<[Param]>) -> Result<Info>
+    where
+        Param: Bytes32,
+    {
+        let info = self.execute(inputs)?;
+        if info.gas >= budget {
+            return Err(anyhow!(
+                "gas used {} is not less than the budget {budget}",
+                info.gas
+            ));
+        }
+
+        Ok(info)
+    }
+
+    /// Execute the contract with the raw calldata, returns the success
+    /// flag and the output bytes without interpreting them.
+    pub fn call_raw(&mut self, calldata: &[u8]) -> Result<(bool, Vec<u8>)> {
+        self.evm()?
+            .caller(self.take_caller())
+            .value(self.take_value())
+            .contract_at(self.target(), &self.artifact.runtime_bytecode)
+            .calldata(calldata)
+            .call_raw(self.target())
+    }
+
+    /// Execute the contract and collect the opcode execution trace.
+    #[cfg(feature = "trace")]
+    pub fn execute_traced<Param>(
+        &mut self,
+        inputs: impl AsRef<[Param]>,
+    ) -> Result<(Info, Vec<crate::TraceStep>)>
+    where
+        Param: Bytes32,
+    {
+        let (info, steps) = self
+            .evm()?
+            .trace(true)
+            .caller(self.take_caller())
+            .value(self.take_value())
+            .contract_at(self.target(), &self.artifact.runtime_bytecode)
+            .calldata(&self.encode(inputs)?)
+            .call_traced(self.target())?;
+
+        Ok((self.check_expectations(info)?, steps))
     }
 
     /// Get the JSON ABI of the contract.
@@ -126,6 +561,46 @@ impl Contract {
         self
     }
 
+    /// Skip the `SSTORE`s writing the present values.
+    pub fn sstore_if_changed(mut self) -> Self {
+        self.sstore_if_changed = true;
+        self
+    }
+
+    /// Keep the induction variables of the loops in memory.
+    pub fn no_loop_induction(mut self) -> Self {
+        self.no_loop_induction = true;
+        self
+    }
+
+    /// Fail on the integer instructions consuming the values wider
+    /// than their types.
+    pub fn strict_width(mut self) -> Self {
+        self.strict_width = true;
+        self
+    }
+
+    /// Verify the jump targets, the push data and the opcodes of the
+    /// emitted bytecode.
+    pub fn verify(mut self) -> Self {
+        self.verify = true;
+        self
+    }
+
+    /// Load the wide constants pushed at least the times of the
+    /// threshold from memory.
+    pub fn const_pool(mut self, threshold: usize) -> Self {
+        self.const_pool = Some(threshold);
+        self
+    }
+
+    /// Compile the inline WAT module without the dispatcher, the
+    /// inputs of the executions are passed to the exported function
+    /// as they are.
+    pub fn compile_wat(wat: &str) -> Result<Self> {
+        Self::from(wat::parse_str(wat)?).pure().compile()
+    }
+
     /// Search for zink contract in the target directory.
     pub fn search(name: &str) -> Result<Self> {
         crate::setup_logger();
@@ -0,0 +1,114 @@
+//! Decoding of the return data.
+
+use crate::U256;
+use anyhow::{anyhow, Result};
+use core::mem::size_of;
+
+/// Types decoded from the ABI encoding of the return data.
+///
+/// Only the static types are supported, addresses are decoded as
+/// `[u8; 20]`.
+pub trait Decode: Sized {
+    /// Count of the words encoding the type.
+    const WORDS: usize = 1;
+
+    /// Decode the type from its words.
+    fn decode(words: &[u8]) -> Result<Self>;
+}
+
+/// Implement [`Decode`] for the integers.
+macro_rules! impl_decode {
+    (unsigned: [$($uty:ty),+], signed: [$($ity:ty),+]) => {
+        $(
+            impl Decode for $uty {
+                fn decode(words: &[u8]) -> Result<Self> {
+                    let low = low_bytes(words, size_of::<$uty>(), 0, stringify!($uty))?;
+                    Ok(<$uty>::from_be_bytes(low.try_into()?))
+                }
+            }
+        )+
+
+        $(
+            impl Decode for $ity {
+                fn decode(words: &[u8]) -> Result<Self> {
+                    let sign = words[32 - size_of::<$ity>()] & 0x80;
+                    let fill = if sign == 0 { 0 } else { 0xff };
+                    let low = low_bytes(words, size_of::<$ity>(), fill, stringify!($ity))?;
+                    Ok(<$ity>::from_be_bytes(low.try_into()?))
+                }
+            }
+        )+
+    };
+}
+
+impl_decode! {
+    unsigned: [u8, u16, u32, u64, u128],
+    signed: [i8, i16, i32, i64, i128]
+}
+
+impl Decode for bool {
+    fn decode(words: &[u8]) -> Result<Self> {
+        Ok(u8::decode(words)? != 0)
+    }
+}
+
+impl Decode for [u8; 20] {
+    fn decode(words: &[u8]) -> Result<Self> {
+        low_bytes(words, 20, 0, "address")?
+            .try_into()
+            .map_err(Into::into)
+    }
+}
+
+impl Decode for [u8; 32] {
+    fn decode(words: &[u8]) -> Result<Self> {
+        words.try_into().map_err(Into::into)
+    }
+}
+
+impl Decode for U256 {
+    fn decode(words: &[u8]) -> Result<Self> {
+        Ok(U256::from_be_slice(words))
+    }
+}
+
+/// Implement [`Decode`] for the tuples.
+macro_rules! impl_decode_tuple {
+    ($($ty:ident),+) => {
+        impl<$($ty: Decode),+> Decode for ($($ty,)+) {
+            const WORDS: usize = 0 $(+ $ty::WORDS)+;
+
+            fn decode(mut words: &[u8]) -> Result<Self> {
+                Ok(($($ty::decode(take(&mut words, $ty::WORDS))?,)+))
+            }
+        }
+    };
+}
+
+impl_decode_tuple!(A);
+impl_decode_tuple!(A, B);
+impl_decode_tuple!(A, B, C);
+impl_decode_tuple!(A, B, C, D);
+impl_decode_tuple!(A, B, C, D, E);
+impl_decode_tuple!(A, B, C, D, E, F);
+
+/// Get the low `size` bytes of the word if the high bytes are all
+/// `fill`, which is the padding of the value of `ty`.
+fn low_bytes<'w>(word: &'w [u8], size: usize, fill: u8, ty: &str) -> Result<&'w [u8]> {
+    let (high, low) = word.split_at(32 - size);
+    if high.iter().any(|byte| *byte != fill) {
+        return Err(anyhow!(
+            "0x{} is out of the range of {ty}",
+            hex::encode(word)
+        ));
+    }
+
+    Ok(low)
+}
+
+/// Take the words of a value from the front of the words.
+fn take<'w>(words: &mut &'w [u8], count: usize) -> &'w [u8] {
+    let (head, rest) = words.split_at(count * 32);
+    *words = rest;
+    head
+}
@@ -0,0 +1,69 @@
+//! Event expectations.
+
+use crate::Info;
+use anyhow::{anyhow, Result};
+use revm::primitives::Log;
+
+/// Event to be matched against the logs.
+pub trait Event {
+    /// Topics of the event, the hash of the signature comes first
+    /// unless the event is anonymous.
+    fn topics(&self) -> Vec<[u8; 32]>;
+
+    /// ABI encoding of the non-indexed fields.
+    fn data(&self) -> Vec<u8>;
+}
+
+/// Log expected to be emitted by the next call.
+#[derive(Clone, Debug)]
+pub struct Emit {
+    /// Expected topics.
+    pub topics: Vec<[u8; 32]>,
+    /// Expected data, not checked if `None`.
+    pub data: Option<Vec<u8>>,
+}
+
+impl Emit {
+    /// Expect the topics and the data of the event.
+    pub fn new(event: impl Event) -> Self {
+        Self {
+            topics: event.topics(),
+            data: Some(event.data()),
+        }
+    }
+
+    /// Expect the topics of the event only.
+    pub fn topics(event: impl Event) -> Self {
+        Self {
+            topics: event.topics(),
+            data: None,
+        }
+    }
+
+    /// If the log matches the expectation.
+    pub fn matches(&self, log: &Log) -> bool {
+        log.topics()
+            .iter()
+            .map(|topic| topic.0)
+            .eq(self.topics.iter().copied())
+            && self
+                .data
+                .as_ref()
+                .is_none_or(|data| log.data.data.as_ref() == data.as_slice())
+    }
+
+    /// Check if the logs of the execution match the expectations.
+    pub fn check(expected: &[Self], info: &Info) -> Result<()> {
+        for emit in expected {
+            if !info.logs.iter().any(|log| emit.matches(log)) {
+                return Err(anyhow!(
+                    "expected event not emitted, topics: {:?}, data: {:?}",
+                    emit.topics.iter().map(hex::encode).collect::<Vec<_>>(),
+                    emit.data.as_ref().map(hex::encode)
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
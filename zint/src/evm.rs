@@ -1,16 +1,29 @@
 //! Wrapper of revm
 
+use crate::{
+    record::{Recorder, StorageOp},
+    Decode, ExternalCall,
+};
 use anyhow::{anyhow, Result};
 use revm::{
-    db::EmptyDB,
+    db::{CacheDB, EmptyDB},
+    inspector_handle_register,
     primitives::{
-        AccountInfo, Bytecode, Bytes, ExecutionResult, HaltReason, Log, Output, ResultAndState,
-        SuccessReason, TransactTo, TxKind, U256,
+        AccountInfo, BlockEnv, Bytecode, Bytes, ExecutionResult, HaltReason, Log, Output,
+        ResultAndState, SuccessReason, TransactTo, TxKind, U256,
     },
-    Database, Evm as Revm, InMemoryDB,
+    Database, Evm as Revm,
 };
 use std::collections::HashMap;
 
+#[cfg(feature = "fork")]
+use crate::fork::Backend;
+#[cfg(not(feature = "fork"))]
+use revm::db::EmptyDB as Backend;
+
+/// Database of the EVM, caching the state of the backend.
+type Db = CacheDB<Backend>;
+
 /// Transaction gas limit.
 const GAS_LIMIT: u64 = 1_000_000_000;
 
@@ -20,30 +33,59 @@ pub const ALICE: [u8; 20] = [0; 20];
 /// Contract address if any.
 pub const CONTRACT: [u8; 20] = [1; 20];
 
+/// Get the hash of the block in the executions, which is the hash of
+/// the decimal number of the block as the empty backend derives it.
+///
+/// The hashes of the forked blocks are the ones of the remote node.
+pub fn block_hash(number: u64) -> [u8; 32] {
+    crate::keccak256(number.to_string().as_bytes())
+}
+
 /// Wrapper of full REVM
 pub struct EVM<'e> {
-    inner: Revm<'e, (), InMemoryDB>,
+    inner: Revm<'e, Recorder, Db>,
     /// Caller for the execution
     pub caller: [u8; 20],
     /// If commit changes
     commit: bool,
+    /// Gas limit of the transactions
+    gas_limit: u64,
 }
 
 impl<'e> Default for EVM<'e> {
     fn default() -> Self {
-        let mut db = InMemoryDB::default();
+        Self::with_backend(Backend::default())
+    }
+}
+
+impl<'e> EVM<'e> {
+    /// Create the EVM with the backend of its database.
+    fn with_backend(backend: Backend) -> Self {
+        let mut db = CacheDB::new(backend);
         db.insert_account_info(ALICE.into(), AccountInfo::from_balance(U256::MAX));
 
-        let evm = Revm::<'e, (), EmptyDB>::builder().with_db(db).build();
+        let evm = Revm::<'e, (), EmptyDB>::builder()
+            .with_db(db)
+            .with_external_context(Recorder::default())
+            .append_handler_register(inspector_handle_register)
+            .build();
         Self {
             inner: evm,
             caller: [0; 20],
             commit: false,
+            gas_limit: GAS_LIMIT,
         }
     }
-}
 
-impl<'e> EVM<'e> {
+    /// Fork the state of the block from the node at `url`, the
+    /// accounts are loaded from the node while being accessed.
+    ///
+    /// This has to run in a multi-threaded tokio runtime.
+    #[cfg(feature = "fork")]
+    pub fn fork(url: &str, block: u64) -> Result<Self> {
+        Ok(Self::with_backend(Backend::fork(url, block)?))
+    }
+
     /// Interpret runtime bytecode with provided arguments
     pub fn interp(runtime_bytecode: &[u8], input: &[u8]) -> Result<Info> {
         Self::default()
@@ -72,19 +114,91 @@ impl<'e> EVM<'e> {
         self
     }
 
+    /// Set the gas limit of the transactions, which has to cover the
+    /// intrinsic gas of them.
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Record the `SLOAD`s and the `SSTORE`s of the transactions in
+    /// [`Info::storage_ops`].
+    pub fn trace_storage(mut self, flag: bool) -> Self {
+        self.inner.context.external.trace_storage = flag;
+        self
+    }
+
+    /// Record the execution steps of the transactions, which are
+    /// returned by [`EVM::call_traced`].
+    #[cfg(feature = "trace")]
+    pub fn trace(mut self, flag: bool) -> Self {
+        self.inner.context.external.trace = flag;
+        self
+    }
+
+    /// Set the value sent with the transaction
+    pub fn value(mut self, value: U256) -> Self {
+        self.inner.tx_mut().value = value;
+        self
+    }
+
+    /// Set the block environment for the execution
+    pub fn block(mut self, block: BlockEnv) -> Self {
+        *self.inner.block_mut() = block;
+        self
+    }
+
     /// Send transaction to the provided address.
     pub fn call(&mut self, to: [u8; 20]) -> Result<Info> {
         let to = TransactTo::Call(to.into());
-        self.inner.tx_mut().gas_limit = GAS_LIMIT;
+        self.inner.tx_mut().gas_limit = self.gas_limit;
         self.inner.tx_mut().transact_to = to;
         self.inner.tx_mut().caller = self.caller.into();
+        self.inner.context.external.calls.clear();
+        self.inner.context.external.storage_ops.clear();
 
-        if self.commit {
-            self.inner.transact_commit()?.try_into()
+        let mut info: Info = if self.commit {
+            self.inner.transact_commit()?.try_into()?
         } else {
             let result = self.inner.transact().map_err(|e| anyhow!(e))?;
-            (result, to).try_into()
-        }
+            (result, to).try_into()?
+        };
+
+        info.calls = std::mem::take(&mut self.inner.context.external.calls);
+        info.storage_ops = std::mem::take(&mut self.inner.context.external.storage_ops);
+        Ok(info)
+    }
+
+    /// Send transaction to the provided address and collect the
+    /// execution steps of it, see [`EVM::trace`].
+    #[cfg(feature = "trace")]
+    pub fn call_traced(&mut self, to: [u8; 20]) -> Result<(Info, Vec<crate::TraceStep>)> {
+        self.inner.context.external.steps.clear();
+        let info = self.call(to)?;
+        Ok((info, std::mem::take(&mut self.inner.context.external.steps)))
+    }
+
+    /// Send transaction to the provided address without interpreting
+    /// the result, returns the success flag and the output bytes.
+    ///
+    /// The output is the revert data if the transaction reverts, and
+    /// empty if it halts exceptionally.
+    pub fn call_raw(&mut self, to: [u8; 20]) -> Result<(bool, Vec<u8>)> {
+        self.inner.tx_mut().gas_limit = self.gas_limit;
+        self.inner.tx_mut().transact_to = TransactTo::Call(to.into());
+        self.inner.tx_mut().caller = self.caller.into();
+
+        let result = if self.commit {
+            self.inner.transact_commit()?
+        } else {
+            self.inner.transact().map_err(|e| anyhow!(e))?.result
+        };
+
+        Ok(match result {
+            ExecutionResult::Success { output, .. } => (true, output.into_data().into()),
+            ExecutionResult::Revert { output, .. } => (false, output.into()),
+            ExecutionResult::Halt { .. } => (false, vec![]),
+        })
     }
 
     /// Interpret runtime bytecode with provided arguments
@@ -100,12 +214,27 @@ impl<'e> EVM<'e> {
         self
     }
 
+    /// Set the balance of the account.
+    pub fn balance(mut self, address: [u8; 20], balance: U256) -> Self {
+        let mut info = self.account(address);
+        info.balance = balance;
+        self.db().insert_account_info(address.into(), info);
+        self
+    }
+
     /// Override the present contract
-    pub fn contract(mut self, runtime_bytecode: &[u8]) -> Self {
+    pub fn contract(self, runtime_bytecode: &[u8]) -> Self {
+        self.contract_at(CONTRACT, runtime_bytecode)
+    }
+
+    /// Override the contract at the provided address, the balance
+    /// of the account is kept.
+    pub fn contract_at(mut self, address: [u8; 20], runtime_bytecode: &[u8]) -> Self {
+        let balance = self.account(address).balance;
         self.db().insert_account_info(
-            CONTRACT.into(),
+            address.into(),
             AccountInfo::new(
-                Default::default(),
+                balance,
                 0,
                 Default::default(),
                 Bytecode::new_raw(Bytes::copy_from_slice(runtime_bytecode)),
@@ -115,7 +244,16 @@ impl<'e> EVM<'e> {
         self
     }
 
-    fn db(&mut self) -> &mut InMemoryDB {
+    /// Get the info of the account, empty if it does not exist.
+    fn account(&mut self, address: [u8; 20]) -> AccountInfo {
+        self.db()
+            .basic(address.into())
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+
+    fn db(&mut self) -> &mut Db {
         self.inner.db_mut()
     }
 }
@@ -131,12 +269,49 @@ pub struct Info {
     pub ret: Vec<u8>,
     /// The storage.
     pub storage: HashMap<U256, U256>,
+    /// Balances of the accounts touched by the execution after it.
+    pub balances: HashMap<[u8; 20], U256>,
     /// Execution logs.
     pub logs: Vec<Log>,
     /// Transaction halt reason.
     pub halt: Option<HaltReason>,
     /// The revert message.
     pub revert: Option<String>,
+    /// Calls made to the other accounts.
+    pub calls: Vec<ExternalCall>,
+    /// Storage accesses in the order of execution, only recorded if
+    /// the storage is traced.
+    pub storage_ops: Vec<StorageOp>,
+}
+
+impl Info {
+    /// If the execution runs out of gas, which halts the execution
+    /// instead of reverting it.
+    pub fn is_out_of_gas(&self) -> bool {
+        matches!(self.halt, Some(HaltReason::OutOfGas(_)))
+    }
+
+    /// Decode the return data into `T` per the ABI, fails if the
+    /// execution reverts or halts.
+    pub fn decode<T: Decode>(&self) -> Result<T> {
+        if let Some(message) = &self.revert {
+            return Err(anyhow!("execution reverted: {message}"));
+        }
+
+        if let Some(reason) = &self.halt {
+            return Err(anyhow!("execution halted: {reason:?}"));
+        }
+
+        let words = self.ret.get(..T::WORDS * 32).ok_or_else(|| {
+            anyhow!(
+                "return data 0x{} is shorter than {} words",
+                hex::encode(&self.ret),
+                T::WORDS
+            )
+        })?;
+
+        T::decode(words)
+    }
 }
 
 impl TryFrom<ExecutionResult> for Info {
@@ -155,8 +330,8 @@ impl TryFrom<ExecutionResult> for Info {
                 output,
                 ..
             } => {
-                if reason != SuccessReason::Return {
-                    return Err(anyhow!("Transaction is not returned: {reason:?}"));
+                if !matches!(reason, SuccessReason::Return | SuccessReason::Stop) {
+                    return Err(anyhow!("Transaction is not halted normally: {reason:?}"));
                 }
                 info.logs = logs;
 
@@ -181,11 +356,7 @@ impl TryFrom<ExecutionResult> for Info {
             }
             ExecutionResult::Revert { gas_used, output } => {
                 info.gas = gas_used;
-                info.revert = Some(
-                    String::from_utf8_lossy(&output)
-                        .trim_start_matches("\0")
-                        .to_string(),
-                );
+                info.revert = Some(revert_message(&output));
             }
         }
 
@@ -193,6 +364,23 @@ impl TryFrom<ExecutionResult> for Info {
     }
 }
 
+/// Parse the revert message from the revert data.
+///
+/// Decodes the message of `Error(string)` if the data is ABI
+/// encoded, otherwise returns the raw data as string.
+fn revert_message(output: &[u8]) -> String {
+    if output.len() >= 0x44 && output[..4] == zabi::selector::parse(b"Error(string)") {
+        let len = U256::from_be_slice(&output[0x24..0x44]).saturating_to::<usize>();
+        if let Some(message) = output.get(0x44..0x44 + len) {
+            return String::from_utf8_lossy(message).to_string();
+        }
+    }
+
+    String::from_utf8_lossy(output)
+        .trim_start_matches("\0")
+        .to_string()
+}
+
 impl TryFrom<(ResultAndState, TransactTo)> for Info {
     type Error = anyhow::Error;
 
@@ -200,6 +388,11 @@ impl TryFrom<(ResultAndState, TransactTo)> for Info {
         let ResultAndState { result, state } = res;
         let mut info = Self::try_from(result)?;
 
+        info.balances = state
+            .iter()
+            .map(|(address, account)| ((*address).into(), account.info.balance))
+            .collect();
+
         if let TransactTo::Call(address) = to {
             info.storage = state
                 .get(&address)
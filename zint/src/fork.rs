@@ -0,0 +1,75 @@
+//! Backend of the EVM database forked from a remote node.
+
+use alloy_eips::BlockId;
+use alloy_provider::{network::Ethereum, ProviderBuilder, RootProvider};
+use alloy_transport::TransportError;
+use alloy_transport_http::{reqwest::Client, Http};
+use anyhow::anyhow;
+use revm::{
+    db::{AlloyDB, EmptyDB},
+    primitives::{AccountInfo, Address, Bytecode, B256, U256},
+    DatabaseRef,
+};
+
+/// State of the remote node.
+type RemoteDB = AlloyDB<Http<Client>, Ethereum, RootProvider<Http<Client>>>;
+
+/// Backend of the EVM database, the accounts missing in the cache
+/// are loaded from it.
+pub enum Backend {
+    /// Empty state.
+    Empty(EmptyDB),
+    /// State of the remote node at the forked block.
+    Fork(RemoteDB),
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Self::Empty(EmptyDB::default())
+    }
+}
+
+impl Backend {
+    /// Fork the state of the block from the node at `url`.
+    ///
+    /// The state is fetched in the present tokio runtime, which has to
+    /// be a multi-threaded one.
+    pub fn fork(url: &str, block: u64) -> anyhow::Result<Self> {
+        let provider = ProviderBuilder::new().on_http(url.parse()?);
+        AlloyDB::new(provider, BlockId::number(block))
+            .map(Self::Fork)
+            .ok_or_else(|| anyhow!("forking requires a multi-threaded tokio runtime"))
+    }
+}
+
+impl DatabaseRef for Backend {
+    type Error = TransportError;
+
+    fn basic_ref(&self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
+        match self {
+            Self::Empty(db) => db.basic_ref(address).map_err(|e| match e {}),
+            Self::Fork(db) => db.basic_ref(address),
+        }
+    }
+
+    fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        match self {
+            Self::Empty(db) => db.code_by_hash_ref(code_hash).map_err(|e| match e {}),
+            Self::Fork(db) => db.code_by_hash_ref(code_hash),
+        }
+    }
+
+    fn storage_ref(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
+        match self {
+            Self::Empty(db) => db.storage_ref(address, index).map_err(|e| match e {}),
+            Self::Fork(db) => db.storage_ref(address, index),
+        }
+    }
+
+    fn block_hash_ref(&self, number: u64) -> Result<B256, Self::Error> {
+        match self {
+            Self::Empty(db) => db.block_hash_ref(number).map_err(|e| match e {}),
+            Self::Fork(db) => db.block_hash_ref(number),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Address labels
+
+use crate::U256;
+use std::collections::BTreeMap;
+
+/// Names of the addresses, printed instead of their hex in the
+/// traces and the error messages.
+#[derive(Clone, Debug, Default)]
+pub struct Labels(BTreeMap<[u8; 20], String>);
+
+impl Labels {
+    /// Label the address with `name`.
+    pub fn insert(&mut self, address: [u8; 20], name: impl Into<String>) {
+        self.0.insert(address, name.into());
+    }
+
+    /// Get the label of the address.
+    pub fn get(&self, address: &[u8; 20]) -> Option<&str> {
+        self.0.get(address).map(String::as_str)
+    }
+
+    /// Format the word, the label is used if the word is a labeled
+    /// address.
+    pub fn word(&self, word: &U256) -> String {
+        let bytes = word.to_be_bytes::<32>();
+        if bytes[..12].iter().all(|b| *b == 0) {
+            if let Some(name) = self.0.get(&bytes[12..]) {
+                return name.clone();
+            }
+        }
+
+        format!("{word:#x}")
+    }
+
+    /// Replace the hex of the labeled addresses in the message, both
+    /// the bare address and the address padded to a word.
+    pub fn replace(&self, message: &str) -> String {
+        let mut message = message.to_string();
+        for (address, name) in &self.0 {
+            let address = hex::encode(address);
+            for pattern in [
+                format!("{}{address}", "0".repeat(24)),
+                format!("0x{address}"),
+                address,
+            ] {
+                message = message.replace(&pattern, name);
+            }
+        }
+
+        message
+    }
+}
@@ -1,20 +1,40 @@
 //! Zink testing framework.
 #![deny(missing_docs)]
 
+mod abi;
 mod bytes;
 mod contract;
+mod decode;
+mod emit;
 mod evm;
+#[cfg(feature = "fork")]
+mod fork;
+mod label;
 mod lookup;
+mod mock;
+mod record;
+pub mod snapshot;
+#[cfg(feature = "trace")]
+mod trace;
 
+#[cfg(feature = "trace")]
+pub use self::trace::TraceStep;
 pub use self::{
-    bytes::Bytes32,
+    abi::Call,
+    bytes::{Bytes32, FixedBytes},
     contract::Contract,
-    evm::{Info, EVM},
+    decode::Decode,
+    emit::{Emit, Event},
+    evm::{block_hash, Info, CONTRACT, EVM},
+    label::Labels,
+    mock::Mocks,
+    record::{ExternalCall, StorageOp},
 };
 pub use hex;
-pub use revm::primitives::{HaltReason, OutOfGasError, U256};
+pub use revm::primitives::{BlockEnv, HaltReason, OutOfGasError, U256};
 pub use tracing as log;
 pub use zabi::selector::keccak256;
+pub use zinkc::SlotInfo;
 
 /// Set up the logger.
 pub fn setup_logger() {
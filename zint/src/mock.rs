@@ -0,0 +1,63 @@
+//! Mocked calls
+
+use revm::interpreter::opcode::{
+    CALLDATALOAD, CODECOPY, DUP1, EQ, JUMPDEST, JUMPI, PUSH0, PUSH1, PUSH2, PUSH4, RETURN, REVERT,
+    SHR,
+};
+use std::collections::BTreeMap;
+
+/// Size of the code loading the selector.
+const SELECTOR_SIZE: usize = 5;
+
+/// Size of the code jumping to the return of a selector.
+const DISPATCH_SIZE: usize = 11;
+
+/// Size of the code reverting the calls not mocked.
+const FALLBACK_SIZE: usize = 3;
+
+/// Size of the code returning the data of a selector.
+const RETURN_SIZE: usize = 14;
+
+/// Return data of the mocked calls of an account by selector.
+#[derive(Clone, Debug, Default)]
+pub struct Mocks(BTreeMap<[u8; 4], Vec<u8>>);
+
+impl Mocks {
+    /// Mock the calls of the selector with the return data.
+    pub fn insert(&mut self, selector: [u8; 4], data: Vec<u8>) {
+        self.0.insert(selector, data);
+    }
+
+    /// Runtime bytecode of the account which returns the data of the
+    /// selector in the calldata, and reverts if it is not mocked.
+    pub fn bytecode(&self) -> Vec<u8> {
+        let returns = SELECTOR_SIZE + DISPATCH_SIZE * self.0.len() + FALLBACK_SIZE;
+        let mut data = returns + RETURN_SIZE * self.0.len();
+        let mut code = vec![PUSH0, CALLDATALOAD, PUSH1, 0xe0, SHR];
+
+        for (index, selector) in self.0.keys().enumerate() {
+            let dest = (returns + RETURN_SIZE * index) as u16;
+            code.extend([DUP1, PUSH4]);
+            code.extend(selector);
+            code.extend([EQ, PUSH2]);
+            code.extend(dest.to_be_bytes());
+            code.push(JUMPI);
+        }
+
+        code.extend([PUSH0, PUSH0, REVERT]);
+        for output in self.0.values() {
+            let len = (output.len() as u16).to_be_bytes();
+            code.extend([JUMPDEST, PUSH2]);
+            code.extend(len);
+            code.push(PUSH2);
+            code.extend((data as u16).to_be_bytes());
+            code.extend([PUSH0, CODECOPY, PUSH2]);
+            code.extend(len);
+            code.extend([PUSH0, RETURN]);
+            data += output.len();
+        }
+
+        code.extend(self.0.values().flatten());
+        code
+    }
+}
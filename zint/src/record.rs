@@ -0,0 +1,157 @@
+//! Recording of the external calls and the storage accesses.
+
+use crate::{Info, U256};
+use anyhow::{anyhow, Result};
+use revm::{
+    interpreter::{opcode, CallInputs, CallOutcome, Interpreter},
+    primitives::Address,
+    Database, EvmContext, Inspector,
+};
+
+/// Call made by the contract to another account.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExternalCall {
+    /// Address of the callee.
+    pub to: [u8; 20],
+    /// Calldata of the call.
+    pub input: Vec<u8>,
+}
+
+impl ExternalCall {
+    /// Check if the calls of the execution match the expectations.
+    pub fn check(expected: &[Self], info: &Info) -> Result<()> {
+        for call in expected {
+            if !info.calls.contains(call) {
+                return Err(anyhow!(
+                    "expected call not made, to: 0x{}, calldata: {}",
+                    hex::encode(call.to),
+                    hex::encode(&call.input)
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `SLOAD` or `SSTORE` executed by the contracts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageOp {
+    /// Address of the contract accessing its storage.
+    pub address: [u8; 20],
+    /// If the access is `SSTORE`, otherwise `SLOAD`.
+    pub store: bool,
+    /// The accessed slot.
+    pub slot: U256,
+    /// Value of the slot before the access.
+    pub old: U256,
+    /// Value of the slot after the access, the same as the old one
+    /// for `SLOAD`.
+    pub new: U256,
+}
+
+/// Inspector recording the calls made by the contracts, the call of
+/// the transaction itself is not recorded.
+///
+/// The storage accesses are recorded as well if `trace_storage` is
+/// enabled, and the execution steps if `trace` is enabled.
+#[derive(Default)]
+pub(crate) struct Recorder {
+    pub calls: Vec<ExternalCall>,
+    pub trace_storage: bool,
+    pub storage_ops: Vec<StorageOp>,
+    #[cfg(feature = "trace")]
+    pub trace: bool,
+    #[cfg(feature = "trace")]
+    pub steps: Vec<crate::TraceStep>,
+    /// The storage access being executed with the value of the slot
+    /// before it, `None` if the slot has not been loaded yet.
+    pending: Option<(StorageOp, Option<U256>)>,
+}
+
+impl Recorder {
+    /// Get the present value of the slot if it has been loaded.
+    fn present<DB: Database>(
+        context: &EvmContext<DB>,
+        address: &Address,
+        slot: &U256,
+    ) -> Option<(U256, U256)> {
+        context
+            .journaled_state
+            .state
+            .get(address)
+            .and_then(|account| account.storage.get(slot))
+            .map(|value| (value.original_value, value.present_value))
+    }
+}
+
+impl<DB: Database> Inspector<DB> for Recorder {
+    fn step(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        #[cfg(feature = "trace")]
+        if self.trace {
+            self.steps.push(crate::TraceStep::new(interp));
+        }
+
+        if !self.trace_storage {
+            return;
+        }
+
+        let store = match interp.current_opcode() {
+            opcode::SLOAD => false,
+            opcode::SSTORE => true,
+            _ => return,
+        };
+        let Ok(slot) = interp.stack.peek(0) else {
+            return;
+        };
+
+        let address = interp.contract.target_address;
+        let old = Self::present(context, &address, &slot).map(|(_, present)| present);
+        self.pending = Some((
+            StorageOp {
+                address: address.into(),
+                store,
+                slot,
+                old: U256::ZERO,
+                new: U256::ZERO,
+            },
+            old,
+        ));
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, context: &mut EvmContext<DB>) {
+        let Some((mut op, old)) = self.pending.take() else {
+            return;
+        };
+
+        if interp.instruction_result.is_error() {
+            return;
+        }
+
+        // the slot is loaded by the access, the value before the first
+        // access of the slot is the original one.
+        let address = Address::from(op.address);
+        let Some((original, present)) = Self::present(context, &address, &op.slot) else {
+            return;
+        };
+
+        op.old = old.unwrap_or(original);
+        op.new = present;
+        self.storage_ops.push(op);
+    }
+
+    fn call(
+        &mut self,
+        context: &mut EvmContext<DB>,
+        inputs: &mut CallInputs,
+    ) -> Option<CallOutcome> {
+        if context.journaled_state.depth() > 0 {
+            self.calls.push(ExternalCall {
+                to: inputs.target_address.into(),
+                input: inputs.input.to_vec(),
+            });
+        }
+
+        None
+    }
+}
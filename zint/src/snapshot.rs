@@ -0,0 +1,86 @@
+//! Gas snapshots
+//!
+//! Snapshots are recorded in `.gas-snapshot` under the current
+//! directory, one `<name> (gas: <gas>)` per line.
+
+use anyhow::{anyhow, Result};
+use std::{collections::BTreeMap, env, fs, path::Path, sync::Mutex};
+
+/// File of the gas snapshots.
+pub const GAS_SNAPSHOT: &str = ".gas-snapshot";
+
+/// Environment variable for updating the gas snapshots.
+pub const UPDATE_GAS_SNAPSHOT: &str = "ZINT_UPDATE_GAS_SNAPSHOT";
+
+/// Tolerance of the gas changes in percent.
+pub const GAS_TOLERANCE: u64 = 1;
+
+/// Lock of the snapshot file for the tests running in parallel.
+static LOCK: Mutex<()> = Mutex::new(());
+
+/// Check the gas against the snapshot of `name` in the
+/// [`GAS_SNAPSHOT`] file of the current directory.
+///
+/// The gas is recorded if the snapshot is missing or if
+/// [`UPDATE_GAS_SNAPSHOT`] is set.
+pub fn check(name: &str, gas: u64) -> Result<()> {
+    let path = env::current_dir()?.join(GAS_SNAPSHOT);
+    check_file(&path, name, gas, env::var_os(UPDATE_GAS_SNAPSHOT).is_some())
+}
+
+/// Check the gas against the snapshot of `name` in the file.
+///
+/// Fails if the gas differs from the snapshot beyond
+/// [`GAS_TOLERANCE`], the gas is recorded if the snapshot is missing
+/// or if `update` is set.
+pub fn check_file(path: &Path, name: &str, gas: u64, update: bool) -> Result<()> {
+    let _lock = LOCK.lock().map_err(|e| anyhow!("{e}"))?;
+    let mut snapshots = read(path)?;
+
+    match snapshots.get(name) {
+        Some(&expected) if !update => {
+            if gas.abs_diff(expected) > expected * GAS_TOLERANCE / 100 {
+                return Err(anyhow!(
+                    "gas of {name} changed from {expected} to {gas}, \
+                     set {UPDATE_GAS_SNAPSHOT} to update the snapshot"
+                ));
+            }
+
+            Ok(())
+        }
+        Some(&expected) if expected == gas => Ok(()),
+        _ => {
+            tracing::info!("recording gas snapshot {name} (gas: {gas})");
+            snapshots.insert(name.into(), gas);
+            write(path, &snapshots)
+        }
+    }
+}
+
+/// Read the snapshots from the file.
+fn read(path: &Path) -> Result<BTreeMap<String, u64>> {
+    if !path.exists() {
+        return Ok(Default::default());
+    }
+
+    fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (name, gas) = line
+                .rsplit_once(" (gas: ")
+                .ok_or_else(|| anyhow!("invalid gas snapshot {line}"))?;
+            Ok((name.into(), gas.trim_end_matches(')').parse()?))
+        })
+        .collect()
+}
+
+/// Write the snapshots to the file.
+fn write(path: &Path, snapshots: &BTreeMap<String, u64>) -> Result<()> {
+    let content = snapshots
+        .iter()
+        .map(|(name, gas)| format!("{name} (gas: {gas})\n"))
+        .collect::<String>();
+
+    fs::write(path, content).map_err(Into::into)
+}
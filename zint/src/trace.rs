@@ -0,0 +1,47 @@
+//! Opcode execution trace
+
+use crate::Labels;
+use revm::{
+    interpreter::{Interpreter, OpCode},
+    primitives::U256,
+};
+
+/// A step of the execution trace.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep {
+    /// Program counter.
+    pub pc: usize,
+    /// The executing opcode.
+    pub opcode: u8,
+    /// Gas remaining before the step.
+    pub gas: u64,
+    /// The top of the stack before the step if any.
+    pub stack_top: Option<U256>,
+}
+
+impl TraceStep {
+    /// Record the step about to be executed by the interpreter.
+    pub(crate) fn new(interp: &Interpreter) -> Self {
+        Self {
+            pc: interp.program_counter(),
+            opcode: interp.current_opcode(),
+            gas: interp.gas.remaining(),
+            stack_top: interp.stack.peek(0).ok(),
+        }
+    }
+
+    /// Format the step as a line of the trace, the labeled addresses
+    /// on the top of the stack are printed with their labels.
+    pub fn line(&self, labels: &Labels) -> String {
+        let opcode = OpCode::new(self.opcode)
+            .map(|op| op.as_str().to_string())
+            .unwrap_or_else(|| format!("{:#04x}", self.opcode));
+
+        let mut line = format!("{:>5}: {opcode:<14} gas: {}", self.pc, self.gas);
+        if let Some(top) = &self.stack_top {
+            line.push_str(&format!(" top: {}", labels.word(top)));
+        }
+
+        line
+    }
+}
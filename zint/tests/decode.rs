@@ -0,0 +1,19 @@
+use zint::{Bytes32, Info, U256};
+
+#[test]
+fn decode() -> anyhow::Result<()> {
+    let owner = [1; 20];
+    let info = Info {
+        ret: [owner.to_bytes32(), 42.to_bytes32(), true.to_bytes32()].concat(),
+        ..Default::default()
+    };
+
+    assert_eq!(info.decode::<[u8; 20]>()?, owner);
+    assert_eq!(
+        info.decode::<([u8; 20], U256, bool)>()?,
+        (owner, U256::from(42), true)
+    );
+    assert!(info.decode::<u8>().is_err());
+    assert!(info.decode::<(U256, U256, U256, U256)>().is_err());
+    Ok(())
+}
@@ -0,0 +1,20 @@
+//! Tests of forking the state of mainnet.
+#![cfg(feature = "fork")]
+
+use zint::{Bytes32, EVM};
+
+/// Address of WETH9 on mainnet.
+const WETH: &str = "c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore = "requires a mainnet RPC endpoint in ETH_RPC_URL"]
+async fn weth_decimals() -> anyhow::Result<()> {
+    let url = std::env::var("ETH_RPC_URL")?;
+    let mut weth = [0; 20];
+    weth.copy_from_slice(&hex::decode(WETH)?);
+
+    // the slot 2 of WETH9 stores its decimals.
+    let mut evm = EVM::fork(&url, 20_000_000)?;
+    assert_eq!(evm.storage(weth, 2.to_bytes32())?, 18.to_bytes32());
+    Ok(())
+}
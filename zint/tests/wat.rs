@@ -0,0 +1,19 @@
+use zint::{Bytes32, Contract};
+
+#[test]
+fn compile_wat() -> anyhow::Result<()> {
+    let mut contract = Contract::compile_wat(
+        r#"
+        (module
+          (func (export "clz") (param i32) (result i32)
+            local.get 0
+            i32.clz))
+        "#,
+    )?;
+
+    let info = contract.execute([1])?;
+    assert_eq!(info.ret, 31.to_bytes32());
+
+    assert!(Contract::compile_wat("(module").is_err());
+    Ok(())
+}